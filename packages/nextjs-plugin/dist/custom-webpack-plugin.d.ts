@@ -9,7 +9,8 @@ declare class StylexPlugin {
     stylexImports: any[];
     babelPlugin: any;
     useCSSLayers: any;
-    constructor({ dev, appendTo, filename, stylexImports, useCSSLayers, }?: any);
+    stylexSwcPath: string;
+    constructor({ dev, appendTo, filename, stylexImports, useCSSLayers, stylexSwcPath, }?: any);
     apply(compiler: Compiler): void;
     transformCode(inputCode: string, filename: string, logger: any): Promise<{
         code: string;
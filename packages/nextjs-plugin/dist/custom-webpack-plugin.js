@@ -4,9 +4,9 @@ var __importDefault = (this && this.__importDefault) || function (mod) {
 };
 Object.defineProperty(exports, "__esModule", { value: true });
 const path_1 = __importDefault(require("path"));
-const babel_plugin_1 = __importDefault(require("@stylexjs/babel-plugin"));
 const webpack_1 = __importDefault(require("webpack"));
 const promises_1 = __importDefault(require("fs/promises"));
+const process_stylex_rules_1 = require("./process-stylex-rules");
 const { NormalModule, Compilation } = webpack_1.default;
 const PLUGIN_NAME = "stylex";
 const IS_DEV_ENV = process.env.NODE_ENV === "development" ||
@@ -25,12 +25,14 @@ class StylexPlugin {
     stylexImports;
     babelPlugin;
     useCSSLayers;
-    constructor({ dev = IS_DEV_ENV, appendTo, filename = appendTo == null ? "stylex.css" : undefined, stylexImports = ["stylex", "@stylexjs/stylex"], useCSSLayers = false, } = {}) {
+    stylexSwcPath;
+    constructor({ dev = IS_DEV_ENV, appendTo, filename = appendTo == null ? "stylex.css" : undefined, stylexImports = ["stylex", "@stylexjs/stylex"], useCSSLayers = false, stylexSwcPath = "stylex-swc", } = {}) {
         this.dev = dev;
         this.appendTo = appendTo;
         this.filename = filename;
         this.stylexImports = stylexImports;
         this.useCSSLayers = useCSSLayers;
+        this.stylexSwcPath = stylexSwcPath;
     }
     apply(compiler) {
         compiler.hooks.make.tap(PLUGIN_NAME, (compilation) => {
@@ -66,7 +68,7 @@ class StylexPlugin {
                     .map((filename) => stylexRules[filename])
                     .filter(Boolean)
                     .flat();
-                return babel_plugin_1.default.processStylexRules(allRules, this.useCSSLayers);
+                return (0, process_stylex_rules_1.processStylexRules)(allRules, this.useCSSLayers, this.stylexSwcPath);
             };
             if (this.appendTo) {
                 compilation.hooks.processAssets.tap({
@@ -131,10 +133,10 @@ class StylexPlugin {
             const map = null;
             if (metadata.stylex != null && metadata.stylex.length > 0) {
                 const oldRules = stylexRules[filename] || [];
-                stylexRules[filename] = metadata.stylex?.map((rule) => [rule.class_name, rule.style, rule.priority]);
+                stylexRules[filename] = metadata.stylex;
                 logger.debug(`Read stylex styles from ${filename}:`, metadata.stylex);
-                const oldClassNames = new Set(oldRules.map((rule) => rule[0]));
-                const newClassNames = new Set(metadata.stylex.map((rule) => rule[0]));
+                const oldClassNames = new Set(oldRules.map((rule) => rule.class_name));
+                const newClassNames = new Set(metadata.stylex.map((rule) => rule.class_name));
                 // If there are any new classNames in the output we need to recompile
                 // the CSS bundle.
                 if (oldClassNames.size !== newClassNames.size ||
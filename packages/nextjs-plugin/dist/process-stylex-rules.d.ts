@@ -0,0 +1,7 @@
+import type { PluginRule } from "./types";
+/**
+ * Builds the stylesheet of the collected rules with the Rust stylesheet generator of the
+ * `stylex-swc` CLI, which sorts, merges RTL rules and dedupes them the same way as the
+ * `processStylexRules` of `@stylexjs/babel-plugin`.
+ */
+export declare function processStylexRules(rules: PluginRule[], useCSSLayers: boolean, stylexSwcPath: string): string;
@@ -0,0 +1,20 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.processStylexRules = processStylexRules;
+const child_process_1 = require("child_process");
+/**
+ * Builds the stylesheet of the collected rules with the Rust stylesheet generator of the
+ * `stylex-swc` CLI, which sorts, merges RTL rules and dedupes them the same way as the
+ * `processStylexRules` of `@stylexjs/babel-plugin`.
+ */
+function processStylexRules(rules, useCSSLayers, stylexSwcPath) {
+    const args = ["--process-rules"];
+    if (useCSSLayers) {
+        args.push("--use-css-layers");
+    }
+    return (0, child_process_1.execFileSync)(stylexSwcPath, args, {
+        input: JSON.stringify(rules),
+        encoding: "utf8",
+        maxBuffer: Infinity,
+    });
+}
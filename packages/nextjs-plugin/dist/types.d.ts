@@ -5,5 +5,10 @@ export type PluginRule = {
         rtl?: null | string;
     };
     priority: number;
+    source?: {
+        file: string;
+        line: number;
+        column: number;
+    };
 };
 //# sourceMappingURL=types.d.ts.map
\ No newline at end of file
@@ -1,10 +1,9 @@
 import path from "path";
-import stylexBabelPlugin from "@stylexjs/babel-plugin";
 import webpack from "webpack";
 import fs from "fs/promises";
 import { PluginRule } from "./types";
+import { processStylexRules } from "./process-stylex-rules";
 
-import type { Rule } from "@stylexjs/babel-plugin";
 import type { Compiler, WebpackError } from "webpack";
 
 const { NormalModule, Compilation } = webpack;
@@ -17,7 +16,7 @@ const IS_DEV_ENV =
 
 const { RawSource, ConcatSource } = webpack.sources;
 
-const stylexRules: Record<string, Rule[]> = {};
+const stylexRules: Record<string, PluginRule[]> = {};
 const cssFiles = new Set<any>();
 const compilers = new Set<any>();
 
@@ -31,6 +30,7 @@ class StylexPlugin {
   stylexImports: any[];
   babelPlugin: any;
   useCSSLayers: any;
+  stylexSwcPath: string;
 
   constructor({
     dev = IS_DEV_ENV,
@@ -38,6 +38,7 @@ class StylexPlugin {
     filename = appendTo == null ? "stylex.css" : undefined,
     stylexImports = ["stylex", "@stylexjs/stylex"],
     useCSSLayers = false,
+    stylexSwcPath = "stylex-swc",
   }: any = {}) {
     this.dev = dev;
     this.appendTo = appendTo;
@@ -45,6 +46,7 @@ class StylexPlugin {
     this.stylexImports = stylexImports;
 
     this.useCSSLayers = useCSSLayers;
+    this.stylexSwcPath = stylexSwcPath;
   }
 
   apply(compiler: Compiler) {
@@ -87,11 +89,12 @@ class StylexPlugin {
         const allRules = Object.keys(stylexRules)
           .map((filename) => stylexRules[filename])
           .filter(Boolean)
-          .flat() as unknown as Rule[];
+          .flat() as PluginRule[];
 
-        return stylexBabelPlugin.processStylexRules(
+        return processStylexRules(
           allRules,
           this.useCSSLayers,
+          this.stylexSwcPath,
         );
       };
 
@@ -172,7 +175,7 @@ class StylexPlugin {
         },
       );
 
-      const metadata = { stylex: [] };
+      const metadata: { stylex: PluginRule[] } = { stylex: [] };
 
       try {
         metadata.stylex = JSON.parse(metadataStr);
@@ -184,15 +187,14 @@ class StylexPlugin {
       if (metadata.stylex != null && metadata.stylex.length > 0) {
         const oldRules = stylexRules[filename] || [];
 
-        stylexRules[filename] = metadata.stylex?.map(
-          (rule: PluginRule) =>
-            [rule.class_name, rule.style, rule.priority] as Rule,
-        );
+        stylexRules[filename] = metadata.stylex;
 
         logger.debug(`Read stylex styles from ${filename}:`, metadata.stylex);
 
-        const oldClassNames = new Set(oldRules.map((rule) => rule[0]));
-        const newClassNames = new Set(metadata.stylex.map((rule) => rule[0]));
+        const oldClassNames = new Set(oldRules.map((rule) => rule.class_name));
+        const newClassNames = new Set(
+          metadata.stylex.map((rule) => rule.class_name),
+        );
 
         // If there are any new classNames in the output we need to recompile
         // the CSS bundle.
@@ -0,0 +1,26 @@
+import { execFileSync } from "child_process";
+
+import type { PluginRule } from "./types";
+
+/**
+ * Builds the stylesheet of the collected rules with the Rust stylesheet generator of the
+ * `stylex-swc` CLI, which sorts, merges RTL rules and dedupes them the same way as the
+ * `processStylexRules` of `@stylexjs/babel-plugin`.
+ */
+export function processStylexRules(
+  rules: PluginRule[],
+  useCSSLayers: boolean,
+  stylexSwcPath: string,
+): string {
+  const args = ["--process-rules"];
+
+  if (useCSSLayers) {
+    args.push("--use-css-layers");
+  }
+
+  return execFileSync(stylexSwcPath, args, {
+    input: JSON.stringify(rules),
+    encoding: "utf8",
+    maxBuffer: Infinity,
+  });
+}
@@ -2,4 +2,5 @@ export type PluginRule = {
   class_name: string;
   style: { ltr: string; rtl?: null | string };
   priority: number;
+  source?: { file: string; line: number; column: number };
 };
@@ -0,0 +1,462 @@
+use std::{
+  cell::{Cell, RefCell},
+  fs, io, mem,
+  panic::{self, catch_unwind, AssertUnwindSafe},
+  path::{Path, PathBuf},
+  process,
+  rc::Rc,
+  sync::Arc,
+};
+
+use clap::Parser;
+use rayon::prelude::*;
+use stylex_swc_plugin::{
+  assign_minified_class_names, process_stylex_rules,
+  shared::structures::{
+    meta_data::MetaData, plugin_pass::PluginPass, stylesheet_options::StyleSheetOptions,
+    stylex_options::StyleXOptionsParams,
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{
+    comments::{SingleThreadedComments, SingleThreadedCommentsMapInner},
+    errors::{ColorConfig, Handler, HANDLER},
+    FileName, Globals, Mark, SourceMap, GLOBALS,
+  },
+  ecma::{
+    ast::{Module, Program},
+    codegen::{text_writer::JsWriter, Emitter},
+    parser::{lexer::Lexer, EsConfig, Parser as SwcParser, StringInput, Syntax, TsConfig},
+    transforms::{
+      base::{fixer::fixer, resolver},
+      typescript::strip,
+    },
+    visit::{FoldWith, VisitMutWith},
+  },
+};
+use walkdir::WalkDir;
+
+const SOURCE_EXTENSIONS: [&str; 6] = ["js", "jsx", "mjs", "cjs", "ts", "tsx"];
+
+thread_local! {
+  /// Set while the StyleX transform runs on this thread. Its panics are reported with the file
+  /// they happened in, so the panic hook stays silent for them.
+  static IS_TRANSFORMING: Cell<bool> = const { Cell::new(false) };
+}
+
+#[derive(Parser)]
+#[clap(
+  name = "stylex-swc",
+  about = "Compiles a source tree with StyleX into JavaScript and a single stylesheet."
+)]
+struct Cli {
+  #[clap(
+    help = "Directory with the source files to compile.",
+    value_name = "INPUT_DIR",
+    required_unless_present = "process_rules"
+  )]
+  input: Option<PathBuf>,
+
+  #[clap(
+    short = 'o',
+    long,
+    default_value = "./dist",
+    help = "Directory the transformed files and the stylesheet are written to.",
+    value_name = "PATH"
+  )]
+  out_dir: PathBuf,
+
+  #[clap(
+    short = 'c',
+    long,
+    help = "JSON file with the StyleX plugin options.",
+    value_name = "PATH"
+  )]
+  config: Option<PathBuf>,
+
+  #[clap(
+    long,
+    default_value = "stylex.css",
+    help = "Name of the generated stylesheet, relative to the output directory.",
+    value_name = "FILE"
+  )]
+  css_file: PathBuf,
+
+  #[clap(
+    long,
+    help = "Wrap every priority group of the stylesheet in a CSS cascade layer."
+  )]
+  use_css_layers: bool,
+
+  #[clap(
+    long,
+    conflicts_with = "input",
+    help = "Read StyleX rules as JSON from stdin and print their stylesheet instead of compiling a directory."
+  )]
+  process_rules: bool,
+}
+
+fn read_source_files(root_path: &Path, out_dir: &Path) -> Vec<PathBuf> {
+  let mut files = WalkDir::new(root_path)
+    .follow_links(true)
+    .into_iter()
+    .filter_entry(|entry| entry.file_name() != "node_modules" && entry.path() != out_dir)
+    .filter_map(Result::ok)
+    .filter(|entry| entry.file_type().is_file())
+    .map(|entry| entry.into_path())
+    .filter(|path| {
+      path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| SOURCE_EXTENSIONS.contains(&extension))
+        .unwrap_or(false)
+    })
+    .collect::<Vec<PathBuf>>();
+
+  files.sort();
+
+  files
+}
+
+fn get_syntax(file_path: &Path) -> Syntax {
+  match file_path
+    .extension()
+    .and_then(|extension| extension.to_str())
+  {
+    Some("ts") => Syntax::Typescript(TsConfig::default()),
+    Some("tsx") => Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    _ => Syntax::Es(EsConfig {
+      jsx: true,
+      ..Default::default()
+    }),
+  }
+}
+
+fn is_typescript(file_path: &Path) -> bool {
+  matches!(
+    file_path
+      .extension()
+      .and_then(|extension| extension.to_str()),
+    Some("ts" | "tsx")
+  )
+}
+
+/// Path of the JavaScript file compiled from `file_path`. TSX files keep their JSX,
+/// like `tsc` does with `jsx: preserve`, so they become `.jsx` files.
+fn output_path(file_path: &Path) -> PathBuf {
+  match file_path
+    .extension()
+    .and_then(|extension| extension.to_str())
+  {
+    Some("ts") => file_path.with_extension("js"),
+    Some("tsx") => file_path.with_extension("jsx"),
+    _ => file_path.to_path_buf(),
+  }
+}
+
+/// A file compiled to JavaScript, ready to be emitted. Its comments are kept in plain maps, so
+/// that it can be sent between threads.
+struct TransformedFile {
+  cm: Arc<SourceMap>,
+  leading_comments: SingleThreadedCommentsMapInner,
+  trailing_comments: SingleThreadedCommentsMapInner,
+  module: Module,
+  metadata: Vec<MetaData>,
+  class_names: Vec<String>,
+}
+
+/// Parses `file_path`, applies the StyleX transform and strips its TypeScript types.
+/// Must run inside of `GLOBALS`.
+fn transform_file(
+  file_path: &Path,
+  cwd: &Path,
+  config: &StyleXOptionsParams,
+) -> Result<TransformedFile, String> {
+  let source_code = fs::read_to_string(file_path).map_err(|error| error.to_string())?;
+
+  let cm: Arc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Real(file_path.to_path_buf()), source_code);
+  let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm.clone()));
+  let comments = SingleThreadedComments::default();
+
+  let lexer = Lexer::new(
+    get_syntax(file_path),
+    Default::default(),
+    StringInput::from(&*fm),
+    Some(&comments),
+  );
+
+  let mut module = SwcParser::new_from(lexer).parse_module().map_err(|error| {
+    error.into_diagnostic(&handler).emit();
+
+    "Failed to parse file".to_string()
+  })?;
+
+  let top_level_mark = Mark::new();
+
+  module.visit_mut_with(&mut resolver(
+    Mark::new(),
+    top_level_mark,
+    is_typescript(file_path),
+  ));
+
+  let mut config = config.clone();
+
+  let mut stylex = ModuleTransformVisitor::new(
+    comments.clone(),
+    Box::new(PluginPass {
+      cwd: Some(cwd.to_path_buf()),
+      filename: FileName::Real(file_path.to_path_buf()),
+    }),
+    &mut config,
+  );
+
+  let module = HANDLER.set(&handler, || {
+    IS_TRANSFORMING.with(|is_transforming| is_transforming.set(true));
+
+    let module = catch_unwind(AssertUnwindSafe(|| module.fold_with(&mut stylex)));
+
+    IS_TRANSFORMING.with(|is_transforming| is_transforming.set(false));
+
+    module
+  });
+
+  let mut module = module.map_err(|error| {
+    let message = error
+      .downcast_ref::<String>()
+      .map(String::as_str)
+      .or_else(|| error.downcast_ref::<&str>().copied())
+      .unwrap_or("unknown error");
+
+    format!("Failed to transform file: {}", message)
+  })?;
+
+  if is_typescript(file_path) {
+    let mut program = Program::Module(module);
+
+    HANDLER.set(&handler, || {
+      program.visit_mut_with(&mut strip(top_level_mark))
+    });
+
+    if handler.has_errors() {
+      return Err("Failed to strip TypeScript types".to_string());
+    }
+
+    module = program.expect_module();
+  }
+
+  module.visit_mut_with(&mut fixer(Some(&comments)));
+
+  let (leading_comments, trailing_comments) = comments.take_all();
+
+  Ok(TransformedFile {
+    cm,
+    leading_comments: leading_comments.take(),
+    trailing_comments: trailing_comments.take(),
+    module,
+    metadata: stylex.get_metadata(),
+    class_names: stylex.get_atomic_class_names(),
+  })
+}
+
+fn emit_file(transformed_file: TransformedFile) -> Result<String, String> {
+  let TransformedFile {
+    cm,
+    leading_comments,
+    trailing_comments,
+    module,
+    ..
+  } = transformed_file;
+
+  let comments = SingleThreadedComments::from_leading_and_trailing(
+    Rc::new(RefCell::new(leading_comments)),
+    Rc::new(RefCell::new(trailing_comments)),
+  );
+
+  let mut code = vec![];
+
+  Emitter {
+    cfg: Default::default(),
+    cm: cm.clone(),
+    comments: Some(&comments),
+    wr: JsWriter::new(cm, "\n", &mut code, None),
+  }
+  .emit_module(&module)
+  .map_err(|error| error.to_string())?;
+
+  String::from_utf8(code).map_err(|error| error.to_string())
+}
+
+/// Renames the class names of the transformed files to the minified names, assigned in the
+/// order of the files so they do not depend on which file happens to be transformed first.
+fn minify_class_names(
+  transformed_files: &mut [Result<TransformedFile, String>],
+  config: &StyleXOptionsParams,
+) {
+  let mut minified_class_names = assign_minified_class_names(
+    config,
+    transformed_files
+      .iter()
+      .flatten()
+      .flat_map(|transformed_file| transformed_file.class_names.iter().map(String::as_str)),
+  );
+
+  for transformed_file in transformed_files.iter_mut().flatten() {
+    transformed_file
+      .module
+      .visit_mut_with(&mut minified_class_names);
+
+    for rule in transformed_file.metadata.iter_mut() {
+      minified_class_names.rename_rule(rule);
+    }
+  }
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), String> {
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir).map_err(|error| error.to_string())?;
+  }
+
+  fs::write(path, content).map_err(|error| error.to_string())
+}
+
+fn read_config(config_path: Option<&Path>) -> StyleXOptionsParams {
+  let mut config = match config_path {
+    Some(config_path) => {
+      let config = fs::read_to_string(config_path).unwrap_or_else(|error| {
+        eprintln!("Error reading config {}: {}", config_path.display(), error);
+        process::exit(1);
+      });
+
+      serde_json::from_str::<StyleXOptionsParams>(&config).unwrap_or_else(|error| {
+        eprintln!("Invalid config {}: {}", config_path.display(), error);
+        process::exit(1);
+      })
+    }
+    None => StyleXOptionsParams::default(),
+  };
+
+  // Styles are collected into a single stylesheet, so they must not be injected at runtime
+  // and the metadata comment would only bloat the output.
+  config.runtime_injection = Some(false);
+  config.metadata.get_or_insert_with(Default::default).comment = Some(false);
+
+  config
+}
+
+/// Prints the stylesheet of the rules read as JSON from stdin, i.e. the `MetaData` that bundler
+/// integrations collect from the metadata of the transformed files.
+fn print_stylesheet(options: &StyleSheetOptions) {
+  let rules =
+    serde_json::from_reader::<_, Vec<MetaData>>(io::stdin().lock()).unwrap_or_else(|error| {
+      eprintln!("Invalid rules: {}", error);
+      process::exit(1);
+    });
+
+  match process_stylex_rules(&rules, options) {
+    Ok(stylesheet) => print!("{}", stylesheet),
+    Err(error) => {
+      eprintln!("{}", error);
+      process::exit(1);
+    }
+  }
+}
+
+fn main() {
+  let cli = Cli::parse();
+
+  let stylesheet_options = StyleSheetOptions {
+    use_css_layers: Some(cli.use_css_layers),
+    ..Default::default()
+  };
+
+  if cli.process_rules {
+    print_stylesheet(&stylesheet_options);
+
+    return;
+  }
+
+  // Transform errors are reported through the diagnostic handler and collected per file,
+  // so the default panic output would only duplicate them. Other panics are still printed.
+  let default_hook = panic::take_hook();
+
+  panic::set_hook(Box::new(move |info| {
+    if !IS_TRANSFORMING.with(Cell::get) {
+      default_hook(info);
+    }
+  }));
+
+  let cwd = std::env::current_dir().expect("Error getting current directory");
+  let input_dir = cwd.join(cli.input.expect("Input directory is required"));
+  let out_dir = cwd.join(&cli.out_dir);
+
+  let config = read_config(cli.config.as_deref());
+
+  let file_paths = read_source_files(&input_dir, &out_dir);
+
+  // Minified names are assigned once every file is transformed, so the files are transformed
+  // with the hashed class names.
+  let mut transform_config = config.clone();
+
+  transform_config.minify_class_names = Some(false);
+
+  let mut transformed_files = file_paths
+    .par_iter()
+    .map(|file_path| {
+      GLOBALS.set(&Globals::new(), || {
+        transform_file(file_path, &cwd, &transform_config)
+      })
+    })
+    .collect::<Vec<Result<TransformedFile, String>>>();
+
+  if config.minify_class_names.unwrap_or(false) {
+    minify_class_names(&mut transformed_files, &config);
+  }
+
+  let results = file_paths
+    .par_iter()
+    .zip(transformed_files)
+    .map(|(file_path, transformed_file)| {
+      let mut transformed_file = transformed_file?;
+      let metadata = mem::take(&mut transformed_file.metadata);
+      let code = emit_file(transformed_file)?;
+
+      let relative_path = file_path.strip_prefix(&input_dir).unwrap_or(file_path);
+
+      write_file(&out_dir.join(output_path(relative_path)), &code)?;
+
+      Ok(metadata)
+    })
+    .collect::<Vec<Result<Vec<MetaData>, String>>>();
+
+  let mut has_errors = false;
+  let mut metadata = vec![];
+
+  for (file_path, result) in file_paths.iter().zip(results) {
+    match result {
+      Ok(file_metadata) => metadata.extend(file_metadata),
+      Err(error) => {
+        has_errors = true;
+        eprintln!("Error compiling file {}: {}", file_path.display(), error);
+      }
+    }
+  }
+
+  let stylesheet = process_stylex_rules(&metadata, &stylesheet_options).unwrap_or_else(|error| {
+    eprintln!("Error generating stylesheet: {}", error);
+    process::exit(1);
+  });
+
+  if let Err(error) = write_file(&out_dir.join(&cli.css_file), &stylesheet) {
+    eprintln!("Error writing stylesheet: {}", error);
+    process::exit(1);
+  }
+
+  if has_errors {
+    process::exit(1);
+  }
+}
@@ -0,0 +1,351 @@
+use std::{
+  fs,
+  io::Write,
+  path::Path,
+  process::{Command, Output, Stdio},
+};
+
+use tempfile::TempDir;
+
+fn write(dir: &Path, file_name: &str, content: &str) {
+  let path = dir.join(file_name);
+
+  fs::create_dir_all(path.parent().unwrap()).unwrap();
+  fs::write(path, content).unwrap();
+}
+
+fn compile(files: &[(&str, &str)]) -> (TempDir, Output) {
+  compile_with_config(files, None)
+}
+
+fn compile_with_config(files: &[(&str, &str)], config: Option<&str>) -> (TempDir, Output) {
+  let dir = tempfile::tempdir().unwrap();
+
+  for (file_name, content) in files {
+    write(&dir.path().join("src"), file_name, content);
+  }
+
+  let mut command = Command::new(env!("CARGO_BIN_EXE_stylex-swc"));
+
+  command
+    .current_dir(dir.path())
+    .args(["./src", "--out-dir", "./dist"]);
+
+  if let Some(config) = config {
+    write(dir.path(), "stylex.config.json", config);
+    command.args(["--config", "./stylex.config.json"]);
+  }
+
+  let output = command.output().unwrap();
+
+  (dir, output)
+}
+
+fn read(dir: &TempDir, file_name: &str) -> String {
+  fs::read_to_string(dir.path().join("dist").join(file_name))
+    .unwrap_or_else(|error| panic!("Failed to read {}: {}", file_name, error))
+}
+
+#[test]
+fn compiles_source_tree_and_collects_stylesheet() {
+  let (dir, output) = compile(&[(
+    "components/Card.js",
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+
+      const styles = stylex.create({
+        root: {
+          color: 'red',
+        },
+      });
+
+      export const className = stylex.props(styles.root).className;
+    "#,
+  )]);
+
+  assert!(output.status.success(), "{:?}", output);
+
+  let code = read(&dir, "components/Card.js");
+
+  assert!(!code.contains("stylex.create"), "{}", code);
+  assert!(read(&dir, "stylex.css").contains("{color:red}"));
+}
+
+#[test]
+fn strips_typescript_and_emits_javascript() {
+  let (dir, output) = compile(&[
+    (
+      "Button.tsx",
+      r#"
+        import * as stylex from '@stylexjs/stylex';
+        import type { ReactNode } from 'react';
+        import { Size, sizes } from './sizes';
+
+        interface Props {
+          children?: ReactNode;
+          size: Size;
+        }
+
+        const styles = stylex.create({
+          root: {
+            padding: 4,
+          },
+        });
+
+        export function Button<T>({ children, size }: Props): JSX.Element {
+          const padding = sizes[size] as number;
+
+          return <button {...stylex.props(styles.root)} data-padding={padding!}>{children}</button>;
+        }
+
+        export type { Props };
+      "#,
+    ),
+    (
+      "sizes.ts",
+      r#"
+        export type Size = 'small' | 'large';
+
+        export const sizes: Record<Size, number> = {
+          small: 4,
+          large: 8,
+        } satisfies Record<Size, number>;
+      "#,
+    ),
+  ]);
+
+  assert!(output.status.success(), "{:?}", output);
+
+  assert!(!dir.path().join("dist/Button.tsx").exists());
+  assert!(!dir.path().join("dist/sizes.ts").exists());
+
+  let button = read(&dir, "Button.jsx");
+
+  assert!(
+    button.contains("import { sizes } from './sizes';"),
+    "{}",
+    button
+  );
+  assert!(
+    button.contains("export function Button({ children, size }) {"),
+    "{}",
+    button
+  );
+  assert!(
+    button.contains("const padding = sizes[size];"),
+    "{}",
+    button
+  );
+  assert!(button.contains("data-padding={padding}"), "{}", button);
+
+  for type_syntax in [
+    "interface",
+    "ReactNode",
+    "Props",
+    "Size,",
+    "<T>",
+    " as ",
+    "JSX.Element",
+  ] {
+    assert!(!button.contains(type_syntax), "{}", button);
+  }
+
+  let sizes = read(&dir, "sizes.js");
+
+  assert!(sizes.contains("export const sizes = {"), "{}", sizes);
+  assert!(!sizes.contains("type"), "{}", sizes);
+  assert!(!sizes.contains("satisfies"), "{}", sizes);
+
+  assert!(read(&dir, "stylex.css").contains("{padding:4px}"));
+}
+
+#[test]
+fn compiles_typescript_that_generates_code() {
+  let (dir, output) = compile(&[(
+    "Button.ts",
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      import path = require('path');
+
+      export enum Color {
+        Red = 'red',
+      }
+
+      export namespace Sizes {
+        export const small = 4;
+      }
+
+      export class Button {
+        constructor(private readonly color: Color) {}
+
+        get file() {
+          return path.basename(this.color);
+        }
+      }
+
+      export const styles = stylex.create({
+        root: {
+          color: Color.Red,
+        },
+      });
+    "#,
+  )]);
+
+  assert!(output.status.success(), "{:?}", output);
+
+  let code = read(&dir, "Button.js");
+
+  assert!(code.contains(r#"Color["Red"] = "red";"#), "{}", code);
+  assert!(code.contains("Sizes.small = 4;"), "{}", code);
+  assert!(code.contains("this.color = color;"), "{}", code);
+  assert!(code.contains("const path = require('path');"), "{}", code);
+  assert!(!code.contains("private"), "{}", code);
+
+  assert!(read(&dir, "stylex.css").contains("{color:red}"));
+}
+
+#[test]
+fn assigns_minified_class_names_in_file_order() {
+  let files = (0..8)
+    .map(|index| {
+      (
+        format!("Component{}.js", index),
+        format!(
+          r#"
+            import * as stylex from '@stylexjs/stylex';
+
+            export const styles = stylex.create({{
+              root: {{
+                zIndex: {},
+                color: 'red',
+              }},
+            }});
+          "#,
+          index
+        ),
+      )
+    })
+    .collect::<Vec<(String, String)>>();
+
+  let files = files
+    .iter()
+    .map(|(file_name, content)| (file_name.as_str(), content.as_str()))
+    .collect::<Vec<(&str, &str)>>();
+
+  let (dir, output) = compile_with_config(
+    &files,
+    Some(r#"{ "minifyClassNames": true, "classNameManifest": "./class-names.json" }"#),
+  );
+
+  assert!(output.status.success(), "{:?}", output);
+
+  // `color: red` is first seen in the first file, after its `zIndex`.
+  assert!(read(&dir, "Component0.js").contains(r#"zIndex: "a""#));
+  assert!(read(&dir, "Component0.js").contains(r#"color: "b""#));
+
+  for index in 1..8 {
+    let code = read(&dir, &format!("Component{}.js", index));
+
+    assert!(
+      code.contains(&format!(r#"zIndex: "{}""#, (b'b' + index as u8) as char)),
+      "{}",
+      code
+    );
+    assert!(code.contains(r#"color: "b""#), "{}", code);
+  }
+
+  let stylesheet = read(&dir, "stylex.css");
+
+  assert!(stylesheet.contains(".b{color:red}"), "{}", stylesheet);
+  assert!(stylesheet.contains(".i{z-index:7}"), "{}", stylesheet);
+  assert!(!stylesheet.contains(".x"), "{}", stylesheet);
+
+  let manifest: serde_json::Value =
+    serde_json::from_str(&fs::read_to_string(dir.path().join("class-names.json")).unwrap())
+      .unwrap();
+
+  assert_eq!(manifest["nextIndex"], 9);
+}
+
+#[test]
+fn prints_stylesheet_of_rules_from_stdin() {
+  let mut child = Command::new(env!("CARGO_BIN_EXE_stylex-swc"))
+    .args(["--process-rules", "--use-css-layers"])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .unwrap();
+
+  child
+    .stdin
+    .take()
+    .unwrap()
+    .write_all(
+      br#"[
+        { "class_name": "x1e2nbdu", "style": { "ltr": ".x1e2nbdu{color:red}" }, "priority": 3000 },
+        { "class_name": "x1e2nbdu", "style": { "ltr": ".x1e2nbdu{color:red}" }, "priority": 3000 },
+        { "class_name": "x1lliihq", "style": { "ltr": ".x1lliihq{display:block}" }, "priority": 3000 }
+      ]"#,
+    )
+    .unwrap();
+
+  let output = child.wait_with_output().unwrap();
+
+  assert!(output.status.success(), "{:?}", output);
+
+  let stylesheet = String::from_utf8(output.stdout).unwrap();
+
+  assert!(
+    stylesheet.contains("@layer priority1;\n@layer priority1{"),
+    "{}",
+    stylesheet
+  );
+  assert_eq!(
+    stylesheet.matches(".x1e2nbdu{color:red}").count(),
+    1,
+    "{}",
+    stylesheet
+  );
+  assert!(
+    stylesheet.contains(".x1lliihq{display:block}"),
+    "{}",
+    stylesheet
+  );
+}
+
+#[test]
+fn reports_class_name_collisions_of_rules_from_stdin() {
+  let mut child = Command::new(env!("CARGO_BIN_EXE_stylex-swc"))
+    .arg("--process-rules")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .unwrap();
+
+  child
+    .stdin
+    .take()
+    .unwrap()
+    .write_all(
+      br#"[
+        { "class_name": "x1e2nbdu", "style": { "ltr": ".x1e2nbdu{color:red}" }, "priority": 3000 },
+        { "class_name": "x1e2nbdu", "style": { "ltr": ".x1e2nbdu{color:blue}" }, "priority": 3000 }
+      ]"#,
+    )
+    .unwrap();
+
+  let output = child.wait_with_output().unwrap();
+
+  assert!(!output.status.success(), "{:?}", output);
+
+  let stderr = String::from_utf8(output.stderr).unwrap();
+
+  assert!(
+    stderr.contains(
+      "\"x1e2nbdu\" is generated by both `.x1e2nbdu{color:red}` and `.x1e2nbdu{color:blue}`."
+    ),
+    "{}",
+    stderr
+  );
+}
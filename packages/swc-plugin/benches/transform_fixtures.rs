@@ -0,0 +1,158 @@
+//! Transforms the inputs of `tests/fixture` repeatedly and reports the wall-clock time and the
+//! heap allocations of one transform. Parsing and name resolution are done once, outside of the
+//! measurement.
+//!
+//! ```sh
+//! cargo bench --bench transform_fixtures
+//! ```
+
+use std::{
+  alloc::{GlobalAlloc, Layout, System},
+  fs,
+  path::Path,
+  sync::atomic::{AtomicUsize, Ordering},
+  time::{Duration, Instant},
+};
+
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{comments::SingleThreadedComments, FileName, Globals, Mark, SourceMap, GLOBALS},
+  ecma::{
+    ast::Module,
+    parser::{Parser, StringInput, Syntax, TsConfig},
+    transforms::base::resolver,
+    visit::{FoldWith, VisitMutWith},
+  },
+};
+
+const RUNS: u32 = 200;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout)
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+
+    System.realloc(ptr, layout, new_size)
+  }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// The same options as the `tests/fixtures.rs` snapshots.
+fn options() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    dev: Some(true),
+    treeshake_compensation: Some(true),
+    unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+fn parse(input_path: &Path) -> Module {
+  let cm: SourceMap = Default::default();
+  let fm = cm.new_source_file(
+    FileName::Real(input_path.to_path_buf()),
+    fs::read_to_string(input_path).unwrap(),
+  );
+
+  let module = Parser::new(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    StringInput::from(&*fm),
+    None,
+  )
+  .parse_module()
+  .expect("Failed to parse fixture");
+
+  module.fold_with(&mut resolver(Mark::new(), Mark::new(), false))
+}
+
+fn transform(module: &mut Module) {
+  let mut visitor = ModuleTransformVisitor::new_test_styles(
+    SingleThreadedComments::default(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/app/pages/Page.stylex.tsx".into()),
+    },
+    Some(&mut options()),
+  );
+
+  module.visit_mut_with(&mut visitor);
+}
+
+fn main() {
+  let fixtures_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixture");
+
+  let mut fixture_dirs = fs::read_dir(fixtures_dir)
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .filter(|path| path.join("input.js").exists())
+    .collect::<Vec<_>>();
+
+  fixture_dirs.sort();
+
+  println!(
+    "{:<20} {:>12} {:>14} {:>14}",
+    "fixture", "time/run", "allocs/run", "bytes/run"
+  );
+
+  GLOBALS.set(&Globals::new(), || {
+    for fixture_dir in fixture_dirs {
+      let module = parse(&fixture_dir.join("input.js"));
+
+      // Warms up the caches shared between transforms, e.g. the resolved modules.
+      transform(&mut module.clone());
+
+      let mut elapsed = Duration::ZERO;
+      let mut allocations = 0;
+      let mut allocated_bytes = 0;
+
+      for _ in 0..RUNS {
+        let mut module = module.clone();
+
+        let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+        let allocated_bytes_before = ALLOCATED_BYTES.load(Ordering::Relaxed);
+        let start = Instant::now();
+
+        transform(&mut module);
+
+        elapsed += start.elapsed();
+        allocations += ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+        allocated_bytes += ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes_before;
+      }
+
+      println!(
+        "{:<20} {:>10.1}µs {:>14} {:>14}",
+        fixture_dir.file_name().unwrap().to_string_lossy(),
+        elapsed.as_secs_f64() * 1e6 / RUNS as f64,
+        allocations / RUNS as usize,
+        allocated_bytes / RUNS as usize,
+      );
+    }
+  });
+}
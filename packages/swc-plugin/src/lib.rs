@@ -3,12 +3,18 @@ pub(crate) mod transform;
 
 use std::env;
 
+pub use shared::structures::class_name_manifest::{
+  assign_minified_class_names, MinifiedClassNames,
+};
 use shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams};
+pub use shared::utils::core::process_stylex_rules::{
+  process_stylex_rules, process_stylex_rules_with_source_map, ClassNameCollision, StyleSheet,
+};
 pub use transform::ModuleTransformVisitor;
 
 use swc_core::{
-  common::FileName,
-  ecma::{ast::Program, visit::FoldWith},
+  common::{sync::Lrc, FileName},
+  ecma::{ast::Program, visit::VisitMutWith},
   plugin::{
     metadata::TransformPluginMetadataContextKind,
     plugin_transform,
@@ -18,7 +24,7 @@ use swc_core::{
 
 #[plugin_transform]
 pub(crate) fn process_transform(
-  program: Program,
+  mut program: Program,
   metadata: TransformPluginProgramMetadata,
 ) -> Program {
   let mut config = serde_json::from_str::<StyleXOptionsParams>(
@@ -42,7 +48,10 @@ pub(crate) fn process_transform(
   let plugin_pass = Box::new(PluginPass { cwd, filename });
 
   let mut stylex: ModuleTransformVisitor<PluginCommentsProxy> =
-    ModuleTransformVisitor::new(PluginCommentsProxy, plugin_pass, &mut config);
+    ModuleTransformVisitor::new(PluginCommentsProxy, plugin_pass, &mut config)
+      .with_source_map(Lrc::new(metadata.source_map));
+
+  program.visit_mut_with(&mut stylex);
 
-  program.fold_with(&mut stylex)
+  program
 }
@@ -19,6 +19,10 @@ pub(crate) static INVALID_METHODS: phf::Set<&'static str> = phf_set! {
   "splice",
 };
 
+/// Limits of the evaluation of the functions of the transformed file, to stop runaway recursion.
+pub(crate) static MAX_FUNCTION_CALL_DEPTH: usize = 32;
+pub(crate) static MAX_FUNCTION_STEPS: usize = 10_000;
+
 pub(crate) static COMPILED_KEY: &str = "$$css";
 
 pub(crate) static SPLIT_TOKEN: &str = "__$$__";
@@ -29,8 +29,9 @@ pub(crate) static ILLEGAL_PROP_VALUE: &str =
   "A style value can only contain an array, string or number.";
 pub(crate) static ILLEGAL_PROP_ARRAY_VALUE: &str =
   "A style array value can only contain strings or numbers.";
+
+pub(crate) static ILLEGAL_PROP_ARRAY_SPREAD: &str = "A style array value cannot contain spreads.";
 pub(crate) static ILLEGAL_NAMESPACE_VALUE: &str = "A stylex namespace must be an object.";
-#[allow(dead_code)]
 pub(crate) static INVALID_SPREAD:&str =  "Imported styles spread with a stylex.create call must be type cast as `XStyle` to verify their type.";
 #[allow(dead_code)]
 pub(crate) static LOCAL_ONLY: &str = "The return value of stylex.create() should not be exported.";
@@ -53,7 +54,10 @@ pub(crate) static NON_EXPORT_NAMED_DECLARATION: &str =
   "The return value of stylex.defineVars() must be bound to a named export.";
 #[allow(dead_code)]
 pub(crate) static ANONYMOUS_THEME: &str = "stylex.createTheme() must be bound to a named constant.";
-pub(crate) static ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS:&str =  "Only named parameters are allowed in Dynamic Style functions. Destructuring, spreading or default values are not allowed.";
+pub(crate) static NO_REST_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS: &str =
+  "Rest parameters are not allowed in Dynamic Style functions.";
+pub(crate) static INVALID_DYNAMIC_STYLE_FUNCTION_BODY: &str =
+  "Dynamic Style functions can only declare variables before returning a style object.";
 pub(crate) static BUILT_IN_FUNCTION: &str = "Evaluation built-in functions not supported";
 
 pub(crate) static NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL: &str =
@@ -68,5 +72,43 @@ pub(crate) static NON_OBJECT_KEYFRAME: &str =
 pub(crate) static LINT_UNCLOSED_FUNCTION: &str = "Rule contains an unclosed function";
 pub(crate) static UNPREFIXED_CUSTOM_PROPERTIES: &str = "Unprefixed custom properties";
 
+pub(crate) static INVALID_THEME_VARIABLES: &str =
+  "Can only override variables theme created with stylex.defineVars().";
+
+pub(crate) static UNDEFINED_THEME_VARIABLE: &str =
+  "Variable is not defined by stylex.defineVars() in the imported theme file:";
+
+pub(crate) static UNEXPORTED_THEME_VARS: &str =
+  "Imported theme variables are not exported with stylex.defineVars():";
+
 pub(crate) static NON_CONTIGUOUS_VARS: &str =
   "All variables passed to `stylex.firstThatWorks` must be contiguous.";
+
+pub(crate) static INVALID_CSS_TEMPLATE: &str =
+  "A css`` template can only contain CSS declarations, nested pseudo selectors and at-rules.";
+
+pub(crate) static INVALID_CSS_TEMPLATE_SELECTOR: &str =
+  "Nested selectors of a css`` template must be a pseudo selector of `&`, e.g. `&:hover`.";
+
+pub(crate) static CLASS_NAME_HASH_COLLISION: &str =
+  "Class name hash collision, use `hashAlgorithm: \"murmur64\"` or a larger `minHashLength`:";
+
+pub(crate) static MISSING_MINIFIED_CLASS_NAME: &str =
+  "Class name has no minified name, assign the class names of every file with `assign_minified_class_names` before transforming them:";
+
+pub(crate) static FUNCTION_EVALUATION_LIMIT: &str =
+  "Function call could not be statically evaluated, it exceeds the recursion or step limit.";
+
+pub(crate) static UNKNOWN_HASTE_MODULE: &str =
+  "Imported haste module is not provided by any file in the root directory:";
+
+pub(crate) static AMBIGUOUS_HASTE_MODULE: &str =
+  "Imported haste module is provided by more than one file in the root directory:";
+
+pub(crate) static UNKNOWN_MODULE_RESOLUTION_TYPE: &str =
+  "`unstable_moduleResolution.type` must be \"commonJS\", \"haste\" or \"cross-file-parsing\", got";
+
+pub(crate) static MODULE_RESOLUTION_ROOT_DIR_REQUIRED: &str =
+  "`unstable_moduleResolution.rootDir` is required by the module resolution";
+
+pub(crate) static INVALID_JSON_MODULE: &str = "Imported JSON module could not be parsed:";
@@ -58,17 +58,18 @@ impl Shorthands {
 
   pub(crate) fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
     match name {
+      "all" => Some(Shorthands::all),
       "animation" => Some(Shorthands::animation),
       "background" => Some(Shorthands::background),
       "border" => Some(Shorthands::border),
-      "border_inline" => Some(Shorthands::border_inline),
-      "border_block" => Some(Shorthands::border_block),
-      "border_top" => Some(Shorthands::border_top),
-      "border_inline_end" => Some(Shorthands::border_inline_end),
-      "border_right" => Some(Shorthands::border_right),
-      "border_bottom" => Some(Shorthands::border_bottom),
-      "border_inline_start" => Some(Shorthands::border_inline_start),
-      "border_left" => Some(Shorthands::border_left),
+      "borderInline" => Some(Shorthands::border_inline),
+      "borderBlock" => Some(Shorthands::border_block),
+      "borderTop" => Some(Shorthands::border_top),
+      "borderInlineEnd" => Some(Shorthands::border_inline_end),
+      "borderRight" => Some(Shorthands::border_right),
+      "borderBottom" => Some(Shorthands::border_bottom),
+      "borderInlineStart" => Some(Shorthands::border_inline_start),
+      "borderLeft" => Some(Shorthands::border_left),
       _ => None,
     }
   }
@@ -243,17 +244,17 @@ impl Aliases {
   pub(crate) fn get(name: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
     match name {
       // @Deprecated
-      "borderHorizontal" => Shorthands::get("borderHorizontal"),
+      "borderHorizontal" => Shorthands::get("borderInline"),
       // @Deprecated
-      "borderVertical" => Shorthands::get("borderVertical"),
+      "borderVertical" => Shorthands::get("borderBlock"),
       // @Deprecated
-      "borderBlockStart" => Shorthands::get("borderBlockStart"),
+      "borderBlockStart" => Shorthands::get("borderTop"),
       // @Deprecated
-      "borderEnd" => Shorthands::get("borderEnd"),
+      "borderEnd" => Shorthands::get("borderInlineEnd"),
       // @Deprecated
-      "borderBlockEnd" => Shorthands::get("borderBlockEnd"),
+      "borderBlockEnd" => Shorthands::get("borderBottom"),
       // @Deprecated
-      "borderStart" => Shorthands::get("borderStart"),
+      "borderStart" => Shorthands::get("borderInlineStart"),
 
       "blockSize" => Some(Aliases::block_size),
       "inlineSize" => Some(Aliases::inline_size),
@@ -1,14 +1,11 @@
 // Represents the current state of a plugin for a file.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Copy)]
 pub(crate) enum ModuleCycle {
-  // The plugin is being processed.
-  TransformEnter,
-  TransformExit,
-  // The plugin has been processed and the file is being cleaned.
-  PreCleaning,
-  Cleaning,
-  // The file has been processed and the plugin is skipped.
+  // The module is analysed: imports, declarations and identifier counts are collected.
   Initializing,
+  // The module is rewritten in a single traversal, after the top-level definitions are compiled.
+  // The top-level items are cleaned once the traversal ends.
+  Transform,
+  // The file has been processed and the plugin is skipped.
   Skip,
-  InjectStyles,
 }
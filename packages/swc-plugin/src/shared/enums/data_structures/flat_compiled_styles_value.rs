@@ -17,6 +17,7 @@ pub(crate) enum FlatCompiledStylesValue {
   Bool(bool),
   Tuple(String, Box<Expr>, Option<BaseCSSType>),
   CSSType(String, CSSSyntax, String),
+  Expr(Box<Expr>),
 }
 
 impl FlatCompiledStylesValue {
@@ -0,0 +1,308 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  fs,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+  time::SystemTime,
+};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use swc_core::ecma::{
+  ast::Str,
+  visit::{noop_visit_mut_type, VisitMut},
+};
+
+use super::{
+  meta_data::MetaData, stylex_options::StyleXOptionsParams,
+  stylex_state_options::StyleXStateOptions,
+};
+
+/// First character of a minified class name. Lowercase only, since class selectors are
+/// case-insensitive in quirks mode.
+const FIRST_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const OTHER_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Modification time of a manifest file together with the manifest loaded from it.
+type CachedManifest = (Option<SystemTime>, Arc<Mutex<ClassNameManifest>>);
+
+/// Manifests shared by every file transformed in this process, by path. Without a path the
+/// manifest is only kept in memory.
+static MANIFESTS: Lazy<DashMap<Option<PathBuf>, CachedManifest>> = Lazy::new(DashMap::new);
+
+/// Minified names of the hashed atomic class names, in the order they were assigned.
+///
+/// Names are assigned sequentially (`a`, `b`, …, `z`, `aa`, `ab`, …) by
+/// [`assign_minified_class_names`] and never reassigned, so a persisted manifest keeps the output
+/// of later and incremental builds stable. Transforms only read it.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClassNameManifest {
+  /// Index of the next name to assign.
+  next_index: usize,
+  /// Minified name of every hashed class name.
+  class_names: BTreeMap<String, String>,
+  #[serde(skip)]
+  modified: bool,
+}
+
+impl ClassNameManifest {
+  /// Manifest used to minify class names with `options`, if enabled.
+  pub(crate) fn from_options(options: &StyleXStateOptions) -> Option<Arc<Mutex<Self>>> {
+    if !options.minify_class_names {
+      return None;
+    }
+
+    Some(Self::shared(
+      options.class_name_manifest.as_deref().map(Path::new),
+    ))
+  }
+
+  /// Returns the manifest persisted at `path`, shared with the other files of the build.
+  ///
+  /// It is loaded again when the file changes. A missing file starts an empty manifest,
+  /// an unreadable one aborts the build.
+  pub(crate) fn shared(path: Option<&Path>) -> Arc<Mutex<Self>> {
+    let modified = path
+      .and_then(|path| fs::metadata(path).ok())
+      .and_then(|metadata| metadata.modified().ok());
+
+    let load = || Arc::new(Mutex::new(path.map(Self::load).unwrap_or_default()));
+
+    let mut manifest = MANIFESTS
+      .entry(path.map(Path::to_path_buf))
+      .or_insert_with(|| (modified, load()));
+
+    if manifest.0 != modified {
+      *manifest = (modified, load());
+    }
+
+    manifest.1.clone()
+  }
+
+  /// Reads the manifest at `path`, rejecting manifests that give two class names the same
+  /// minified name, since their rules would override each other.
+  pub(crate) fn load(path: &Path) -> Self {
+    let Ok(content) = fs::read_to_string(path) else {
+      return Self::default();
+    };
+
+    let manifest: Self = serde_json::from_str(&content).unwrap_or_else(|error| {
+      panic!(
+        "Invalid StyleX class name manifest {}: {}",
+        path.display(),
+        error
+      )
+    });
+
+    let mut class_names_by_minified: HashMap<&str, &str> = HashMap::new();
+
+    for (class_name, minified) in &manifest.class_names {
+      if let Some(other) = class_names_by_minified.insert(minified, class_name) {
+        panic!(
+          "Invalid StyleX class name manifest {}: \"{}\" is assigned to both \"{}\" and \"{}\"",
+          path.display(),
+          minified,
+          other,
+          class_name
+        );
+      }
+    }
+
+    manifest
+  }
+
+  /// Writes the manifest to `path` if names were assigned since it was loaded or last saved.
+  pub(crate) fn save(&mut self, path: &Path) {
+    if !self.modified {
+      return;
+    }
+
+    if let Some(dir) = path.parent() {
+      fs::create_dir_all(dir).unwrap_or_else(|error| {
+        panic!(
+          "Failed to create StyleX class name manifest directory {}: {}",
+          dir.display(),
+          error
+        )
+      });
+    }
+
+    fs::write(path, serde_json::to_string_pretty(self).unwrap()).unwrap_or_else(|error| {
+      panic!(
+        "Failed to write StyleX class name manifest to {}: {}",
+        path.display(),
+        error
+      )
+    });
+
+    self.modified = false;
+  }
+
+  /// Minified name of `class_name`, if one was assigned.
+  pub(crate) fn get(&self, class_name: &str) -> Option<&String> {
+    self.class_names.get(class_name)
+  }
+
+  /// Minified name of `class_name`, assigning the next free one on first use.
+  pub(crate) fn get_or_assign(&mut self, class_name: &str) -> String {
+    if let Some(minified) = self.class_names.get(class_name) {
+      return minified.clone();
+    }
+
+    let minified = loop {
+      let name = minified_class_name(self.next_index);
+
+      self.next_index += 1;
+
+      // Ad blockers hide elements with classes like `ad` or `ads`.
+      if !name.starts_with("ad") {
+        break name;
+      }
+    };
+
+    self
+      .class_names
+      .insert(class_name.to_string(), minified.clone());
+
+    self.modified = true;
+
+    minified
+  }
+}
+
+/// Assigns minified names to `class_names`, in order, and saves the manifest configured
+/// by `options`.
+///
+/// Transforms only read the manifest, so hosts assign every name before the files are
+/// transformed with `minifyClassNames`, in a stable order: the output is then the same on
+/// every run, however the files are scheduled, and the manifest is only written here.
+///
+/// Hosts that already transformed the files with `minifyClassNames` disabled can instead
+/// rename their output with the returned names.
+pub fn assign_minified_class_names<'a>(
+  options: &StyleXOptionsParams,
+  class_names: impl IntoIterator<Item = &'a str>,
+) -> MinifiedClassNames {
+  let path = options.class_name_manifest.as_deref().map(Path::new);
+  let manifest = ClassNameManifest::shared(path);
+  let mut manifest = manifest.lock().unwrap();
+
+  let class_names = class_names
+    .into_iter()
+    .map(|class_name| (class_name.to_string(), manifest.get_or_assign(class_name)))
+    .collect();
+
+  if let Some(path) = path {
+    manifest.save(path);
+  }
+
+  MinifiedClassNames { class_names }
+}
+
+/// Minified names returned by [`assign_minified_class_names`], by hashed class name.
+///
+/// Renames string literals of a module transformed with `minifyClassNames` disabled when used
+/// as a visitor, e.g. `"x1e2nbdu x1t391ir"` becomes `"a b"`.
+#[derive(Debug, Default, Clone)]
+pub struct MinifiedClassNames {
+  class_names: HashMap<String, String>,
+}
+
+impl MinifiedClassNames {
+  pub fn get(&self, class_name: &str) -> Option<&str> {
+    self.class_names.get(class_name).map(String::as_str)
+  }
+
+  /// Renames the class name of `rule` and its class selectors.
+  pub fn rename_rule(&self, rule: &mut MetaData) {
+    let Some(minified) = self.get(rule.get_class_name()) else {
+      return;
+    };
+
+    let class_name = rule.get_class_name().to_string();
+
+    rule.rename(minified, |css| {
+      rename_class_selectors(css, &class_name, minified)
+    });
+  }
+}
+
+impl VisitMut for MinifiedClassNames {
+  noop_visit_mut_type!();
+
+  fn visit_mut_str(&mut self, str: &mut Str) {
+    let mut renamed = false;
+
+    let value = str
+      .value
+      .split(' ')
+      .map(|class_name| match self.get(class_name) {
+        Some(minified) => {
+          renamed = true;
+          minified
+        }
+        None => class_name,
+      })
+      .collect::<Vec<&str>>()
+      .join(" ");
+
+    if renamed {
+      str.value = value.into();
+      str.raw = None;
+    }
+  }
+}
+
+/// Replaces the `.class_name` selectors of `css`, leaving longer class names such as `.x12`
+/// intact.
+fn rename_class_selectors(css: &str, class_name: &str, minified: &str) -> String {
+  let class_selector = format!(".{}", class_name);
+  let mut result = String::with_capacity(css.len());
+  let mut rest = css;
+
+  while let Some(index) = rest.find(&class_selector) {
+    let (before, after) = rest.split_at(index);
+    let after = &after[class_selector.len()..];
+
+    result.push_str(before);
+
+    if after.starts_with(|c: char| c.is_alphanumeric() || c == '-' || c == '_') {
+      result.push_str(&class_selector);
+    } else {
+      result.push('.');
+      result.push_str(minified);
+    }
+
+    rest = after;
+  }
+
+  result.push_str(rest);
+
+  result
+}
+
+/// The `index`-th shortest class name: `a`…`z`, then `aa`…`a9`, `ba`… and so on.
+pub(crate) fn minified_class_name(index: usize) -> String {
+  let mut index = index;
+  let mut length = 1;
+  let mut count = FIRST_CHARS.len();
+
+  while index >= count {
+    index -= count;
+    count *= OTHER_CHARS.len();
+    length += 1;
+  }
+
+  let mut chars = vec![0; length];
+
+  for char in chars.iter_mut().skip(1).rev() {
+    *char = OTHER_CHARS[index % OTHER_CHARS.len()];
+    index /= OTHER_CHARS.len();
+  }
+
+  chars[0] = FIRST_CHARS[index];
+
+  String::from_utf8(chars).unwrap()
+}
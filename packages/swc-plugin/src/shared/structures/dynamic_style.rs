@@ -0,0 +1,22 @@
+use indexmap::IndexMap;
+use swc_core::ecma::ast::{Expr, Pat, Stmt};
+
+/// A dynamic style function of a `stylex.create()` namespace, e.g.
+/// `(color, size = 4) => { const half = size / 2; return { color, width: half }; }`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DynamicStyle {
+  /// Parameters of the function, with their default values and destructuring.
+  pub(crate) params: Vec<Pat>,
+  /// Statements of a block body before its final `return`.
+  pub(crate) stmts: Vec<Stmt>,
+  /// Values of the CSS variables of the dynamic properties, by variable name.
+  pub(crate) inline_styles: IndexMap<String, Box<Expr>>,
+}
+
+impl DynamicStyle {
+  /// Whether the function can be applied at compile time by replacing its parameters
+  /// with the arguments of a call.
+  pub(crate) fn is_inlinable(&self) -> bool {
+    self.stmts.is_empty() && self.params.iter().all(|param| param.is_ident())
+  }
+}
@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
 use indexmap::IndexMap;
-use swc_core::ecma::ast::{Expr, Id, KeyValueProp, Lit};
+use swc_core::{
+  common::{Span, Spanned},
+  ecma::ast::{Expr, Id, KeyValueProp, Lit},
+};
 
 use crate::shared::enums::data_structures::evaluate_result_value::EvaluateResultValue;
 
@@ -20,6 +23,18 @@ pub struct EvaluateResult {
   pub(crate) fns: Option<EvaluateResultFns>,
 }
 
+impl EvaluateResult {
+  /// Span of the expression that stopped static evaluation, or `fallback` when it is unknown.
+  pub(crate) fn deopt_span(&self, fallback: Span) -> Span {
+    self
+      .deopt
+      .as_ref()
+      .map(|deopt| deopt.span())
+      .filter(|span| !span.is_dummy())
+      .unwrap_or(fallback)
+  }
+}
+
 impl EvaluateResultValue {
   pub fn as_expr(&self) -> Option<&Expr> {
     match self {
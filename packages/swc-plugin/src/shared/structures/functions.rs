@@ -4,7 +4,7 @@ use swc_core::ecma::ast::{Expr, Id};
 
 use crate::shared::enums::{
   data_structures::value_with_default::ValueWithDefault,
-  js::{ArrayJS, MathJS, ObjectJS, StringJS},
+  js::{ArrayJS, ObjectJS, StringJS},
 };
 
 use super::{
@@ -16,7 +16,6 @@ use super::{
 pub enum CallbackType {
   Array(ArrayJS),
   Object(ObjectJS),
-  Math(MathJS),
   String(StringJS),
 }
 
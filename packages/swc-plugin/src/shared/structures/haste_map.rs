@@ -0,0 +1,213 @@
+use std::{
+  collections::HashMap,
+  fs,
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::SystemTime,
+};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+
+use crate::shared::constants::messages::{AMBIGUOUS_HASTE_MODULE, UNKNOWN_HASTE_MODULE};
+
+use super::state_manager::EXTENSIONS;
+
+/// Haste maps shared by every file transformed in this process, by root directory.
+static HASTE_MAPS: Lazy<DashMap<PathBuf, Arc<HasteMap>>> = Lazy::new(DashMap::new);
+
+/// Directories that never contain haste modules.
+const IGNORED_DIRS: [&str; 1] = ["node_modules"];
+
+/// Why a haste module name could not be resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum HasteMapError {
+  Unknown(String),
+  Ambiguous(String, Vec<PathBuf>),
+}
+
+impl std::fmt::Display for HasteMapError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      HasteMapError::Unknown(name) => write!(f, "{} \"{}\"", UNKNOWN_HASTE_MODULE, name),
+      HasteMapError::Ambiguous(name, paths) => write!(
+        f,
+        "{} \"{}\" is provided by {}",
+        AMBIGUOUS_HASTE_MODULE,
+        name,
+        paths
+          .iter()
+          .map(|path| path.display().to_string())
+          .collect::<Vec<String>>()
+          .join(", ")
+      ),
+    }
+  }
+}
+
+/// Modules of a haste project, by name.
+///
+/// A module is named by the `@providesModule` tag of its leading docblock,
+/// or otherwise by its file name without the extension, e.g. `colors.stylex` for `colors.stylex.js`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct HasteMap {
+  /// Files of every module name, more than one means the name is ambiguous.
+  modules: HashMap<String, Vec<PathBuf>>,
+  /// Indexed directories with their modification time, which changes when files are added,
+  /// removed or renamed.
+  dirs: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl HasteMap {
+  /// Returns the haste map of `root_dir`, shared with the other files of the build.
+  pub(crate) fn shared(root_dir: &Path) -> Arc<Self> {
+    HASTE_MAPS
+      .entry(root_dir.to_path_buf())
+      .or_insert_with(|| Arc::new(Self::build(root_dir)))
+      .clone()
+  }
+
+  /// Resolves `name` in the shared haste map of `root_dir`.
+  ///
+  /// When the name is unknown or its file is gone, and files were added, removed or renamed
+  /// since the map was built, e.g. by a watch mode rebuild, the map is indexed again.
+  pub(crate) fn resolve_shared(root_dir: &Path, name: &str) -> Result<PathBuf, HasteMapError> {
+    let haste_map = Self::shared(root_dir);
+
+    let result = haste_map.resolve(name).map(Path::to_path_buf);
+
+    let is_missing = match &result {
+      Ok(path) => !path.exists(),
+      Err(error) => matches!(error, HasteMapError::Unknown(_)),
+    };
+
+    if !is_missing || !haste_map.is_stale() {
+      return result;
+    }
+
+    let haste_map = Arc::new(Self::build(root_dir));
+
+    HASTE_MAPS.insert(root_dir.to_path_buf(), haste_map.clone());
+
+    haste_map.resolve(name).map(Path::to_path_buf)
+  }
+
+  /// Whether an indexed directory changed since the map was built.
+  fn is_stale(&self) -> bool {
+    self
+      .dirs
+      .iter()
+      .any(|(dir, modified)| modified_time(dir) != *modified)
+  }
+
+  /// Indexes every JavaScript and TypeScript file under `root_dir`.
+  pub(crate) fn build(root_dir: &Path) -> Self {
+    let mut haste_map = Self::default();
+
+    haste_map.index_dir(root_dir);
+
+    for paths in haste_map.modules.values_mut() {
+      paths.sort();
+    }
+
+    haste_map
+  }
+
+  fn index_dir(&mut self, dir: &Path) {
+    self.dirs.push((dir.to_path_buf(), modified_time(dir)));
+
+    let Ok(entries) = fs::read_dir(dir) else {
+      return;
+    };
+
+    for entry in entries.flatten() {
+      let path = entry.path();
+      let file_name = entry.file_name().to_string_lossy().to_string();
+
+      if file_name.starts_with('.') {
+        continue;
+      }
+
+      match entry.file_type() {
+        Ok(file_type) if file_type.is_dir() && !IGNORED_DIRS.contains(&file_name.as_str()) => {
+          self.index_dir(&path);
+        }
+        Ok(file_type) if file_type.is_file() => {
+          if let Some(name) = module_name(&path) {
+            self.modules.entry(name).or_default().push(path);
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  /// Path of the module `name`, which may end with a file extension.
+  pub(crate) fn resolve(&self, name: &str) -> Result<&Path, HasteMapError> {
+    let paths = self
+      .modules
+      .get(name)
+      .or_else(|| self.modules.get(strip_extension(name)?))
+      .ok_or_else(|| HasteMapError::Unknown(name.to_string()))?;
+
+    match paths.as_slice() {
+      [path] => Ok(path),
+      _ => Err(HasteMapError::Ambiguous(name.to_string(), paths.clone())),
+    }
+  }
+
+  /// Path of the only module in a file named `file_name`.
+  pub(crate) fn find_file(&self, file_name: &str) -> Option<&Path> {
+    let mut paths = self
+      .modules
+      .values()
+      .flatten()
+      .filter(|path| path.file_name().is_some_and(|name| name == file_name));
+
+    match (paths.next(), paths.next()) {
+      (Some(path), None) => Some(path),
+      _ => None,
+    }
+  }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+  fs::metadata(path)
+    .and_then(|metadata| metadata.modified())
+    .ok()
+}
+
+/// Haste name of the module in `path`, if it is a JavaScript or TypeScript file.
+fn module_name(path: &Path) -> Option<String> {
+  let file_name = path.file_name()?.to_str()?;
+  let name = strip_extension(file_name)?;
+
+  let provided_name = fs::read_to_string(path)
+    .ok()
+    .and_then(|source_code| provides_module(&source_code));
+
+  Some(provided_name.unwrap_or_else(|| name.to_string()))
+}
+
+fn strip_extension(file_name: &str) -> Option<&str> {
+  let (name, _) = file_name.rsplit_once('.')?;
+  let ext = &file_name[name.len()..];
+
+  (!name.is_empty() && EXTENSIONS.contains(&ext)).then_some(name)
+}
+
+/// Name declared by the `@providesModule` tag of the leading docblock.
+pub(crate) fn provides_module(source_code: &str) -> Option<String> {
+  let docblock = source_code.trim_start().strip_prefix("/*")?;
+  let docblock = &docblock[..docblock.find("*/")?];
+
+  docblock.lines().find_map(|line| {
+    let (_, name) = line.split_once("@providesModule")?;
+
+    if !name.starts_with(char::is_whitespace) {
+      return None;
+    }
+
+    name.split_whitespace().next().map(str::to_string)
+  })
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize, Serializer};
 
 use crate::shared::structures::injectable_style::InjectableStyle;
 
-use super::injectable_style::InjectableStyleBase;
+use super::{injectable_style::InjectableStyleBase, source_location::SourceLocation};
 
 fn f64_to_int<S>(priority: &f64, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -16,13 +16,18 @@ where
   serializer.serialize_f64(*priority)
 }
 
+/// Version of the [`MetaDataFile`] format. Bump it on every breaking change of the document shape.
+pub const METADATA_VERSION: u32 = 1;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 
-pub(crate) struct MetaData {
+pub struct MetaData {
   class_name: String,
   style: InjectableStyleBase,
   #[serde(serialize_with = "f64_to_int")]
   priority: f64,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  source: Option<SourceLocation>,
 }
 
 impl MetaData {
@@ -31,28 +36,45 @@ impl MetaData {
       class_name,
       priority: injectable_style.priority.unwrap(),
       style: InjectableStyleBase::from(injectable_style),
+      source: None,
     }
   }
   pub(crate) fn _get_style(&self) -> &InjectableStyleBase {
     &self.style
   }
 
-  pub(crate) fn get_css(&self) -> &str {
+  pub fn get_css(&self) -> &str {
     self.style.ltr.as_str()
   }
 
-  pub(crate) fn get_css_rtl(&self) -> Option<&String> {
+  pub fn get_css_rtl(&self) -> Option<&String> {
     self.style.rtl.as_ref()
   }
 
-  pub(crate) fn get_class_name(&self) -> &str {
+  pub fn get_class_name(&self) -> &str {
     &self.class_name
   }
 
-  pub(crate) fn get_priority(&self) -> &f64 {
+  pub fn get_priority(&self) -> &f64 {
     &self.priority
   }
 
+  /// Where the style that produced this rule was authored, when a source map was available.
+  pub fn get_source(&self) -> Option<&SourceLocation> {
+    self.source.as_ref()
+  }
+
+  pub(crate) fn set_source(&mut self, source: Option<SourceLocation>) {
+    self.source = source;
+  }
+
+  /// Renames the class of this rule, rewriting its CSS with `rename_css`.
+  pub(crate) fn rename(&mut self, class_name: &str, rename_css: impl Fn(&str) -> String) {
+    self.class_name = class_name.to_string();
+    self.style.ltr = rename_css(&self.style.ltr);
+    self.style.rtl = self.style.rtl.as_deref().map(rename_css);
+  }
+
   pub(crate) fn convert_from_injected_styles_map(
     injected_styles_map: &IndexMap<String, Box<InjectableStyle>>,
   ) -> Vec<MetaData> {
@@ -64,3 +86,77 @@ impl MetaData {
       .collect::<Vec<MetaData>>()
   }
 }
+
+/// Metadata document written for every transformed file when a sidecar file or an output
+/// directory is configured.
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "filePath": "/app/src/Button.tsx",
+///   "rules": [
+///     {
+///       "className": "x1e2nbdu",
+///       "ltr": ".x1e2nbdu{color:red}",
+///       "rtl": null,
+///       "priority": 3000,
+///       "source": { "file": "src/Button.tsx", "line": 12, "column": 4 }
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MetaDataFile {
+  pub version: u32,
+  pub file_path: String,
+  pub rules: Vec<MetaDataRule>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MetaDataRule {
+  pub class_name: String,
+  pub ltr: String,
+  pub rtl: Option<String>,
+  #[serde(serialize_with = "f64_to_int")]
+  pub priority: f64,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub source: Option<SourceLocation>,
+}
+
+impl MetaDataFile {
+  pub(crate) fn new(file_path: String, metadata: &[MetaData]) -> Self {
+    Self {
+      version: METADATA_VERSION,
+      file_path,
+      rules: metadata.iter().map(MetaDataRule::from).collect(),
+    }
+  }
+}
+
+impl From<&MetaData> for MetaDataRule {
+  fn from(metadata: &MetaData) -> Self {
+    Self {
+      class_name: metadata.class_name.clone(),
+      ltr: metadata.style.ltr.clone(),
+      rtl: metadata.style.rtl.clone(),
+      priority: metadata.priority,
+      source: metadata.source.clone(),
+    }
+  }
+}
+
+impl From<MetaDataRule> for MetaData {
+  fn from(rule: MetaDataRule) -> Self {
+    Self {
+      class_name: rule.class_name,
+      style: InjectableStyleBase {
+        ltr: rule.ltr,
+        rtl: rule.rtl,
+      },
+      priority: rule.priority,
+      source: rule.source,
+    }
+  }
+}
@@ -1,12 +1,15 @@
 pub(crate) mod application_order;
 pub(crate) mod base_css_type;
+pub(crate) mod class_name_manifest;
+pub(crate) mod dynamic_style;
 pub mod evaluate_result;
 pub mod functions;
+pub(crate) mod haste_map;
 pub(crate) mod included_style;
 pub(crate) mod injectable_style;
 pub(crate) mod legacy_expand_shorthands_order;
 pub(crate) mod member_transform;
-pub(crate) mod meta_data;
+pub mod meta_data;
 pub mod named_import_source;
 pub(crate) mod null_pre_rule;
 pub(crate) mod order;
@@ -17,11 +20,12 @@ pub(crate) mod pre_included_styles_rule;
 pub(crate) mod pre_rule;
 pub(crate) mod pre_rule_set;
 pub(crate) mod property_specificity;
-pub(crate) mod property_specificity_order;
 pub(crate) mod seen_value;
 pub(crate) mod shorthands_of_shorthands;
+pub mod source_location;
 pub(crate) mod state;
 pub mod state_manager;
+pub mod stylesheet_options;
 pub mod stylex_options;
 pub(crate) mod stylex_state_options;
 pub(crate) mod tests;
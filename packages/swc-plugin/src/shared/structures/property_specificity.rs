@@ -1,9 +1,33 @@
-use super::{order::Order, order_pair::OrderPair};
+use crate::shared::constants::property_specificity_order::{Aliases, Shorthands};
+
+use super::{
+  order::Order, order_pair::OrderPair, shorthands_of_shorthands::ShorthandsOfShorthands,
+};
 
 pub(crate) struct PropertySpecificity {}
 
 impl Order for PropertySpecificity {
-  fn get_expansion_fn(_property: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
-    unimplemented!("PropertySpecificity")
+  /// Shorthands are kept as authored, and longhands override them through their higher priority.
+  /// Only aliases are rewritten and unsupported shorthands are rejected.
+  fn get_expansion_fn(property: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
+    let shorthand_of_shorthands = ShorthandsOfShorthands::get_expansion_fn(property);
+
+    if let Some(shorthand_of_shorthands_fn) = shorthand_of_shorthands {
+      return Some(shorthand_of_shorthands_fn);
+    }
+
+    let alias_fn = Aliases::get(property);
+
+    if let Some(alias_fn) = alias_fn {
+      return Some(alias_fn);
+    }
+
+    let shorthand = Shorthands::get(property);
+
+    if let Some(shorthand_fn) = shorthand {
+      return Some(shorthand_fn);
+    }
+
+    None
   }
 }
@@ -1,9 +1,22 @@
+use crate::shared::{
+  constants::{
+    property_specificity_order::Shorthands, shorthands_of_shorthands::SHORTHANDS_OF_SHORTHANDS,
+  },
+  utils::common::dashify,
+};
+
 use super::{order::Order, order_pair::OrderPair};
 
 pub(crate) struct ShorthandsOfShorthands {}
 
 impl Order for ShorthandsOfShorthands {
-  fn get_expansion_fn(_property: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
-    unimplemented!("PropertySpecificity")
+  /// Shorthands of shorthands such as `margin` are kept as authored,
+  /// unless the `Shorthands` table rejects them as unsupported, e.g. `border` or `background`.
+  fn get_expansion_fn(property: &str) -> Option<fn(Option<String>) -> Vec<OrderPair>> {
+    if !SHORTHANDS_OF_SHORTHANDS.contains(dashify(property).as_str()) {
+      return None;
+    }
+
+    Shorthands::get(property)
   }
 }
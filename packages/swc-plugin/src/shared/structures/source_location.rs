@@ -0,0 +1,50 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use swc_core::common::{errors::SourceMapperDyn, sync::Lrc, Span};
+
+/// Position of the style that produced a generated CSS rule.
+///
+/// `line` is 1-based and `column` is 0-based, like the locations reported by Babel.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+  pub file: String,
+  pub line: usize,
+  pub column: usize,
+}
+
+impl fmt::Display for SourceLocation {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}:{}", self.file, self.line)
+  }
+}
+
+/// Source map of the file being transformed, used to turn spans into [`SourceLocation`]s.
+#[derive(Clone)]
+pub(crate) struct SourceLocator(Lrc<SourceMapperDyn>);
+
+impl SourceLocator {
+  pub(crate) fn new(source_map: Lrc<SourceMapperDyn>) -> Self {
+    Self(source_map)
+  }
+
+  pub(crate) fn locate(&self, span: Span, file: &str) -> Option<SourceLocation> {
+    if span.is_dummy() {
+      return None;
+    }
+
+    let loc = self.0.lookup_char_pos(span.lo);
+
+    Some(SourceLocation {
+      file: file.to_string(),
+      line: loc.line,
+      column: loc.col.0,
+    })
+  }
+}
+
+impl fmt::Debug for SourceLocator {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("SourceLocator")
+  }
+}
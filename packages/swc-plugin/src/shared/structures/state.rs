@@ -1,44 +1,31 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 use swc_ecma_ast::Expr;
 
 use super::{functions::FunctionMap, state_manager::StateManager};
 
+/// State of a single evaluation, borrowing the traversal state it reads declarations and
+/// imports from and caches evaluated values in.
 #[derive(Debug)]
-pub struct EvaluationState {
+pub struct EvaluationState<'a> {
   pub(crate) confident: bool,
   pub(crate) deopt_path: Option<Box<Expr>>,
+  /// The deopt is a call that exceeded the evaluation limit of local functions.
+  pub(crate) limit_exceeded: bool,
   pub(crate) added_imports: HashSet<String>,
-  pub(crate) functions: FunctionMap,
-  pub(crate) traversal_state: StateManager,
+  pub(crate) functions: &'a FunctionMap,
+  pub(crate) traversal_state: &'a mut StateManager,
 }
 
-impl Default for EvaluationState {
-  fn default() -> Self {
+impl<'a> EvaluationState<'a> {
+  pub(crate) fn new(traversal_state: &'a mut StateManager, functions: &'a FunctionMap) -> Self {
     EvaluationState {
       confident: true,
       deopt_path: None,
+      limit_exceeded: false,
       added_imports: HashSet::new(),
-      functions: FunctionMap {
-        identifiers: HashMap::new(),
-        member_expressions: HashMap::new(),
-      },
-      traversal_state: StateManager::default(),
-    }
-  }
-}
-
-impl EvaluationState {
-  pub(crate) fn new(traversal_state: &StateManager) -> Self {
-    EvaluationState {
-      confident: true,
-      deopt_path: None,
-      added_imports: HashSet::new(),
-      functions: FunctionMap {
-        identifiers: HashMap::new(),
-        member_expressions: HashMap::new(),
-      },
-      traversal_state: traversal_state.clone(),
+      functions,
+      traversal_state,
     }
   }
 }
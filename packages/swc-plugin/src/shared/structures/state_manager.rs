@@ -1,27 +1,33 @@
 use core::panic;
 use std::option::Option;
-use std::path::Path;
+use std::path::{Component, Path};
 use std::{
   collections::{HashMap, HashSet},
   path::PathBuf,
+  sync::{Arc, Mutex},
 };
 
 use indexmap::{IndexMap, IndexSet};
-use swc_core::common::{EqIgnoreSpan, FileName, DUMMY_SP};
+use swc_core::common::{FileName, Span, Spanned, DUMMY_SP};
 use swc_core::ecma::ast::{
   CallExpr, Callee, Decl, Expr, ExprStmt, Id, Ident, ImportDecl, ImportDefaultSpecifier,
   ImportNamedSpecifier, ImportPhase, ImportSpecifier, ModuleDecl, ModuleExportName, ModuleItem,
   Pat, Stmt, Str, VarDecl, VarDeclKind, VarDeclarator,
 };
+use swc_core::ecma::utils::drop_span;
 
 use crate::shared::utils::{
   ast::factories::binding_ident_factory,
   common::{
     extract_filename_from_path, extract_filename_with_ext_from_path, extract_path, round_f64,
   },
+  diagnostics::span_panic,
 };
 use crate::shared::{
-  constants::common::DEFAULT_INJECT_PATH,
+  constants::{
+    common::DEFAULT_INJECT_PATH,
+    messages::{CLASS_NAME_HASH_COLLISION, MISSING_MINIFIED_CLASS_NAME},
+  },
   utils::ast::factories::{
     expr_or_spread_number_expression_factory, expr_or_spread_string_expression_factory,
   },
@@ -32,15 +38,21 @@ use crate::shared::{
     style_vars_to_keep::StyleVarsToKeep,
     top_level_expression::{TopLevelExpression, TopLevelExpressionKind},
   },
-  utils::common::resolve_file_path,
+  utils::common::{resolve_file_path, resolve_module_path},
 };
 
+use super::class_name_manifest::ClassNameManifest;
+use super::haste_map::{HasteMap, HasteMapError};
 use super::plugin_pass::PluginPass;
 use super::stylex_options::{CheckModuleResolution, StyleXOptions};
 use super::stylex_state_options::StyleXStateOptions;
 use super::uid_generator::UidGenerator;
 use super::{injectable_style::InjectableStyle, stylex_options::ModuleResolution};
-use super::{meta_data::MetaData, types::StylesObjectMap};
+use super::{
+  meta_data::MetaData,
+  source_location::{SourceLocation, SourceLocator},
+  types::{EvaluateResultFns, StylesObjectMap},
+};
 use super::{
   named_import_source::{ImportSources, NamedImportSource, RuntimeInjectionState},
   seen_value::SeenValue,
@@ -49,6 +61,7 @@ use super::{
 #[derive(Clone, Debug)]
 pub struct StateManager {
   pub(crate) _state: Box<PluginPass>,
+  pub(crate) source_locator: Option<SourceLocator>,
 
   // Imports
   pub(crate) import_paths: HashSet<String>,
@@ -62,17 +75,30 @@ pub struct StateManager {
   pub(crate) stylex_define_vars_import: HashSet<Box<Id>>,
   pub(crate) stylex_create_theme_import: HashSet<Box<Id>>,
   pub(crate) stylex_types_import: HashSet<Box<Id>>,
+  pub(crate) stylex_css_import: HashSet<Box<Id>>,
   pub(crate) inject_import_inserted: Option<(Box<Ident>, Box<Ident>)>,
   pub(crate) theme_name: Option<String>,
 
   pub(crate) declarations: Vec<VarDeclarator>,
+  /// Indices of `declarations` by the span of their initializer.
+  pub(crate) declaration_indices: HashMap<Span, usize>,
   pub(crate) top_level_expressions: Vec<TopLevelExpression>,
-  pub(crate) all_call_expressions: Vec<CallExpr>,
+  /// Indices of `top_level_expressions` by the span of the expression.
+  pub(crate) top_level_expression_indices: HashMap<Span, usize>,
+  /// Callees of the call expressions of the module, without spans, with their number of calls.
+  pub(crate) call_expression_callees: HashMap<Box<Expr>, usize>,
   pub(crate) var_decl_count_map: HashMap<Id, i8>,
   pub(crate) seen: HashMap<Box<Expr>, Box<SeenValue>>,
+  /// Number of nested calls of functions of the file being evaluated.
+  pub(crate) function_call_depth: usize,
+  /// Statements executed since the outermost function call started.
+  pub(crate) function_steps: usize,
+  /// Parameters and local variables bound by the function calls being evaluated.
+  pub(crate) function_scope: Vec<Id>,
 
   // `stylex.create` calls
   pub(crate) style_map: HashMap<String, Box<StylesObjectMap>>,
+  pub(crate) style_fns: HashMap<String, Box<EvaluateResultFns>>,
   pub(crate) style_vars: HashMap<String, Box<VarDeclarator>>,
 
   // results of `stylex.create` calls that should be kept
@@ -88,6 +114,13 @@ pub struct StateManager {
   pub(crate) prepend_import_module_items: Vec<ModuleItem>,
 
   pub(crate) injected_keyframes: IndexMap<String, Box<InjectableStyle>>,
+  /// Rule and description of the style behind every class name of the file,
+  /// used to detect hash collisions.
+  pub(crate) class_name_sources: HashMap<String, (Box<InjectableStyle>, String)>,
+  /// Class names generated by `stylex.create()`, as opposed to themes, variables and keyframes.
+  pub(crate) atomic_class_names: HashSet<String>,
+  /// Minified names of the atomic class names, when `minify_class_names` is enabled.
+  pub(crate) class_name_manifest: Option<Arc<Mutex<ClassNameManifest>>>,
   pub(crate) top_imports: Vec<ImportDecl>,
 }
 
@@ -100,9 +133,11 @@ impl Default for StateManager {
 impl StateManager {
   pub fn new(stylex_options: StyleXOptions) -> Self {
     let options = Box::new(StyleXStateOptions::from(stylex_options));
+    let class_name_manifest = ClassNameManifest::from_options(&options);
 
     Self {
       _state: Box::<PluginPass>::default(),
+      source_locator: None,
       import_paths: HashSet::new(),
       stylex_import: HashSet::new(),
       stylex_props_import: HashSet::new(),
@@ -114,20 +149,27 @@ impl StateManager {
       stylex_define_vars_import: HashSet::new(),
       stylex_create_theme_import: HashSet::new(),
       stylex_types_import: HashSet::new(),
+      stylex_css_import: HashSet::new(),
       inject_import_inserted: None,
       style_map: HashMap::new(),
+      style_fns: HashMap::new(),
       style_vars: HashMap::new(),
       style_vars_to_keep: HashSet::new(),
       member_object_ident_count_map: HashMap::new(),
       theme_name: None,
 
       seen: HashMap::new(),
+      function_call_depth: 0,
+      function_steps: 0,
+      function_scope: vec![],
 
       top_imports: vec![],
 
       declarations: vec![],
+      declaration_indices: HashMap::new(),
       top_level_expressions: vec![],
-      all_call_expressions: vec![],
+      top_level_expression_indices: HashMap::new(),
+      call_expression_callees: HashMap::new(),
       var_decl_count_map: HashMap::new(),
 
       in_stylex_create: false,
@@ -139,6 +181,9 @@ impl StateManager {
       prepend_import_module_items: vec![],
 
       injected_keyframes: IndexMap::new(),
+      class_name_sources: HashMap::new(),
+      atomic_class_names: HashSet::new(),
+      class_name_manifest,
     }
   }
 
@@ -177,11 +222,10 @@ impl StateManager {
   pub fn stylex_import_stringified(&self) -> Vec<String> {
     self
       .stylex_import
-      .clone()
-      .into_iter()
+      .iter()
       .map(|import_source| match import_source.as_ref() {
         ImportSources::Regular(regular) => regular.clone(),
-        ImportSources::Named(named) => named.clone().r#as,
+        ImportSources::Named(named) => named.r#as.clone(),
       })
       .collect()
   }
@@ -194,6 +238,10 @@ impl StateManager {
     self.options.dev
   }
 
+  pub(crate) fn is_debug_data_prop_enabled(&self) -> bool {
+    self.options.dev && self.options.enable_debug_data_prop
+  }
+
   pub(crate) fn gen_conditional_classes(&self) -> bool {
     self.options.gen_conditional_classes
   }
@@ -204,6 +252,105 @@ impl StateManager {
   pub(crate) fn get_filename(&self) -> String {
     extract_path(&self._state.filename)
   }
+  /// Location of `span` in the transformed file, relative to the working directory.
+  pub(crate) fn get_source_location(&self, span: Span) -> Option<SourceLocation> {
+    let source_locator = self.source_locator.as_ref()?;
+
+    let file = match (&self._state.filename, &self._state.cwd) {
+      (FileName::Real(file_path), Some(cwd)) => file_path
+        .strip_prefix(cwd)
+        .unwrap_or(file_path)
+        .display()
+        .to_string(),
+      _ => self.get_filename(),
+    };
+
+    source_locator.locate(span, &file)
+  }
+  /// Records the style behind `class_name`, aborting the transform when a different style
+  /// already produced the same class name.
+  pub(crate) fn register_class_name(
+    &mut self,
+    class_name: &str,
+    style: &InjectableStyle,
+    description: String,
+    span: Span,
+  ) {
+    match self.class_name_sources.get(class_name) {
+      Some((registered_style, registered_description)) => {
+        if registered_style.ltr != style.ltr || registered_style.rtl != style.rtl {
+          span_panic(
+            span,
+            &format!(
+              "{} \"{}\" is generated by both {} and {}.",
+              CLASS_NAME_HASH_COLLISION, class_name, registered_description, description
+            ),
+          );
+        }
+      }
+      None => {
+        self.class_name_sources.insert(
+          class_name.to_string(),
+          (Box::new(style.clone()), description),
+        );
+      }
+    }
+  }
+  /// Same as [`Self::register_class_name`], for an atomic class name of `stylex.create()`.
+  pub(crate) fn register_atomic_class_name(
+    &mut self,
+    class_name: &str,
+    style: &InjectableStyle,
+    description: String,
+    span: Span,
+  ) {
+    self.register_class_name(class_name, style, description, span);
+
+    self.atomic_class_names.insert(class_name.to_string());
+  }
+  /// Final name of the atomic class `class_name`, minified when enabled.
+  ///
+  /// Minified names are only read from the manifest, aborting the transform for a class name
+  /// that was not assigned one up front.
+  pub(crate) fn get_class_name(&self, class_name: String) -> String {
+    let Some(manifest) = &self.class_name_manifest else {
+      return class_name;
+    };
+
+    let manifest = manifest.lock().unwrap();
+
+    match manifest.get(&class_name) {
+      Some(minified) => minified.clone(),
+      None => panic!("{} \"{}\"", MISSING_MINIFIED_CLASS_NAME, class_name),
+    }
+  }
+  pub(crate) fn get_metadata_output_path(&self) -> Option<PathBuf> {
+    let FileName::Real(file_path) = &self._state.filename else {
+      return None;
+    };
+
+    let file_name = format!("{}.stylex.json", file_path.file_name()?.to_str()?);
+
+    match &self.options.metadata.output_dir {
+      Some(output_dir) => {
+        let cwd = self._state.cwd.clone().unwrap_or_default();
+
+        let relative_dir = file_path
+          .parent()
+          .map(|dir| dir.strip_prefix(&cwd).unwrap_or(dir))
+          .unwrap_or(Path::new(""))
+          .components()
+          .filter(|component| matches!(component, Component::Normal(_)))
+          .collect::<PathBuf>();
+
+        Some(cwd.join(output_dir).join(relative_dir).join(file_name))
+      }
+      None if self.options.metadata.sidecar.unwrap_or(false) => {
+        Some(file_path.with_file_name(file_name))
+      }
+      None => None,
+    }
+  }
   pub(crate) fn get_filename_for_hashing(&self) -> Option<String> {
     let filename = self.get_filename();
 
@@ -244,9 +391,7 @@ impl StateManager {
       }
       CheckModuleResolution::CommonJS(module_resolution)
       | CheckModuleResolution::CrossFileParsing(module_resolution) => {
-        let root_dir = module_resolution
-          .root_dir
-          .expect("root_dir is required for CommonJS");
+        let root_dir = module_resolution.root_dir?;
 
         let root_dir = Path::new(root_dir.as_str());
 
@@ -259,23 +404,87 @@ impl StateManager {
     }
   }
 
-  pub(crate) fn import_path_resolver(&self, import_path: &str) -> ImportPathResolution {
+  /// Resolves theme files according to `unstable_module_resolution`,
+  /// and any other module to its absolute path so its constants can be evaluated.
+  /// Haste resolution errors are reported at `span`, the source of the import.
+  pub(crate) fn import_path_resolver(&self, import_path: &str, span: Span) -> ImportPathResolution {
     let source_file_path = self.get_filename();
 
     if source_file_path.is_empty() {
       return ImportPathResolution::False;
     }
 
+    match self.theme_path_resolver(import_path, &source_file_path, span) {
+      ImportPathResolution::False => {
+        self.module_path_resolver(import_path, &source_file_path, span)
+      }
+      theme_path => theme_path,
+    }
+  }
+
+  fn module_path_resolver(
+    &self,
+    import_path: &str,
+    source_file_path: &str,
+    span: Span,
+  ) -> ImportPathResolution {
+    let root_dir = match &self.options.unstable_module_resolution {
+      Some(
+        CheckModuleResolution::CommonJS(module_resolution)
+        | CheckModuleResolution::Haste(module_resolution)
+        | CheckModuleResolution::CrossFileParsing(module_resolution),
+      ) => module_resolution.root_dir.clone(),
+      None => None,
+    };
+
+    let root_dir = root_dir.unwrap_or_else(|| {
+      self
+        ._state
+        .cwd
+        .clone()
+        .unwrap_or_default()
+        .display()
+        .to_string()
+    });
+
+    if let Some(haste_root_dir) = self.haste_root_dir().filter(|_| is_haste_name(import_path)) {
+      match HasteMap::resolve_shared(haste_root_dir, import_path) {
+        Ok(resolved_file_path) => {
+          return ImportPathResolution::Tuple(
+            ImportPathResolutionType::FilePath,
+            resolved_file_path.display().to_string(),
+          );
+        }
+        // Not a haste module, but maybe a package.
+        Err(HasteMapError::Unknown(_)) => {}
+        Err(error) => span_panic(span, &error.to_string()),
+      }
+    }
+
+    match resolve_module_path(import_path, source_file_path, &root_dir) {
+      Some(resolved_file_path) => ImportPathResolution::Tuple(
+        ImportPathResolutionType::FilePath,
+        resolved_file_path.display().to_string(),
+      ),
+      None => ImportPathResolution::False,
+    }
+  }
+
+  fn theme_path_resolver(
+    &self,
+    import_path: &str,
+    source_file_path: &str,
+    span: Span,
+  ) -> ImportPathResolution {
     let Some(unstable_module_resolution) = &self.options.unstable_module_resolution else {
       return ImportPathResolution::False;
     };
 
     match unstable_module_resolution {
       CheckModuleResolution::CommonJS(module_resolution) => {
-        let root_dir = &module_resolution
-          .clone()
-          .root_dir
-          .expect("root_dir is required for CommonJS");
+        let Some(root_dir) = &module_resolution.root_dir else {
+          return ImportPathResolution::False;
+        };
 
         let root_dir_path = Path::new(root_dir.as_str());
 
@@ -289,7 +498,7 @@ impl StateManager {
         }
 
         let resolved_file_path =
-          file_path_resolver(import_path, source_file_path, root_dir.as_str());
+          file_path_resolver(import_path, source_file_path.to_string(), root_dir.as_str());
 
         ImportPathResolution::Tuple(
           ImportPathResolutionType::ThemeNameRef,
@@ -311,39 +520,210 @@ impl StateManager {
           return ImportPathResolution::False;
         }
 
+        let theme_file_name = match self.haste_root_dir().filter(|_| is_haste_name(import_path)) {
+          Some(haste_root_dir) => HasteMap::resolve_shared(haste_root_dir, import_path)
+            .unwrap_or_else(|error| span_panic(span, &error.to_string()))
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+          None => add_file_extension(import_path, source_file_path),
+        };
+
+        ImportPathResolution::Tuple(ImportPathResolutionType::ThemeNameRef, theme_file_name)
+      }
+      CheckModuleResolution::CrossFileParsing(module_resolution) => {
+        let Some(root_dir) = module_resolution.root_dir.as_deref() else {
+          return ImportPathResolution::False;
+        };
+
+        let theme_file_extension = module_resolution
+          .theme_file_extension
+          .as_deref()
+          .unwrap_or(".stylex");
+
+        if !matches_file_suffix(theme_file_extension, import_path) {
+          return ImportPathResolution::False;
+        }
+
+        let Some(resolved_file_path) = resolve_module_path(import_path, source_file_path, root_dir)
+        else {
+          return ImportPathResolution::False;
+        };
+
         ImportPathResolution::Tuple(
           ImportPathResolutionType::ThemeNameRef,
-          add_file_extension(import_path, &source_file_path),
+          relative_path(Path::new(root_dir), &resolved_file_path)
+            .display()
+            .to_string(),
         )
       }
-      _ => unimplemented!("Module resolution is not supported"),
     }
   }
 
+  /// Location on disk of a theme file resolved by `import_path_resolver`,
+  /// when its `stylex.defineVars()` exports have to be parsed.
+  pub(crate) fn get_cross_file_path(&self, theme_file_name: &str) -> Option<PathBuf> {
+    match &self.options.unstable_module_resolution {
+      Some(CheckModuleResolution::CrossFileParsing(module_resolution)) => Some(
+        Path::new(module_resolution.root_dir.as_deref().unwrap_or_default()).join(theme_file_name),
+      ),
+      Some(CheckModuleResolution::Haste(_)) => HasteMap::shared(self.haste_root_dir()?)
+        .find_file(theme_file_name)
+        .map(Path::to_path_buf),
+      _ => None,
+    }
+  }
+
+  /// The `rootDir` of the haste module resolution, whose modules are indexed, if one is set.
+  fn haste_root_dir(&self) -> Option<&Path> {
+    match &self.options.unstable_module_resolution {
+      Some(CheckModuleResolution::Haste(ModuleResolution {
+        root_dir: Some(root_dir),
+        ..
+      })) => Some(Path::new(root_dir)),
+      _ => None,
+    }
+  }
+
+  /// Adds a top-level variable declaration, unless it was already added.
+  pub(crate) fn add_declaration(&mut self, decl: &VarDeclarator) {
+    match decl.init.as_ref().map(|init| init.span()) {
+      Some(span) if !span.is_dummy() => {
+        if self.declaration_indices.contains_key(&span) {
+          return;
+        }
+
+        self
+          .declaration_indices
+          .insert(span, self.declarations.len());
+      }
+      _ => {
+        if self.declarations.contains(decl) {
+          return;
+        }
+      }
+    }
+
+    self.declarations.push(decl.clone());
+  }
+
+  /// The variable declaration initialized with the call.
+  pub(crate) fn get_declaration_by_init(&self, call: &CallExpr) -> Option<&VarDeclarator> {
+    self
+      .declaration_indices
+      .get(&call.span)
+      .map(|index| &self.declarations[*index])
+  }
+
+  pub(crate) fn add_top_level_expression(&mut self, top_level_expression: TopLevelExpression) {
+    let span = top_level_expression.1.span();
+
+    if !span.is_dummy() {
+      self
+        .top_level_expression_indices
+        .insert(span, self.top_level_expressions.len());
+    }
+
+    self.top_level_expressions.push(top_level_expression);
+  }
+
+  /// The top-level expression of the module that is the call, of any kind.
+  pub(crate) fn get_top_level_call(&self, call: &CallExpr) -> Option<&TopLevelExpression> {
+    self
+      .top_level_expression_indices
+      .get(&call.span)
+      .map(|index| &self.top_level_expressions[*index])
+  }
+
   pub(crate) fn get_top_level_expr(
     &self,
     kind: &TopLevelExpressionKind,
     call: &CallExpr,
-  ) -> Option<TopLevelExpression> {
+  ) -> Option<&TopLevelExpression> {
     self
-      .top_level_expressions
-      .clone()
-      .into_iter()
-      .find(|tpe| kind.eq(&tpe.0) && tpe.1.eq(&Box::new(Expr::Call(call.clone()))))
+      .get_top_level_call(call)
+      .filter(|top_level_expression| kind.eq(&top_level_expression.0))
+  }
+
+  pub(crate) fn add_call_expression(&mut self, call: &CallExpr) {
+    if let Callee::Expr(callee) = &call.callee {
+      *self
+        .call_expression_callees
+        .entry(Box::new(drop_span(*callee.clone())))
+        .or_default() += 1;
+    }
+  }
+
+  fn remove_call_expression(&mut self, call: &CallExpr) {
+    if let Callee::Expr(callee) = &call.callee {
+      let callee = Box::new(drop_span(*callee.clone()));
+
+      if let Some(count) = self.call_expression_callees.get_mut(&callee) {
+        *count -= 1;
+
+        if *count == 0 {
+          self.call_expression_callees.remove(&callee);
+        }
+      }
+    }
+  }
+
+  /// Whether the expression, without spans, is called anywhere in the module.
+  pub(crate) fn is_callee(&self, expr: &Expr) -> bool {
+    self.call_expression_callees.contains_key(expr)
   }
 
+  /// Registers the styles of a `stylex.keyframes()`, `stylex.defineVars()` or
+  /// `stylex.createTheme()` call, checking their class names for collisions.
   pub(crate) fn register_styles(
     &mut self,
     call: &CallExpr,
     style: &IndexMap<String, Box<InjectableStyle>>,
     ast: &Expr,
     var_name: &Option<String>,
+  ) {
+    let description = format!(
+      "`{}` ({})",
+      var_name.as_deref().unwrap_or("<anonymous>"),
+      self
+        .get_source_location(call.span)
+        .map(|location| location.to_string())
+        .unwrap_or_else(|| self.get_filename())
+    );
+
+    for (class_name, injectable_style) in style {
+      self.register_class_name(class_name, injectable_style, description.clone(), call.span);
+    }
+
+    self.register_styles_with_sources(call, style, &HashMap::new(), ast, var_name);
+  }
+
+  /// Same as [`Self::register_styles`], attributing every class name found in `sources`
+  /// to the given span instead of the span of the whole call.
+  pub(crate) fn register_styles_with_sources(
+    &mut self,
+    call: &CallExpr,
+    style: &IndexMap<String, Box<InjectableStyle>>,
+    sources: &HashMap<String, Span>,
+    ast: &Expr,
+    var_name: &Option<String>,
   ) {
     if style.is_empty() {
       return;
     }
 
-    let metadatas = MetaData::convert_from_injected_styles_map(style);
+    let mut metadatas = MetaData::convert_from_injected_styles_map(style);
+
+    if self.source_locator.is_some() {
+      for metadata in metadatas.iter_mut() {
+        let span = sources
+          .get(metadata.get_class_name())
+          .copied()
+          .unwrap_or(call.span);
+
+        metadata.set_source(self.get_source_location(span));
+      }
+    }
 
     let mut uid_generator_inject = UidGenerator::new("inject");
 
@@ -404,50 +784,31 @@ impl StateManager {
       return;
     }
 
-    if let Some(item) = self
-      .declarations
-      .iter_mut()
-      .find(|decl| match decl.init.as_ref() {
-        Some(boxed_expr) => {
-          matches!(**boxed_expr, Expr::Call(ref existing_call) if existing_call == call)
-        }
-        None => false,
-      })
-    {
-      item.init = Some(Box::new(ast.clone())); // Only clone ast here
+    if let Some(index) = self.declaration_indices.get(&call.span) {
+      self.declarations[*index].init = Some(Box::new(ast.clone()));
     };
 
-    if let Some((_, item)) = self
-      .style_vars
-      .iter_mut()
-      .find(|(_, decl)| match decl.init.as_ref() {
-        Some(expr) => matches!(**expr, Expr::Call(ref existing_call) if existing_call == call),
-        None => false,
+    if let Some(item) = var_name
+      .as_ref()
+      .and_then(|var_name| self.style_vars.get_mut(var_name))
+      .filter(|decl| {
+        decl
+          .init
+          .as_ref()
+          .is_some_and(|init| init.span() == call.span)
       })
     {
-      item.init = Some(Box::new(ast.clone())); // Clone `ast` only when necessary
+      item.init = Some(Box::new(ast.clone()));
     };
 
-    if let Some(TopLevelExpression(_, item, _)) = self
-      .top_level_expressions
-      .iter_mut()
-      .find(|TopLevelExpression(_, decl, _)| matches!(decl, Expr::Call(c) if c == call))
-    {
-      *item = ast.clone();
+    if let Some(index) = self.top_level_expression_indices.get(&call.span) {
+      self.top_level_expressions[*index].1 = ast.clone();
     };
 
-    if let Some((index, _)) = self
-      .all_call_expressions
-      .iter()
-      .enumerate()
-      .find(|(_, expr)| expr.eq_ignore_span(&call))
-    {
-      match ast.as_call() {
-        Some(call_expr) => self.all_call_expressions[index] = call_expr.clone(),
-        None => {
-          self.all_call_expressions.remove(index);
-        }
-      }
+    self.remove_call_expression(call);
+
+    if let Some(call_expr) = ast.as_call() {
+      self.add_call_expression(call_expr);
     }
   }
 
@@ -508,6 +869,7 @@ impl StateManager {
 
   // Now you can use these helper functions to simplify your function
   pub fn combine(&mut self, other: &Self) {
+    self.source_locator = self.source_locator.clone().or(other.source_locator.clone());
     self.import_paths = union_hash_set(&self.import_paths, &other.import_paths);
     self.stylex_import = union_hash_set(&self.stylex_import, &other.stylex_import);
     self.stylex_props_import =
@@ -536,23 +898,28 @@ impl StateManager {
     );
     self.stylex_types_import =
       union_hash_set(&self.stylex_types_import, &other.stylex_types_import);
+    self.stylex_css_import = union_hash_set(&self.stylex_css_import, &other.stylex_css_import);
     self.inject_import_inserted = self
       .inject_import_inserted
       .clone()
       .or(other.inject_import_inserted.clone());
     self.theme_name = self.theme_name.clone().or(other.theme_name.clone());
-    self.declarations = chain_collect(self.declarations.clone(), other.declarations.clone());
-    self.top_level_expressions = chain_collect(
-      self.top_level_expressions.clone(),
-      other.top_level_expressions.clone(),
-    );
-    self.all_call_expressions = chain_collect(
-      self.all_call_expressions.clone(),
-      other.all_call_expressions.clone(),
-    );
+    for decl in &other.declarations {
+      self.add_declaration(decl);
+    }
+    for top_level_expression in &other.top_level_expressions {
+      self.add_top_level_expression(top_level_expression.clone());
+    }
+    for (callee, count) in &other.call_expression_callees {
+      *self
+        .call_expression_callees
+        .entry(callee.clone())
+        .or_default() += count;
+    }
     self.var_decl_count_map =
       chain_collect_hash_map(self.var_decl_count_map.clone(), other.var_decl_count_map.clone());
     self.style_map = chain_collect_hash_map(self.style_map.clone(), other.style_map.clone());
+    self.style_fns = chain_collect_hash_map(self.style_fns.clone(), other.style_fns.clone());
     self.style_vars = chain_collect_hash_map(self.style_vars.clone(), other.style_vars.clone());
     self.style_vars_to_keep =
       union_hash_set(&self.style_vars_to_keep.clone(), &other.style_vars_to_keep);
@@ -576,6 +943,17 @@ impl StateManager {
     );
     self.injected_keyframes =
       chain_collect_index_map(self.injected_keyframes.clone(), other.injected_keyframes.clone());
+    self.class_name_sources = chain_collect_hash_map(
+      self.class_name_sources.clone(),
+      other.class_name_sources.clone(),
+    );
+    self
+      .atomic_class_names
+      .extend(other.atomic_class_names.iter().cloned());
+    self.class_name_manifest = self
+      .class_name_manifest
+      .clone()
+      .or(other.class_name_manifest.clone());
     self.top_imports = chain_collect(self.top_imports.clone(), other.top_imports.clone());
   }
 }
@@ -665,6 +1043,11 @@ pub(crate) fn matches_file_suffix(allowed_suffix: &str, filename: &str) -> bool
   })
 }
 
+/// Whether `import_path` names a haste module rather than a file.
+fn is_haste_name(import_path: &str) -> bool {
+  !import_path.starts_with('.') && !import_path.starts_with('/')
+}
+
 fn add_file_extension(imported_file_path: &str, source_file: &str) -> String {
   if EXTENSIONS
     .iter()
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StyleSheetOptions {
+  pub rtl_selector: Option<RtlSelector>,
+  #[serde(rename = "useCSSLayers")]
+  pub use_css_layers: Option<bool>,
+  /// Precedes every rule with a comment pointing to the style that produced it.
+  pub dev: Option<bool>,
+}
+
+/// How the LTR and RTL variants of a direction dependent rule are scoped.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RtlSelector {
+  /// `html:not([dir='rtl']) .x{}` and `html[dir='rtl'] .x{}`
+  #[default]
+  DirAttribute,
+  /// `.x:dir(ltr){}` and `.x:dir(rtl){}`
+  DirPseudoClass,
+}
@@ -2,7 +2,10 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
-use crate::shared::constants::common::DEFAULT_INJECT_PATH;
+use crate::shared::constants::{
+  common::DEFAULT_INJECT_PATH,
+  messages::{MODULE_RESOLUTION_ROOT_DIR_REQUIRED, UNKNOWN_MODULE_RESOLUTION_TYPE},
+};
 
 use super::named_import_source::{ImportSources, RuntimeInjection};
 
@@ -19,9 +22,27 @@ pub struct StyleXOptionsParams {
   pub gen_conditional_classes: Option<bool>,
   pub dev: Option<bool>,
   pub test: Option<bool>,
+  /// In dev, adds a `data-style-src` attribute to the output of `props()` and `attrs()`,
+  /// listing where each applied namespace was defined.
+  pub enable_debug_data_prop: Option<bool>,
+  /// Template of the readable class names added in dev, built from the `[file]`, `[varName]`,
+  /// `[namespace]` and `[line]` placeholders. Defaults to `[file]__[varName].[namespace]`.
+  pub dev_class_name_format: Option<String>,
+  /// Hash function of the atomic class names. Defaults to `murmur2`.
+  pub hash_algorithm: Option<HashAlgorithm>,
+  /// Minimum number of characters of the hash part of atomic class names.
+  /// Shorter hashes are extended with hashes of the same input under other seeds.
+  pub min_hash_length: Option<usize>,
+  /// Replaces atomic class names with the shortest available names (`a`, `b`, …, `aa`).
+  /// Meant for production builds.
+  pub minify_class_names: Option<bool>,
+  /// JSON file with the minified names assigned by `assign_minified_class_names`, so they stay
+  /// the same across builds. Without it, names are only kept within the current process.
+  pub class_name_manifest: Option<String>,
   pub aliases: Option<Aliases>,
   #[serde(rename = "unstable_moduleResolution")]
   pub unstable_module_resolution: Option<ModuleResolution>,
+  pub metadata: Option<MetadataOptions>,
 }
 
 impl Default for StyleXOptionsParams {
@@ -37,12 +58,31 @@ impl Default for StyleXOptionsParams {
       gen_conditional_classes: Some(false),
       dev: Some(false),
       test: Some(false),
+      enable_debug_data_prop: None,
+      dev_class_name_format: None,
+      hash_algorithm: None,
+      min_hash_length: None,
+      minify_class_names: None,
+      class_name_manifest: None,
       aliases: None,
       unstable_module_resolution: None,
+      metadata: None,
     }
   }
 }
 
+/// Where the extracted style metadata of a file is written.
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataOptions {
+  /// Emit the `__stylex_metadata_start__` leading comment. Enabled by default.
+  pub comment: Option<bool>,
+  /// Write a `<file>.stylex.json` document next to the transformed file.
+  pub sidecar: Option<bool>,
+  /// Write the document into this directory, mirroring the file path relative to `cwd`.
+  pub output_dir: Option<String>,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all(deserialize = "kebab-case", serialize = "PascalCase"))]
 
@@ -52,6 +92,17 @@ pub enum StyleResolution {
   LegacyExpandShorthands,
 }
 
+/// Hash function used for atomic class names.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+  /// 32-bit MurmurHash2, the hash of the Babel plugin.
+  #[default]
+  Murmur2,
+  /// 64-bit MurmurHash64A, for large codebases where 32-bit class names may collide.
+  Murmur64,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 
 pub enum Aliases {
@@ -75,10 +126,40 @@ pub enum CheckModuleResolution {
   CrossFileParsing(ModuleResolution),
 }
 
+impl From<ModuleResolution> for CheckModuleResolution {
+  /// Panics on an unknown `type`, or cross-file parsing without `rootDir`, before any file is transformed.
+  fn from(module_resolution: ModuleResolution) -> Self {
+    let module_resolution_type = module_resolution.r#type.to_lowercase();
+
+    if module_resolution_type == "cross-file-parsing" && module_resolution.root_dir.is_none() {
+      panic!(
+        "{} \"{}\"",
+        MODULE_RESOLUTION_ROOT_DIR_REQUIRED, module_resolution.r#type
+      );
+    }
+
+    match module_resolution_type.as_str() {
+      "commonjs" => CheckModuleResolution::CommonJS(module_resolution),
+      "haste" => CheckModuleResolution::Haste(module_resolution),
+      "cross-file-parsing" => CheckModuleResolution::CrossFileParsing(module_resolution),
+      _ => panic!(
+        "{} \"{}\"",
+        UNKNOWN_MODULE_RESOLUTION_TYPE, module_resolution.r#type
+      ),
+    }
+  }
+}
+
 #[derive(Clone, Debug)]
 pub struct StyleXOptions {
   pub dev: bool,
   pub test: bool,
+  pub enable_debug_data_prop: bool,
+  pub dev_class_name_format: Option<String>,
+  pub hash_algorithm: HashAlgorithm,
+  pub min_hash_length: usize,
+  pub minify_class_names: bool,
+  pub class_name_manifest: Option<String>,
   pub use_rem_for_font_size: bool,
   pub class_name_prefix: String,
   // pub defined_stylex_css_variables: HashMap<String, String>,
@@ -89,6 +170,7 @@ pub struct StyleXOptions {
   pub gen_conditional_classes: bool,
   // pub aliases: Option<Aliases>,
   pub unstable_module_resolution: Option<CheckModuleResolution>,
+  pub metadata: MetadataOptions,
 }
 
 impl StyleXOptions {
@@ -107,6 +189,14 @@ impl StyleXOptions {
       theme_file_extension: None,
     }
   }
+
+  pub fn get_cross_file_parsing_module_resolution(root_dir: Option<String>) -> ModuleResolution {
+    ModuleResolution {
+      r#type: "cross-file-parsing".to_string(),
+      root_dir,
+      theme_file_extension: None,
+    }
+  }
 }
 
 impl Default for StyleXOptions {
@@ -120,26 +210,28 @@ impl Default for StyleXOptions {
       import_sources: vec![],
       dev: false,
       test: false,
+      enable_debug_data_prop: false,
+      dev_class_name_format: None,
+      hash_algorithm: HashAlgorithm::default(),
+      min_hash_length: 0,
+      minify_class_names: false,
+      class_name_manifest: None,
       treeshake_compensation: None,
       gen_conditional_classes: false,
       // aliases: None,
       unstable_module_resolution: Some(CheckModuleResolution::Haste(
         StyleXOptions::get_haste_module_resolution(None),
       )),
+      metadata: MetadataOptions::default(),
     }
   }
 }
 
 impl From<StyleXOptionsParams> for StyleXOptions {
   fn from(options: StyleXOptionsParams) -> Self {
-    let unstable_module_resolution = match options.unstable_module_resolution {
-      Some(module_resolution) => match module_resolution.r#type.to_lowercase().as_str() {
-        "haste" => Some(CheckModuleResolution::Haste(module_resolution)),
-        "cross-file-parsing" => Some(CheckModuleResolution::CrossFileParsing(module_resolution)),
-        _ => Some(CheckModuleResolution::CommonJS(module_resolution)),
-      },
-      None => None,
-    };
+    let unstable_module_resolution = options
+      .unstable_module_resolution
+      .map(CheckModuleResolution::from);
 
     let runtime_injection = match options.runtime_injection {
       Some(value) => match value {
@@ -160,10 +252,17 @@ impl From<StyleXOptionsParams> for StyleXOptions {
       import_sources: options.import_sources.unwrap_or_default(),
       dev: options.dev.unwrap_or(false),
       test: options.test.unwrap_or(false),
+      enable_debug_data_prop: options.enable_debug_data_prop.unwrap_or(false),
+      dev_class_name_format: options.dev_class_name_format,
+      hash_algorithm: options.hash_algorithm.unwrap_or_default(),
+      min_hash_length: options.min_hash_length.unwrap_or(0),
+      minify_class_names: options.minify_class_names.unwrap_or(false),
+      class_name_manifest: options.class_name_manifest,
       treeshake_compensation: options.treeshake_compensation,
       gen_conditional_classes: options.gen_conditional_classes.unwrap_or(false),
       // aliases: options.aliases,
       unstable_module_resolution,
+      metadata: options.metadata.unwrap_or_default(),
     }
   }
 }
@@ -4,13 +4,21 @@ use crate::shared::constants::common::DEFAULT_INJECT_PATH;
 
 use super::{
   named_import_source::{ImportSources, RuntimeInjection, RuntimeInjectionState},
-  stylex_options::{CheckModuleResolution, StyleResolution, StyleXOptions},
+  stylex_options::{
+    CheckModuleResolution, HashAlgorithm, MetadataOptions, StyleResolution, StyleXOptions,
+  },
 };
 
 #[derive(Deserialize, Clone, Debug)]
 pub struct StyleXStateOptions {
   pub dev: bool,
   pub test: bool,
+  pub enable_debug_data_prop: bool,
+  pub dev_class_name_format: Option<String>,
+  pub hash_algorithm: HashAlgorithm,
+  pub min_hash_length: usize,
+  pub minify_class_names: bool,
+  pub class_name_manifest: Option<String>,
   pub use_rem_for_font_size: bool,
   pub class_name_prefix: String,
   // pub defined_stylex_css_variables: HashMap<String, String>,
@@ -21,6 +29,7 @@ pub struct StyleXStateOptions {
   pub gen_conditional_classes: bool,
   // pub aliases: Option<HashMap<String, Vec<String>>>,
   pub unstable_module_resolution: Option<CheckModuleResolution>,
+  pub metadata: MetadataOptions,
 }
 
 impl StyleXStateOptions {
@@ -34,10 +43,17 @@ impl StyleXStateOptions {
       import_sources: vec![],
       dev: false,
       test: false,
+      enable_debug_data_prop: false,
+      dev_class_name_format: None,
+      hash_algorithm: HashAlgorithm::default(),
+      min_hash_length: 0,
+      minify_class_names: false,
+      class_name_manifest: None,
       treeshake_compensation: None,
       gen_conditional_classes: false,
       // aliases: None,
       unstable_module_resolution: None,
+      metadata: MetadataOptions::default(),
     }
   }
 }
@@ -89,10 +105,17 @@ impl From<StyleXOptions> for StyleXStateOptions {
       import_sources: options.import_sources,
       dev: options.dev,
       test: options.test,
+      enable_debug_data_prop: options.enable_debug_data_prop,
+      dev_class_name_format: options.dev_class_name_format,
+      hash_algorithm: options.hash_algorithm,
+      min_hash_length: options.min_hash_length,
+      minify_class_names: options.minify_class_names,
+      class_name_manifest: options.class_name_manifest,
       treeshake_compensation: options.treeshake_compensation,
       gen_conditional_classes: options.gen_conditional_classes,
       // aliases,
       unstable_module_resolution: options.unstable_module_resolution,
+      metadata: options.metadata,
     }
   }
 }
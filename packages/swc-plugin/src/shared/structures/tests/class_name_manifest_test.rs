@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod class_name_manifest {
+  use std::{env, fs};
+
+  use swc_core::ecma::{ast::Str, visit::VisitMutWith};
+
+  use crate::shared::structures::{
+    class_name_manifest::{assign_minified_class_names, minified_class_name, ClassNameManifest},
+    injectable_style::InjectableStyle,
+    meta_data::MetaData,
+    stylex_options::StyleXOptionsParams,
+  };
+
+  #[test]
+  fn generates_shortest_names_first() {
+    let names = [0, 1, 25, 26, 27, 61, 62, 961, 962]
+      .iter()
+      .map(|index| minified_class_name(*index))
+      .collect::<Vec<String>>();
+
+    assert_eq!(
+      names,
+      vec!["a", "b", "z", "aa", "ab", "a9", "ba", "z9", "aaa"]
+    );
+  }
+
+  #[test]
+  fn reuses_assigned_names() {
+    let mut manifest = ClassNameManifest::default();
+
+    assert_eq!(manifest.get_or_assign("x1e2nbdu"), "a");
+    assert_eq!(manifest.get_or_assign("x1t391ir"), "b");
+    assert_eq!(manifest.get_or_assign("x1e2nbdu"), "a");
+  }
+
+  #[test]
+  fn skips_names_blocked_by_ad_blockers() {
+    let mut manifest = ClassNameManifest::default();
+
+    let names = (0..30)
+      .map(|index| manifest.get_or_assign(&format!("x{}", index)))
+      .collect::<Vec<String>>();
+
+    assert_eq!(names[3], "d");
+    assert!(names.iter().all(|name| !name.starts_with("ad")));
+    assert_eq!(names[29], "ae");
+  }
+
+  #[test]
+  fn persists_names_across_builds() {
+    let path = env::temp_dir()
+      .join("stylex_class_name_manifest_test")
+      .join("manifest.json");
+
+    let _ = fs::remove_file(&path);
+
+    let mut manifest = ClassNameManifest::load(&path);
+
+    manifest.get_or_assign("x1e2nbdu");
+    manifest.get_or_assign("x1t391ir");
+    manifest.save(&path);
+
+    let mut reloaded = ClassNameManifest::load(&path);
+
+    assert_eq!(reloaded, manifest);
+    assert_eq!(reloaded.get_or_assign("x1t391ir"), "b");
+    assert_eq!(reloaded.get_or_assign("x78zum5"), "c");
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  fn assigns_names_up_front_and_saves_them() {
+    let path = env::temp_dir()
+      .join("stylex_assign_minified_class_names_test")
+      .join("manifest.json");
+
+    let _ = fs::remove_file(&path);
+
+    let options = StyleXOptionsParams {
+      class_name_manifest: Some(path.to_str().unwrap().to_string()),
+      ..StyleXOptionsParams::default()
+    };
+
+    assign_minified_class_names(&options, ["x1e2nbdu", "x1t391ir", "x1e2nbdu"]);
+
+    let mut manifest = ClassNameManifest::shared(Some(&path))
+      .lock()
+      .unwrap()
+      .clone();
+
+    assert_eq!(manifest.get_or_assign("x1t391ir"), "b");
+    assert_eq!(
+      ClassNameManifest::load(&path).get_or_assign("x1e2nbdu"),
+      "a"
+    );
+    assert_eq!(ClassNameManifest::load(&path).get_or_assign("x78zum5"), "c");
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "\"a\" is assigned to both \"x1e2nbdu\" and \"x1t391ir\"")]
+  fn rejects_manifests_assigning_a_name_twice() {
+    let path = env::temp_dir()
+      .join("stylex_class_name_manifest_duplicate_test")
+      .join("manifest.json");
+
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(
+      &path,
+      r#"{"nextIndex":1,"classNames":{"x1e2nbdu":"a","x1t391ir":"a"}}"#,
+    )
+    .unwrap();
+
+    ClassNameManifest::load(&path);
+  }
+
+  #[test]
+  fn renames_class_names_of_transformed_output() {
+    let mut minified_class_names =
+      assign_minified_class_names(&StyleXOptionsParams::default(), ["x1e2nbdu", "x1t391ir"]);
+
+    let mut str = Str::from("x1e2nbdu x1t391ir x1e2nbdu2");
+
+    str.visit_mut_with(&mut minified_class_names);
+
+    assert_eq!(&*str.value, "a b x1e2nbdu2");
+
+    let mut rule = MetaData::new(
+      "x1e2nbdu".to_string(),
+      InjectableStyle {
+        ltr: ".x1e2nbdu.x1e2nbdu:hover, .x1e2nbdu2{color:red}".to_string(),
+        rtl: None,
+        priority: Some(3130.0),
+      },
+    );
+
+    minified_class_names.rename_rule(&mut rule);
+
+    assert_eq!(rule.get_class_name(), "a");
+    assert_eq!(rule.get_css(), ".a.a:hover, .x1e2nbdu2{color:red}");
+  }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod haste_map {
+  use std::{env, fs, path::Path, thread, time::Duration};
+
+  use crate::shared::structures::haste_map::{provides_module, HasteMap, HasteMapError};
+
+  fn write(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+  }
+
+  #[test]
+  fn reads_provided_module_name_from_docblock() {
+    assert_eq!(
+      provides_module("/**\n * @flow\n * @providesModule Theme.stylex\n */\nexport {};"),
+      Some("Theme.stylex".to_string())
+    );
+    assert_eq!(provides_module("// @providesModule Theme\n"), None);
+    assert_eq!(provides_module("/* @providesModuleTheme */"), None);
+    assert_eq!(
+      provides_module("export {};\n/* @providesModule Theme */"),
+      None
+    );
+  }
+
+  #[test]
+  fn indexes_modules_by_name() {
+    let root_dir = env::temp_dir().join("stylex_haste_map_test");
+
+    let _ = fs::remove_dir_all(&root_dir);
+
+    write(&root_dir.join("themes/colors.stylex.ts"), "export {};");
+    write(
+      &root_dir.join("Sizes.js"),
+      "/** @providesModule SizeConstants */",
+    );
+    write(&root_dir.join("left/Button.mjs"), "export {};");
+    write(&root_dir.join("right/Button.jsx"), "export {};");
+    write(&root_dir.join("node_modules/pkg/index.js"), "export {};");
+    write(&root_dir.join("styles.css"), "");
+
+    let haste_map = HasteMap::build(&root_dir);
+
+    assert_eq!(
+      haste_map.resolve("colors.stylex"),
+      Ok(root_dir.join("themes/colors.stylex.ts").as_path())
+    );
+    assert_eq!(
+      haste_map.resolve("colors.stylex.ts"),
+      Ok(root_dir.join("themes/colors.stylex.ts").as_path())
+    );
+    assert_eq!(
+      haste_map.resolve("SizeConstants"),
+      Ok(root_dir.join("Sizes.js").as_path())
+    );
+    assert_eq!(
+      haste_map.resolve("Sizes"),
+      Err(HasteMapError::Unknown("Sizes".to_string()))
+    );
+    assert_eq!(
+      haste_map.resolve("index"),
+      Err(HasteMapError::Unknown("index".to_string()))
+    );
+    assert_eq!(
+      haste_map.resolve("Button"),
+      Err(HasteMapError::Ambiguous(
+        "Button".to_string(),
+        vec![
+          root_dir.join("left/Button.mjs"),
+          root_dir.join("right/Button.jsx")
+        ]
+      ))
+    );
+    assert_eq!(
+      haste_map.find_file("colors.stylex.ts"),
+      Some(root_dir.join("themes/colors.stylex.ts").as_path())
+    );
+  }
+
+  #[test]
+  fn reindexes_shared_map_when_files_change() {
+    let root_dir = env::temp_dir().join("stylex_haste_map_reindex_test");
+
+    let _ = fs::remove_dir_all(&root_dir);
+
+    write(&root_dir.join("colors.stylex.js"), "export {};");
+
+    assert_eq!(
+      HasteMap::resolve_shared(&root_dir, "colors.stylex"),
+      Ok(root_dir.join("colors.stylex.js"))
+    );
+    assert_eq!(
+      HasteMap::resolve_shared(&root_dir, "spacing.stylex"),
+      Err(HasteMapError::Unknown("spacing.stylex".to_string()))
+    );
+
+    // Let the modification time of the directory change.
+    thread::sleep(Duration::from_millis(20));
+
+    write(&root_dir.join("themes/spacing.stylex.js"), "export {};");
+    fs::rename(
+      root_dir.join("colors.stylex.js"),
+      root_dir.join("themes/colors.stylex.js"),
+    )
+    .unwrap();
+
+    assert_eq!(
+      HasteMap::resolve_shared(&root_dir, "spacing.stylex"),
+      Ok(root_dir.join("themes/spacing.stylex.js"))
+    );
+    assert_eq!(
+      HasteMap::resolve_shared(&root_dir, "colors.stylex"),
+      Ok(root_dir.join("themes/colors.stylex.js"))
+    );
+  }
+}
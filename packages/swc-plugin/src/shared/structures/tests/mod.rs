@@ -1,2 +1,6 @@
+mod class_name_manifest_test;
 mod flatten_raw_style_objects_test;
 mod gen_css_test;
+mod haste_map_test;
+mod state_manager_test;
+mod stylex_options_test;
@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod state_manager_indices {
+  use swc_core::{
+    common::{BytePos, Span, DUMMY_SP},
+    ecma::{
+      ast::{CallExpr, Callee, Expr, MemberExpr, MemberProp, Pat, VarDeclarator},
+      utils::drop_span,
+    },
+  };
+
+  use crate::shared::{
+    enums::data_structures::top_level_expression::{TopLevelExpression, TopLevelExpressionKind},
+    structures::state_manager::StateManager,
+    utils::ast::factories::{binding_ident_factory, ident_factory},
+  };
+
+  fn span(lo: u32, hi: u32) -> Span {
+    Span::new(BytePos(lo), BytePos(hi), Default::default())
+  }
+
+  fn create_call(span: Span) -> CallExpr {
+    CallExpr {
+      span,
+      callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+        span,
+        obj: Box::new(Expr::Ident(ident_factory("stylex"))),
+        prop: MemberProp::Ident(ident_factory("create")),
+      }))),
+      args: vec![],
+      type_args: None,
+    }
+  }
+
+  fn declaration(name: &str, call: &CallExpr) -> VarDeclarator {
+    VarDeclarator {
+      span: DUMMY_SP,
+      name: Pat::Ident(binding_ident_factory(ident_factory(name))),
+      init: Some(Box::new(Expr::Call(call.clone()))),
+      definite: false,
+    }
+  }
+
+  #[test]
+  fn finds_declarations_by_their_initializer() {
+    let mut state = StateManager::default();
+
+    let first_call = create_call(span(10, 20));
+    let second_call = create_call(span(30, 40));
+
+    state.add_declaration(&declaration("first", &first_call));
+    state.add_declaration(&declaration("second", &second_call));
+    state.add_declaration(&declaration("second", &second_call));
+
+    assert_eq!(state.declarations.len(), 2);
+
+    let found = state
+      .get_declaration_by_init(&second_call)
+      .and_then(|decl| decl.name.as_ident())
+      .map(|ident| ident.sym.to_string());
+
+    assert_eq!(found, Some("second".to_string()));
+    assert!(state
+      .get_declaration_by_init(&create_call(span(50, 60)))
+      .is_none());
+  }
+
+  #[test]
+  fn finds_top_level_calls_by_kind() {
+    let mut state = StateManager::default();
+
+    let call = create_call(span(10, 20));
+
+    state.add_top_level_expression(TopLevelExpression(
+      TopLevelExpressionKind::NamedExport,
+      Expr::Call(call.clone()),
+      None,
+    ));
+
+    assert!(state.get_top_level_call(&call).is_some());
+    assert!(state
+      .get_top_level_expr(&TopLevelExpressionKind::NamedExport, &call)
+      .is_some());
+    assert!(state
+      .get_top_level_expr(&TopLevelExpressionKind::Stmt, &call)
+      .is_none());
+  }
+
+  #[test]
+  fn counts_callees_without_spans() {
+    let mut state = StateManager::default();
+
+    let call = create_call(span(10, 20));
+
+    state.add_call_expression(&call);
+    state.add_call_expression(&create_call(span(30, 40)));
+
+    let callee = drop_span(*call.callee.as_expr().unwrap().clone());
+
+    assert!(state.is_callee(&callee));
+    assert_eq!(state.call_expression_callees.get(&callee), Some(&2));
+    assert!(!state.is_callee(&Expr::Ident(ident_factory("stylex"))));
+  }
+}
+
+#[cfg(test)]
+mod state_manager_class_names {
+  use indexmap::IndexMap;
+  use swc_core::{
+    common::DUMMY_SP,
+    ecma::ast::{CallExpr, Callee, Expr, MemberExpr, MemberProp},
+  };
+
+  use crate::shared::{
+    structures::{injectable_style::InjectableStyle, state_manager::StateManager},
+    utils::ast::{convertors::string_to_expression, factories::ident_factory},
+  };
+
+  fn keyframes_call() -> CallExpr {
+    CallExpr {
+      span: DUMMY_SP,
+      callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(Expr::Ident(ident_factory("stylex"))),
+        prop: MemberProp::Ident(ident_factory("keyframes")),
+      }))),
+      args: vec![],
+      type_args: None,
+    }
+  }
+
+  fn keyframes(name: &str, ltr: &str) -> IndexMap<String, Box<InjectableStyle>> {
+    let mut styles = IndexMap::new();
+
+    styles.insert(
+      name.to_string(),
+      Box::new(InjectableStyle {
+        ltr: ltr.to_string(),
+        rtl: None,
+        priority: Some(1.0),
+      }),
+    );
+
+    styles
+  }
+
+  #[test]
+  fn registers_class_names_of_keyframes() {
+    let mut state = StateManager::default();
+
+    let name = "x1y2z3-B";
+    let styles = keyframes(name, "@keyframes x1y2z3-B{from{opacity:0;}}");
+
+    state.register_styles(
+      &keyframes_call(),
+      &styles,
+      &string_to_expression(name),
+      &Some("fadeIn".to_string()),
+    );
+    state.register_styles(
+      &keyframes_call(),
+      &styles,
+      &string_to_expression(name),
+      &Some("fadeInAgain".to_string()),
+    );
+
+    assert!(state.class_name_sources.contains_key(name));
+    assert!(!state.atomic_class_names.contains(name));
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "\"x1y2z3-B\" is generated by both `fadeIn` (UnknownFile) and `fadeOut` (UnknownFile)."
+  )]
+  fn reports_colliding_keyframes() {
+    let mut state = StateManager::default();
+
+    let name = "x1y2z3-B";
+
+    state.register_styles(
+      &keyframes_call(),
+      &keyframes(name, "@keyframes x1y2z3-B{from{opacity:0;}}"),
+      &string_to_expression(name),
+      &Some("fadeIn".to_string()),
+    );
+    state.register_styles(
+      &keyframes_call(),
+      &keyframes(name, "@keyframes x1y2z3-B{from{opacity:1;}}"),
+      &string_to_expression(name),
+      &Some("fadeOut".to_string()),
+    );
+  }
+}
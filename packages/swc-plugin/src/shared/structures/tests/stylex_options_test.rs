@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod stylex_options {
+  use crate::shared::structures::stylex_options::{
+    CheckModuleResolution, ModuleResolution, StyleXOptions, StyleXOptionsParams,
+  };
+
+  fn options_with(module_resolution: ModuleResolution) -> StyleXOptions {
+    StyleXOptions::from(StyleXOptionsParams {
+      unstable_module_resolution: Some(module_resolution),
+      ..StyleXOptionsParams::default()
+    })
+  }
+
+  #[test]
+  fn parses_module_resolution_type_case_insensitively() {
+    let options = options_with(ModuleResolution {
+      r#type: "commonJS".to_string(),
+      root_dir: Some("/project".to_string()),
+      theme_file_extension: None,
+    });
+
+    assert!(matches!(
+      options.unstable_module_resolution,
+      Some(CheckModuleResolution::CommonJS(_))
+    ));
+  }
+
+  #[test]
+  fn haste_does_not_require_root_dir() {
+    let options = options_with(StyleXOptions::get_haste_module_resolution(None));
+
+    assert!(matches!(
+      options.unstable_module_resolution,
+      Some(CheckModuleResolution::Haste(_))
+    ));
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "`unstable_moduleResolution.rootDir` is required by the module resolution \"cross-file-parsing\""
+  )]
+  fn cross_file_parsing_requires_root_dir() {
+    options_with(StyleXOptions::get_cross_file_parsing_module_resolution(
+      None,
+    ));
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "`unstable_moduleResolution.type` must be \"commonJS\", \"haste\" or \"cross-file-parsing\", got \"node\""
+  )]
+  fn rejects_unknown_module_resolution_type() {
+    options_with(ModuleResolution {
+      r#type: "node".to_string(),
+      root_dir: Some("/project".to_string()),
+      theme_file_extension: None,
+    });
+  }
+}
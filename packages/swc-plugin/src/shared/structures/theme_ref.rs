@@ -1,32 +1,60 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
-use crate::shared::utils::common::{create_hash, gen_file_based_identifier};
+use indexmap::IndexMap;
 
-use super::state_manager::StateManager;
+use crate::shared::utils::common::{create_hash, gen_file_based_identifier};
 
 #[derive(Debug, Clone)]
 pub struct ThemeRef {
   file_name: String,
   export_name: String,
-  state: StateManager,
+  class_name_prefix: String,
   map: HashMap<String, String>,
+  defined_vars: Option<Arc<IndexMap<String, String>>>,
 }
 
 impl ThemeRef {
-  pub(crate) fn new(file_name: String, export_name: String, state: StateManager) -> Self {
+  pub(crate) fn new(file_name: String, export_name: String, class_name_prefix: String) -> Self {
     Self {
       file_name,
       export_name,
-      state,
+      class_name_prefix,
       map: HashMap::new(),
+      defined_vars: None,
     }
   }
 
-  pub(crate) fn get(&mut self, key: &str) -> (String, &StateManager) {
+  /// Uses the variables compiled from the theme file instead of deriving them from hashes.
+  pub(crate) fn with_defined_vars(mut self, defined_vars: Arc<IndexMap<String, String>>) -> Self {
+    self.defined_vars = Some(defined_vars);
+    self
+  }
+
+  pub(crate) fn is_defined(&self, key: &str) -> bool {
+    key.starts_with("--")
+      || self
+        .defined_vars
+        .as_ref()
+        .map_or(true, |defined_vars| defined_vars.contains_key(key))
+  }
+
+  pub(crate) fn get(&mut self, key: &str) -> String {
     if key.starts_with("--") {
       let css_key = format!("var({})", key);
-      return (css_key, &self.state);
+      return css_key;
     }
+
+    if let Some(defined_vars) = &self.defined_vars {
+      let value = defined_vars.get(key).unwrap_or_else(|| {
+        panic!(
+          "Variable `{}` is not defined by `{}` in {}",
+          key, self.export_name, self.file_name
+        )
+      });
+
+      return value.clone();
+    }
+
     let entry = self.map.entry(key.to_string()).or_insert_with(|| {
       let str_to_hash = gen_file_based_identifier(
         &self.file_name,
@@ -38,16 +66,12 @@ impl ThemeRef {
         },
       );
 
-      let var_name = format!(
-        "{}{}",
-        self.state.options.class_name_prefix,
-        create_hash(&str_to_hash)
-      );
+      let var_name = format!("{}{}", self.class_name_prefix, create_hash(&str_to_hash));
 
       format!("var(--{})", var_name)
     });
 
-    (entry.to_string(), &self.state)
+    entry.to_string()
   }
 
   fn _set(&self, key: &str, value: &str) {
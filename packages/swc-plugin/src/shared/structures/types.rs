@@ -1,18 +1,26 @@
 use std::{collections::HashMap, rc::Rc};
 
 use indexmap::IndexMap;
-use swc_ecma_ast::{BindingIdent, Expr, Id};
+use swc_ecma_ast::{Expr, Id};
 
-use crate::shared::enums::data_structures::{
-  evaluate_result_value::EvaluateResultValue, flat_compiled_styles_value::FlatCompiledStylesValue,
+use crate::shared::{
+  enums::data_structures::{
+    evaluate_result_value::EvaluateResultValue, flat_compiled_styles_value::FlatCompiledStylesValue,
+  },
+  utils::js::local_functions::LocalFunctionError,
 };
 
-use super::{functions::FunctionConfigType, named_import_source::ImportSources};
+use super::{
+  dynamic_style::DynamicStyle, functions::FunctionConfigType, named_import_source::ImportSources,
+  state_manager::StateManager,
+};
 
 pub(crate) type FlatCompiledStyles = IndexMap<String, Box<FlatCompiledStylesValue>>;
-pub(crate) type EvaluateResultFns =
-  IndexMap<String, (Vec<BindingIdent>, IndexMap<String, Box<Expr>>)>;
-pub(crate) type EvaluationCallback = Rc<dyn Fn(Vec<Option<EvaluateResultValue>>) -> Expr + 'static>;
+pub(crate) type EvaluateResultFns = IndexMap<String, DynamicStyle>;
+pub(crate) type EvaluationCallback = Rc<
+  dyn Fn(Vec<Option<EvaluateResultValue>>, &mut StateManager) -> Result<Expr, LocalFunctionError>
+    + 'static,
+>;
 pub(crate) type FunctionMapMemberExpression =
   HashMap<Box<ImportSources>, Box<HashMap<Box<Id>, Box<FunctionConfigType>>>>;
 pub(crate) type FunctionMapIdentifiers = HashMap<Box<Id>, Box<FunctionConfigType>>;
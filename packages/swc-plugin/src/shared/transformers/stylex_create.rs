@@ -1,4 +1,5 @@
 use indexmap::IndexMap;
+use swc_core::common::Spanned;
 
 use crate::shared::{
   constants::common::COMPILED_KEY,
@@ -30,7 +31,7 @@ pub(crate) fn stylex_create_set(
   let mut injected_styles_map: IndexMap<String, Box<InjectableStyle>> = IndexMap::new();
 
   for (namespace_name, namespace) in namespaces.as_map().unwrap() {
-    validate_namespace(namespace, &[]);
+    validate_namespace(namespace, &[], namespace_name.span());
 
     let mut pseudos = vec![];
     let mut at_rules = vec![];
@@ -70,6 +71,8 @@ pub(crate) fn stylex_create_set(
       })
       .collect::<IndexMap<String, CompiledResult>>();
 
+    let resolved_namespace_name = expr_to_str(namespace_name, state, functions);
+
     let mut namespace_obj: FlatCompiledStyles = IndexMap::new();
 
     for key in compiled_namespace.keys() {
@@ -94,7 +97,24 @@ pub(crate) fn stylex_create_set(
           Box::new(FlatCompiledStylesValue::String(class_name.clone())),
         );
 
+        let description = format!(
+          "`{}.{}` ({})",
+          resolved_namespace_name,
+          key,
+          state
+            .get_source_location(namespace_name.span())
+            .map(|location| location.to_string())
+            .unwrap_or_else(|| state.get_filename())
+        );
+
         for ComputedStyle(class_name, injectable_styles) in class_name_tuples.iter() {
+          state.register_atomic_class_name(
+            class_name,
+            injectable_styles,
+            description.clone(),
+            namespace_name.span(),
+          );
+
           injected_styles_map
             .entry(class_name.clone())
             .or_insert_with(|| Box::new(injectable_styles.clone()));
@@ -103,8 +123,6 @@ pub(crate) fn stylex_create_set(
         namespace_obj.insert(key.clone(), Box::new(FlatCompiledStylesValue::Null));
       }
     }
-    let resolved_namespace_name = expr_to_str(namespace_name, state, functions);
-
     namespace_obj.insert(
       COMPILED_KEY.to_owned(),
       Box::new(FlatCompiledStylesValue::Bool(true)),
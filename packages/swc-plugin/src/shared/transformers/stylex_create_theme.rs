@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
 
 use indexmap::IndexMap;
+use swc_core::common::Span;
 
 use crate::shared::{
   constants::common::{COMPILED_KEY, THEME_NAME_KEY},
@@ -23,11 +24,12 @@ pub(crate) fn stylex_create_theme(
   variables: &EvaluateResultValue,
   state: &mut StateManager,
   typed_variables: &mut IndexMap<String, Box<FlatCompiledStylesValue>>,
+  span: Span,
 ) -> (
   IndexMap<String, Box<FlatCompiledStylesValue>>,
   IndexMap<String, Box<InjectableStyle>>,
 ) {
-  let theme_name_key_value = validate_theme_variables(theme_vars, state);
+  let theme_name_key_value = validate_theme_variables(theme_vars, span);
 
   let mut rules_by_at_rule: IndexMap<String, Vec<String>> = IndexMap::new();
 
@@ -68,7 +70,7 @@ pub(crate) fn stylex_create_theme(
 
         theme_vars_str_value
       }
-      EvaluateResultValue::ThemeRef(theme_ref) => theme_ref.get(key.as_str()).0.clone(),
+      EvaluateResultValue::ThemeRef(theme_ref) => theme_ref.get(key.as_str()),
       _ => unimplemented!("Unsupported theme vars type"),
     };
 
@@ -155,7 +157,7 @@ pub(crate) fn stylex_create_theme(
       state,
       &FunctionMap::default(),
     ),
-    EvaluateResultValue::ThemeRef(theme_ref) => theme_ref.get(THEME_NAME_KEY).0.to_owned(),
+    EvaluateResultValue::ThemeRef(theme_ref) => theme_ref.get(THEME_NAME_KEY),
     _ => unimplemented!("Unsupported theme vars type"),
   };
 
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod stylex_create_theme {
   use indexmap::IndexMap;
-  use swc_core::ecma::ast::PropOrSpread;
+  use swc_core::{common::DUMMY_SP, ecma::ast::PropOrSpread};
 
   use crate::shared::{
     enums::data_structures::evaluate_result_value::EvaluateResultValue,
@@ -131,6 +131,7 @@ mod stylex_create_theme {
       &created_theme,
       &mut StateManager::default(),
       &mut IndexMap::default(),
+      DUMMY_SP,
     );
 
     let key = class_name_output
@@ -197,6 +198,7 @@ mod stylex_create_theme {
       &created_theme,
       &mut StateManager::default(),
       &mut IndexMap::default(),
+      DUMMY_SP,
     );
 
     let key = class_name_output
@@ -286,6 +288,7 @@ mod stylex_create_theme {
       &created_theme,
       &mut StateManager::default(),
       &mut IndexMap::default(),
+      DUMMY_SP,
     );
 
     let (class_name_output_2, css_output_2) = stylex_create_theme(
@@ -293,6 +296,7 @@ mod stylex_create_theme {
       &created_theme_2,
       &mut StateManager::default(),
       &mut IndexMap::default(),
+      DUMMY_SP,
     );
 
     assert_eq!(class_name_output, class_name_output_2);
@@ -341,6 +345,7 @@ mod stylex_create_theme {
       &created_theme,
       &mut StateManager::default(),
       &mut IndexMap::default(),
+      DUMMY_SP,
     );
 
     let (class_name_output_2, css_output_2) = stylex_create_theme(
@@ -348,6 +353,7 @@ mod stylex_create_theme {
       &created_theme_2,
       &mut StateManager::default(),
       &mut IndexMap::default(),
+      DUMMY_SP,
     );
 
     assert_ne!(class_name_output, class_name_output_2);
@@ -400,6 +406,7 @@ mod stylex_create_theme {
       &created_theme,
       &mut StateManager::default(),
       &mut IndexMap::default(),
+      DUMMY_SP,
     );
 
     assert_eq!(
@@ -448,6 +455,7 @@ mod stylex_create_theme {
       &created_theme,
       &mut StateManager::default(),
       &mut IndexMap::default(),
+      DUMMY_SP,
     );
 
     assert_eq!(
@@ -31,7 +31,7 @@ pub fn expr_to_num(expr_num: &Expr, traversal_state: &mut StateManager, fns: &Fu
     Expr::Lit(lit) => lit_to_num(lit),
     Expr::Unary(unary) => unari_to_num(unary, traversal_state, fns),
     Expr::Bin(lit) => {
-      let mut state = Box::new(EvaluationState::new(traversal_state));
+      let mut state = EvaluationState::new(traversal_state, fns);
 
       match binary_expr_to_num(lit, &mut state, fns) {
         Some(result) => result,
@@ -106,65 +106,67 @@ pub fn binary_expr_to_num(
 
   let result = match &op {
     BinaryOp::Add => {
-      expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        + expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        + expr_to_num(right.as_expr()?, state.traversal_state, fns)
     }
     BinaryOp::Sub => {
-      expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        - expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        - expr_to_num(right.as_expr()?, state.traversal_state, fns)
     }
     BinaryOp::Mul => {
-      expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        * expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        * expr_to_num(right.as_expr()?, state.traversal_state, fns)
     }
     BinaryOp::Div => {
-      expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        / expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        / expr_to_num(right.as_expr()?, state.traversal_state, fns)
     }
     BinaryOp::Mod => {
-      expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        % expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
-    }
-    BinaryOp::Exp => expr_to_num(left.as_expr()?, &mut state.traversal_state, fns).powf(
-      expr_to_num(right.as_expr()?, &mut state.traversal_state, fns),
-    ),
+      expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        % expr_to_num(right.as_expr()?, state.traversal_state, fns)
+    }
+    BinaryOp::Exp => expr_to_num(left.as_expr()?, state.traversal_state, fns).powf(expr_to_num(
+      right.as_expr()?,
+      state.traversal_state,
+      fns,
+    )),
     BinaryOp::RShift => {
-      ((expr_to_num(left.as_expr()?, &mut state.traversal_state, fns) as i32)
-        >> expr_to_num(right.as_expr()?, &mut state.traversal_state, fns) as i32) as f64
+      ((expr_to_num(left.as_expr()?, state.traversal_state, fns) as i32)
+        >> expr_to_num(right.as_expr()?, state.traversal_state, fns) as i32) as f64
     }
     BinaryOp::LShift => {
-      ((expr_to_num(left.as_expr()?, &mut state.traversal_state, fns) as i32)
-        << expr_to_num(right.as_expr()?, &mut state.traversal_state, fns) as i32) as f64
+      ((expr_to_num(left.as_expr()?, state.traversal_state, fns) as i32)
+        << expr_to_num(right.as_expr()?, state.traversal_state, fns) as i32) as f64
     }
     BinaryOp::BitAnd => {
-      ((expr_to_num(left.as_expr()?, &mut state.traversal_state, fns) as i32)
-        & expr_to_num(right.as_expr()?, &mut state.traversal_state, fns) as i32) as f64
+      ((expr_to_num(left.as_expr()?, state.traversal_state, fns) as i32)
+        & expr_to_num(right.as_expr()?, state.traversal_state, fns) as i32) as f64
     }
     BinaryOp::BitOr => {
-      ((expr_to_num(left.as_expr()?, &mut state.traversal_state, fns) as i32)
-        | expr_to_num(right.as_expr()?, &mut state.traversal_state, fns) as i32) as f64
+      ((expr_to_num(left.as_expr()?, state.traversal_state, fns) as i32)
+        | expr_to_num(right.as_expr()?, state.traversal_state, fns) as i32) as f64
     }
     BinaryOp::BitXor => {
-      ((expr_to_num(left.as_expr()?, &mut state.traversal_state, fns) as i32)
-        ^ expr_to_num(right.as_expr()?, &mut state.traversal_state, fns) as i32) as f64
+      ((expr_to_num(left.as_expr()?, state.traversal_state, fns) as i32)
+        ^ expr_to_num(right.as_expr()?, state.traversal_state, fns) as i32) as f64
     }
     BinaryOp::In => {
-      if expr_to_num(right.as_expr()?, &mut state.traversal_state, fns) == 0.0 {
+      if expr_to_num(right.as_expr()?, state.traversal_state, fns) == 0.0 {
         1.0
       } else {
         0.0
       }
     }
     BinaryOp::InstanceOf => {
-      if expr_to_num(right.as_expr()?, &mut state.traversal_state, fns) == 0.0 {
+      if expr_to_num(right.as_expr()?, state.traversal_state, fns) == 0.0 {
         1.0
       } else {
         0.0
       }
     }
     BinaryOp::EqEq => {
-      if expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        == expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      if expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        == expr_to_num(right.as_expr()?, state.traversal_state, fns)
       {
         1.0
       } else {
@@ -172,8 +174,8 @@ pub fn binary_expr_to_num(
       }
     }
     BinaryOp::NotEq => {
-      if expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        != expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      if expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        != expr_to_num(right.as_expr()?, state.traversal_state, fns)
       {
         1.0
       } else {
@@ -181,8 +183,8 @@ pub fn binary_expr_to_num(
       }
     }
     BinaryOp::EqEqEq => {
-      if expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        == expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      if expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        == expr_to_num(right.as_expr()?, state.traversal_state, fns)
       {
         1.0
       } else {
@@ -190,8 +192,8 @@ pub fn binary_expr_to_num(
       }
     }
     BinaryOp::NotEqEq => {
-      if expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        != expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      if expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        != expr_to_num(right.as_expr()?, state.traversal_state, fns)
       {
         1.0
       } else {
@@ -199,8 +201,8 @@ pub fn binary_expr_to_num(
       }
     }
     BinaryOp::Lt => {
-      if expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        < expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      if expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        < expr_to_num(right.as_expr()?, state.traversal_state, fns)
       {
         1.0
       } else {
@@ -208,8 +210,8 @@ pub fn binary_expr_to_num(
       }
     }
     BinaryOp::LtEq => {
-      if expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        <= expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      if expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        <= expr_to_num(right.as_expr()?, state.traversal_state, fns)
       {
         1.0
       } else {
@@ -217,8 +219,8 @@ pub fn binary_expr_to_num(
       }
     }
     BinaryOp::Gt => {
-      if expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        > expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      if expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        > expr_to_num(right.as_expr()?, state.traversal_state, fns)
       {
         1.0
       } else {
@@ -226,8 +228,8 @@ pub fn binary_expr_to_num(
       }
     }
     BinaryOp::GtEq => {
-      if expr_to_num(left.as_expr()?, &mut state.traversal_state, fns)
-        >= expr_to_num(right.as_expr()?, &mut state.traversal_state, fns)
+      if expr_to_num(left.as_expr()?, state.traversal_state, fns)
+        >= expr_to_num(right.as_expr()?, state.traversal_state, fns)
       {
         1.0
       } else {
@@ -253,8 +255,8 @@ pub fn binary_expr_to_num(
       let right = right.as_expr().unwrap();
       let right_confident = state.confident;
 
-      let left = expr_to_num(left, &mut state.traversal_state, fns);
-      let right = expr_to_num(right, &mut state.traversal_state, fns);
+      let left = expr_to_num(left, state.traversal_state, fns);
+      let right = expr_to_num(right, state.traversal_state, fns);
 
       state.confident = left_confident && (left != 0.0 || right_confident);
 
@@ -286,8 +288,8 @@ pub fn binary_expr_to_num(
       let right = right.as_expr().unwrap();
       let right_confident = state.confident;
 
-      let left = expr_to_num(left, &mut state.traversal_state, fns);
-      let right = expr_to_num(right, &mut state.traversal_state, fns);
+      let left = expr_to_num(left, state.traversal_state, fns);
+      let right = expr_to_num(right, state.traversal_state, fns);
 
       state.confident = left_confident && (left == 0.0 || right_confident);
 
@@ -319,8 +321,8 @@ pub fn binary_expr_to_num(
       let right = right.as_expr().unwrap();
       let right_confident = state.confident;
 
-      let left = expr_to_num(left, &mut state.traversal_state, fns);
-      let right = expr_to_num(right, &mut state.traversal_state, fns);
+      let left = expr_to_num(left, state.traversal_state, fns);
+      let right = expr_to_num(right, state.traversal_state, fns);
 
       state.confident = left_confident && !!(left == 0.0 || right_confident);
 
@@ -336,8 +338,8 @@ pub fn binary_expr_to_num(
     }
     // #endregion Logical
     BinaryOp::ZeroFillRShift => {
-      ((expr_to_num(left.as_expr()?, &mut state.traversal_state, fns) as i32)
-        >> expr_to_num(right.as_expr()?, &mut state.traversal_state, fns) as i32) as f64
+      ((expr_to_num(left.as_expr()?, state.traversal_state, fns) as i32)
+        >> expr_to_num(right.as_expr()?, state.traversal_state, fns) as i32) as f64
     }
   };
 
@@ -351,13 +353,15 @@ pub fn ident_to_number(ident: &Ident, traveral_state: &mut StateManager, fns: &F
     Some(var_decl) => {
       let var_decl_expr = get_expr_from_var_decl(var_decl);
 
-      let mut state: EvaluationState = EvaluationState::new(traveral_state);
-
       match &var_decl_expr {
-        Expr::Bin(bin_expr) => match binary_expr_to_num(bin_expr, &mut state, fns) {
-          Some(result) => result,
-          None => panic!("Binary expression is not a number"),
-        },
+        Expr::Bin(bin_expr) => {
+          let mut state = EvaluationState::new(traveral_state, fns);
+
+          match binary_expr_to_num(bin_expr, &mut state, fns) {
+            Some(result) => result,
+            None => panic!("Binary expression is not a number"),
+          }
+        }
         Expr::Unary(unary_expr) => unari_to_num(unary_expr, traveral_state, fns),
         Expr::Lit(lit) => lit_to_num(lit),
         _ => panic!("Varable {:?} is not a number", var_decl_expr),
@@ -471,7 +475,7 @@ pub fn transform_bin_expr_to_number(
   traversal_state: &mut StateManager,
   fns: &FunctionMap,
 ) -> f64 {
-  let mut state = Box::new(EvaluationState::new(traversal_state));
+  let mut state = EvaluationState::new(traversal_state, fns);
   let op = bin.op;
   let Some(left) = evaluate_cached(&bin.left, &mut state, fns) else {
     panic!("Left expression is not a number")
@@ -1,6 +1,6 @@
 use std::{
   any::type_name,
-  collections::HashSet,
+  collections::HashMap,
   fs,
   hash::{DefaultHasher, Hash, Hasher},
   ops::Deref,
@@ -10,16 +10,20 @@ use std::{
 use path_clean::PathClean;
 use radix_fmt::radix;
 use swc_core::{
+  atoms::Atom,
   common::{FileName, DUMMY_SP},
-  ecma::ast::{
-    BinaryOp, Decl, Expr, Id, Ident, ImportDecl, ImportSpecifier, KeyValueProp, Lit, MemberExpr,
-    Module, ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, Pat, Prop, PropName, PropOrSpread,
-    Stmt, VarDeclarator,
+  ecma::{
+    ast::{
+      BinExpr, BinaryOp, Decl, Expr, FnDecl, FnExpr, Id, Ident, ImportDecl, ImportSpecifier,
+      KeyValueProp, Lit, MemberExpr, ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, Pat,
+      Prop, PropName, PropOrSpread, Stmt, TsEnumDecl, TsEnumMemberId, VarDeclarator,
+    },
+    visit::{VisitMut, VisitMutWith},
   },
 };
 
 use crate::shared::{
-  constants::messages::ILLEGAL_PROP_VALUE,
+  constants::messages::{ILLEGAL_PROP_VALUE, INVALID_SPREAD},
   enums::{
     data_structures::top_level_expression::{TopLevelExpression, TopLevelExpressionKind},
     misc::VarDeclAction,
@@ -29,10 +33,17 @@ use crate::shared::{
     base_css_type::BaseCSSType,
     functions::{FunctionConfigType, FunctionMap, FunctionType},
     state_manager::{StateManager, EXTENSIONS},
+    stylex_options::HashAlgorithm,
   },
 };
 
-use super::ast::{convertors::transform_shorthand_to_key_values, factories::binding_ident_factory};
+use super::{
+  ast::{
+    convertors::{number_to_expression, transform_shorthand_to_key_values},
+    factories::{binding_ident_factory, prop_or_spread_expression_factory},
+  },
+  diagnostics::span_panic,
+};
 
 pub(crate) fn extract_filename_from_path(path: &FileName) -> String {
   match path {
@@ -59,6 +70,39 @@ pub fn create_hash(value: &str) -> String {
   radix(murmur2::murmur2(value.as_bytes(), 1), 36).to_string()
 }
 
+/// Hash of an atomic class name, using the algorithm and minimum length of the options.
+///
+/// Hashes shorter than `min_length` are extended with hashes of the same value under the
+/// following seeds, then cut to exactly `min_length` characters. With the default options
+/// this is the same as [`create_hash`].
+pub(crate) fn create_class_name_hash(
+  value: &str,
+  algorithm: HashAlgorithm,
+  min_length: usize,
+) -> String {
+  let hash_with_seed = |seed: u32| match algorithm {
+    HashAlgorithm::Murmur2 => radix(murmur2::murmur2(value.as_bytes(), seed), 36).to_string(),
+    HashAlgorithm::Murmur64 => {
+      radix(murmur2::murmur64a(value.as_bytes(), seed as u64), 36).to_string()
+    }
+  };
+
+  let mut hash = hash_with_seed(1);
+
+  if hash.len() < min_length {
+    let mut seed = 2;
+
+    while hash.len() < min_length {
+      hash.push_str(&hash_with_seed(seed));
+      seed += 1;
+    }
+
+    hash.truncate(min_length);
+  }
+
+  hash
+}
+
 pub(crate) fn get_string_val_from_lit(value: &Lit) -> Option<String> {
   match value {
     Lit::Str(str) => Some(format!("{}", str.value)),
@@ -268,23 +312,13 @@ pub(crate) fn type_of<T>(_: T) -> &'static str {
   type_name::<T>()
 }
 
-fn prop_name_eq(a: &PropName, b: &PropName) -> bool {
-  match (a, b) {
-    (PropName::Ident(a), PropName::Ident(b)) => a.sym == b.sym,
-    (PropName::Str(a), PropName::Str(b)) => a.value == b.value,
-    (PropName::Num(a), PropName::Num(b)) => (a.value - b.value).abs() < f64::EPSILON,
-
-    (PropName::BigInt(a), PropName::BigInt(b)) => a.value == b.value,
-    // Add more cases as needed
-    _ => false,
-  }
-}
-
+/// Removes repeated keys of object props like a JS object literal does:
+/// a key keeps the position of its first occurrence and the value of its last one.
 pub(crate) fn remove_duplicates(props: Vec<PropOrSpread>) -> Vec<PropOrSpread> {
-  let mut set = HashSet::new();
-  let mut result = vec![];
+  let mut positions = HashMap::new();
+  let mut result: Vec<PropOrSpread> = vec![];
 
-  for prop in props.into_iter().rev() {
+  for prop in props {
     let key = match &prop {
       PropOrSpread::Prop(prop) => match prop.as_ref() {
         Prop::Shorthand(ident) => ident.sym.clone(),
@@ -298,53 +332,18 @@ pub(crate) fn remove_duplicates(props: Vec<PropOrSpread>) -> Vec<PropOrSpread> {
       _ => continue,
     };
 
-    if set.insert(key) {
-      result.push(prop);
+    match positions.get(&key) {
+      Some(&position) => result[position] = prop,
+      None => {
+        positions.insert(key, result.len());
+        result.push(prop);
+      }
     }
   }
 
-  result.reverse();
-
   result
 }
 
-pub(crate) fn deep_merge_props(
-  old_props: Vec<PropOrSpread>,
-  mut new_props: Vec<PropOrSpread>,
-) -> Vec<PropOrSpread> {
-  for prop in old_props {
-    match prop {
-      PropOrSpread::Prop(prop) => match *prop {
-        Prop::KeyValue(mut kv) => {
-          if new_props.iter().any(|p| match p {
-            PropOrSpread::Prop(p) => match p.as_ref() {
-              Prop::KeyValue(ref existing_kv) => prop_name_eq(&kv.key, &existing_kv.key),
-              _ => false,
-            },
-            _ => false,
-          }) {
-            if let Expr::Object(ref mut obj) = *kv.value {
-              new_props.push(PropOrSpread::Prop(Box::new(Prop::from(KeyValueProp {
-                key: kv.key.clone(),
-                value: Box::new(Expr::Object(ObjectLit {
-                  span: DUMMY_SP,
-                  props: obj.props.clone(),
-                })),
-              }))));
-            }
-          } else {
-            new_props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(kv))));
-          }
-        }
-        _ => new_props.push(PropOrSpread::Prop(Box::new(*prop))),
-      },
-      _ => new_props.push(prop),
-    }
-  }
-
-  remove_duplicates(new_props.into_iter().rev().collect())
-}
-
 pub(crate) fn get_css_value(key_value: KeyValueProp) -> (Box<Expr>, Option<BaseCSSType>) {
   let Some(obj) = key_value.value.as_object() else {
     return (key_value.value, None);
@@ -352,7 +351,7 @@ pub(crate) fn get_css_value(key_value: KeyValueProp) -> (Box<Expr>, Option<BaseC
 
   for prop in obj.props.clone().into_iter() {
     match prop {
-      PropOrSpread::Spread(_) => unimplemented!("Spread"),
+      PropOrSpread::Spread(spread) => span_panic(spread.dot3_token, INVALID_SPREAD),
       PropOrSpread::Prop(mut prop) => {
         transform_shorthand_to_key_values(&mut prop);
 
@@ -362,7 +361,7 @@ pub(crate) fn get_css_value(key_value: KeyValueProp) -> (Box<Expr>, Option<BaseC
               if ident.sym == "syntax" {
                 let value = obj.props.iter().find(|prop| {
                   match prop {
-                    PropOrSpread::Spread(_) => unimplemented!("Spread"),
+                    PropOrSpread::Spread(spread) => span_panic(spread.dot3_token, INVALID_SPREAD),
                     PropOrSpread::Prop(prop) => {
                       let mut prop = prop.clone();
                       transform_shorthand_to_key_values(&mut prop);
@@ -406,7 +405,7 @@ pub(crate) fn get_key_values_from_object(object: &ObjectLit) -> Vec<KeyValueProp
 
   for prop in object.props.iter() {
     match prop {
-      PropOrSpread::Spread(_) => unimplemented!("Spread"),
+      PropOrSpread::Spread(spread) => span_panic(spread.dot3_token, INVALID_SPREAD),
       PropOrSpread::Prop(prop) => {
         let mut prop = prop.clone();
 
@@ -429,46 +428,55 @@ pub(crate) fn dashify(s: &str) -> String {
   after.to_lowercase()
 }
 
-pub(crate) fn fill_top_level_expressions(module: &Module, state: &mut StateManager) {
-  module.clone().body.iter().for_each(|item| match &item {
-    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
-      if let Decl::Var(decl_var) = &export_decl.decl {
+pub(crate) fn fill_top_level_expressions(module_items: &[ModuleItem], state: &mut StateManager) {
+  module_items.iter().for_each(|item| match item {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => match &export_decl.decl {
+      Decl::Var(decl_var) => {
         for decl in &decl_var.decls {
           if let Some(decl_init) = decl.init.as_ref() {
-            state.top_level_expressions.push(TopLevelExpression(
+            state.add_top_level_expression(TopLevelExpression(
               TopLevelExpressionKind::NamedExport,
               *decl_init.clone(),
               Some(decl.name.as_ident().unwrap().to_id()),
             ));
-            state.declarations.push(decl.clone());
+            state.add_declaration(decl);
           }
         }
       }
-    }
+      Decl::Fn(fn_decl) => state.add_declaration(&fn_decl_to_var_declarator(fn_decl)),
+      Decl::TsEnum(enum_decl) => state.add_declaration(&enum_decl_to_var_declarator(enum_decl)),
+      _ => {}
+    },
     ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_decl)) => {
       if let Some(paren) = export_decl.expr.as_paren() {
-        state.top_level_expressions.push(TopLevelExpression(
+        state.add_top_level_expression(TopLevelExpression(
           TopLevelExpressionKind::DefaultExport,
           *paren.expr.clone(),
           None,
         ));
       } else {
-        state.top_level_expressions.push(TopLevelExpression(
+        state.add_top_level_expression(TopLevelExpression(
           TopLevelExpressionKind::DefaultExport,
           *export_decl.expr.clone(),
           None,
         ));
       }
     }
+    ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl))) => {
+      state.add_declaration(&fn_decl_to_var_declarator(fn_decl));
+    }
+    ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(enum_decl))) => {
+      state.add_declaration(&enum_decl_to_var_declarator(enum_decl));
+    }
     ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => {
       for decl in &var.decls {
         if let Some(decl_init) = decl.init.as_ref() {
-          state.top_level_expressions.push(TopLevelExpression(
+          state.add_top_level_expression(TopLevelExpression(
             TopLevelExpressionKind::Stmt,
             *decl_init.clone(),
             Some(decl.name.as_ident().unwrap().to_id()),
           ));
-          state.declarations.push(decl.clone());
+          state.add_declaration(decl);
         }
       }
     }
@@ -476,6 +484,86 @@ pub(crate) fn fill_top_level_expressions(module: &Module, state: &mut StateManag
   });
 }
 
+/// `function name() {}` as `const name = function name() {}`, so that the evaluator can call it.
+fn fn_decl_to_var_declarator(fn_decl: &FnDecl) -> VarDeclarator {
+  VarDeclarator {
+    span: DUMMY_SP,
+    name: Pat::from(binding_ident_factory(fn_decl.ident.clone())),
+    init: Some(Box::new(Expr::Fn(FnExpr {
+      ident: Some(fn_decl.ident.clone()),
+      function: fn_decl.function.clone(),
+    }))),
+    definite: false,
+  }
+}
+
+/// `enum Size { Sm = 4, Md }` as `const Size = { Sm: 4, Md: 5 }`, so that the evaluator can
+/// read its members like the ones of an object.
+fn enum_decl_to_var_declarator(enum_decl: &TsEnumDecl) -> VarDeclarator {
+  let mut values: Vec<(Atom, Expr)> = vec![];
+
+  for member in &enum_decl.members {
+    let value = match &member.init {
+      Some(init) => {
+        let mut init = *init.clone();
+
+        // Initializers can refer to the previous members by name.
+        init.visit_mut_with(&mut EnumMemberReplacer { values: &values });
+
+        init
+      }
+      None => match values.last() {
+        None => number_to_expression(0.0),
+        Some((_, Expr::Lit(Lit::Num(num)))) => number_to_expression(num.value + 1.0),
+        Some((_, previous)) => Expr::from(BinExpr {
+          span: DUMMY_SP,
+          op: BinaryOp::Add,
+          left: Box::new(previous.clone()),
+          right: Box::new(number_to_expression(1.0)),
+        }),
+      },
+    };
+
+    let name = match &member.id {
+      TsEnumMemberId::Ident(ident) => ident.sym.clone(),
+      TsEnumMemberId::Str(str) => str.value.clone(),
+    };
+
+    values.push((name, value));
+  }
+
+  VarDeclarator {
+    span: DUMMY_SP,
+    name: Pat::from(binding_ident_factory(enum_decl.id.clone())),
+    init: Some(Box::new(Expr::from(ObjectLit {
+      span: enum_decl.span,
+      props: values
+        .into_iter()
+        .map(|(name, value)| prop_or_spread_expression_factory(&name, value))
+        .collect(),
+    }))),
+    definite: false,
+  }
+}
+
+struct EnumMemberReplacer<'a> {
+  values: &'a [(Atom, Expr)],
+}
+
+impl VisitMut for EnumMemberReplacer<'_> {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::Ident(ident) = expr {
+      if let Some((_, value)) = self.values.iter().find(|(name, _)| *name == ident.sym) {
+        *expr = value.clone();
+      }
+
+      return;
+    }
+
+    expr.visit_mut_children_with(self);
+  }
+}
+
 pub(crate) fn gen_file_based_identifier(
   file_name: &str,
   export_name: &str,
@@ -498,6 +586,32 @@ pub(crate) fn round_f64(value: f64, decimal_places: u32) -> f64 {
   (value * multiplier).round() / multiplier
 }
 
+/// Resolves `import_path` the way Node.js does, relative to the importing file.
+/// Paths starting with `/` are resolved against `root_dir`.
+pub(crate) fn resolve_module_path(
+  import_path: &str,
+  source_file_path: &str,
+  root_dir: &str,
+) -> Option<PathBuf> {
+  let basedir = Path::new(source_file_path).parent()?.to_path_buf();
+
+  let import_path = match import_path.strip_prefix('/') {
+    Some(root_relative_path) => Path::new(root_dir)
+      .join(root_relative_path)
+      .display()
+      .to_string(),
+    None => import_path.to_string(),
+  };
+
+  node_resolve::Resolver::default()
+    .with_basedir(basedir)
+    .preserve_symlinks(true)
+    .with_extensions(EXTENSIONS)
+    .with_main_fields(vec![String::from("main"), String::from("module")])
+    .resolve(&import_path)
+    .ok()
+}
+
 pub(crate) fn _resolve_node_package_path(package_name: &str) -> Result<PathBuf, String> {
   match node_resolve::Resolver::default()
     .with_basedir(PathBuf::from("./cwd"))
@@ -569,10 +683,6 @@ pub(crate) fn normalize_expr(expr: &mut Expr) -> &mut Expr {
   }
 }
 
-pub(crate) fn sort_numbers_factory() -> impl FnMut(&f64, &f64) -> std::cmp::Ordering {
-  |a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
-}
-
 pub(crate) fn char_code_at(s: &str, index: usize) -> Option<u32> {
   s.chars().nth(index).map(|c| c as u32)
 }
@@ -1,10 +1,18 @@
 use indexmap::IndexMap;
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::ast::{BinExpr, BinaryOp, Expr, Lit, UnaryOp},
+};
 
 use crate::shared::{
   enums::data_structures::{
     flat_compiled_styles_value::FlatCompiledStylesValue, fn_result::FnResult,
   },
-  utils::core::js_to_expr::NestedStringObject,
+  utils::{
+    ast::convertors::string_to_expression,
+    common::{get_key_str, get_key_values_from_object},
+    core::js_to_expr::NestedStringObject,
+  },
 };
 
 use super::{parse_nullable_style::ResolvedArg, props::props};
@@ -22,11 +30,72 @@ pub(crate) fn attrs(styles: &Vec<ResolvedArg>) -> Option<FnResult> {
     attrs_map.insert("class".to_string(), class_name.clone());
   };
 
-  if let Some(_inline_style) = props.get("style") {
-    panic!("Implement inline style");
+  if let Some(FlatCompiledStylesValue::Expr(inline_style)) = props.get("style").map(|v| v.as_ref())
+  {
+    attrs_map.insert(
+      "style".to_string(),
+      Box::new(FlatCompiledStylesValue::Expr(Box::new(
+        inline_style_to_string_expression(inline_style),
+      ))),
+    );
+  };
+
+  if let Some(data_style_src) = props.get("data-style-src") {
+    attrs_map.insert("data-style-src".to_string(), data_style_src.clone());
   };
 
   Some(FnResult::Attrs(
     NestedStringObject::FlatCompiledStylesValues(attrs_map),
   ))
 }
+
+/// Builds `"key:" + value + ";key2:" + value2 + ";"` from the inline style object of `props`.
+/// Entries set to `null` or `undefined` are left out, like the runtime does.
+fn inline_style_to_string_expression(inline_style: &Expr) -> Expr {
+  let key_values = inline_style
+    .as_object()
+    .map(get_key_values_from_object)
+    .unwrap_or_default();
+
+  let mut parts: Vec<Expr> = vec![];
+  let mut prefix = String::default();
+
+  for key_value in key_values {
+    if is_nullish(&key_value.value) {
+      continue;
+    }
+
+    let key = get_key_str(&key_value);
+
+    prefix.push_str(&format!("{}:", key));
+
+    parts.push(string_to_expression(&prefix));
+    parts.push(*key_value.value);
+
+    prefix = ";".to_string();
+  }
+
+  parts.push(string_to_expression(&prefix));
+
+  parts
+    .into_iter()
+    .reduce(|left, right| {
+      Expr::Bin(BinExpr {
+        span: DUMMY_SP,
+        op: BinaryOp::Add,
+        left: Box::new(left),
+        right: Box::new(right),
+      })
+    })
+    .unwrap_or_else(|| string_to_expression(""))
+}
+
+fn is_nullish(value: &Expr) -> bool {
+  match value {
+    Expr::Lit(Lit::Null(_)) => true,
+    Expr::Ident(ident) => ident.sym == "undefined",
+    Expr::Unary(unary) => unary.op == UnaryOp::Void && unary.arg.is_lit(),
+    Expr::Paren(paren) => is_nullish(&paren.expr),
+    _ => false,
+  }
+}
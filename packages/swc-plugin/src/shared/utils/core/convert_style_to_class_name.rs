@@ -6,7 +6,7 @@ use crate::shared::{
     injectable_style::InjectableStyle, pre_rule::PreRuleValue, state_manager::StateManager,
   },
   utils::{
-    common::{create_hash, dashify},
+    common::{create_class_name_hash, dashify},
     css::common::{generate_rule, transform_value},
   },
 };
@@ -77,7 +77,15 @@ pub(crate) fn convert_style_to_class_name(
     modifier_hash_string
   );
 
-  let class_name_hashed = format!("{}{}", prefix, create_hash(string_to_hash.as_str()));
+  let class_name_hashed = state.get_class_name(format!(
+    "{}{}",
+    prefix,
+    create_class_name_hash(
+      string_to_hash.as_str(),
+      state.options.hash_algorithm,
+      state.options.min_hash_length,
+    )
+  ));
 
   let css_rules = generate_rule(
     class_name_hashed.as_str(),
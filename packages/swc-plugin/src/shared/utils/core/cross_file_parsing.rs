@@ -0,0 +1,200 @@
+use std::{
+  cell::RefCell,
+  collections::HashSet,
+  fs, io,
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::SystemTime,
+};
+
+use dashmap::DashMap;
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use swc_core::{
+  common::{
+    comments::SingleThreadedComments,
+    errors::{Handler, HANDLER},
+    sync::Lrc,
+    FileName, SourceMap,
+  },
+  ecma::{
+    ast::{Module, ModuleDecl, ModuleItem, PropName},
+    parser::{EsConfig, Parser, StringInput, Syntax, TsConfig},
+    visit::VisitMutWith,
+  },
+};
+
+use crate::{
+  shared::{
+    constants::common::THEME_NAME_KEY,
+    structures::{
+      plugin_pass::PluginPass, state_manager::StateManager, stylex_options::MetadataOptions,
+      stylex_state_options::StyleXStateOptions,
+    },
+    utils::common::get_string_val_from_lit,
+  },
+  ModuleTransformVisitor,
+};
+
+/// Compiled variables of every `stylex.defineVars()` export of a theme file, by export name.
+pub(crate) type ThemeFileVars = IndexMap<String, Arc<IndexMap<String, String>>>;
+
+/// Path of a theme file together with the `cwd` and options it is compiled with.
+/// The class name prefix, dev mode, hashing and module resolution all change the compiled
+/// variables, so a theme file imported with different options is compiled again.
+type ThemeFileKey = (PathBuf, String);
+
+/// Modification time of a theme file together with the variables compiled from it.
+type CachedThemeFile = (Option<SystemTime>, Arc<ThemeFileVars>);
+
+static THEME_FILES: Lazy<DashMap<ThemeFileKey, CachedThemeFile>> = Lazy::new(DashMap::new);
+
+thread_local! {
+  static THEME_FILES_IN_PROGRESS: RefCell<HashSet<PathBuf>> = RefCell::new(HashSet::new());
+}
+
+/// Parses and compiles the theme file at `file_path` with the options of `state`.
+///
+/// Results are cached per options until the file changes. Returns `None` for a theme file that
+/// (indirectly) imports itself, so that its references fall back to hashing.
+pub(crate) fn get_theme_file_vars(
+  file_path: &Path,
+  state: &StateManager,
+) -> Option<Arc<ThemeFileVars>> {
+  let modified = fs::metadata(file_path)
+    .and_then(|metadata| metadata.modified())
+    .ok();
+
+  let options = get_theme_file_options(state);
+  let cwd = state._state.cwd.clone();
+
+  let key = (file_path.to_path_buf(), format!("{:?} {:?}", cwd, options));
+
+  if let Some(theme_file) = THEME_FILES.get(&key) {
+    if theme_file.0 == modified {
+      return Some(theme_file.1.clone());
+    }
+  }
+
+  if !THEME_FILES_IN_PROGRESS.with(|files| files.borrow_mut().insert(file_path.to_path_buf())) {
+    return None;
+  }
+
+  let theme_file_vars = Arc::new(compile_theme_file(file_path, cwd, options));
+
+  THEME_FILES_IN_PROGRESS.with(|files| files.borrow_mut().remove(file_path));
+
+  THEME_FILES.insert(key, (modified, theme_file_vars.clone()));
+
+  Some(theme_file_vars)
+}
+
+fn get_theme_file_options(state: &StateManager) -> Box<StyleXStateOptions> {
+  let mut options = state.options.clone();
+
+  options.runtime_injection = None;
+  options.metadata = MetadataOptions {
+    comment: Some(false),
+    ..Default::default()
+  };
+
+  options
+}
+
+fn compile_theme_file(
+  file_path: &Path,
+  cwd: Option<PathBuf>,
+  options: Box<StyleXStateOptions>,
+) -> ThemeFileVars {
+  let source_code = fs::read_to_string(file_path).unwrap_or_else(|error| {
+    panic!(
+      "Failed to read theme file {}: {}",
+      file_path.display(),
+      error
+    )
+  });
+
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Real(file_path.to_path_buf()), source_code);
+
+  // Spans of the theme file belong to its own source map,
+  // so its diagnostics can't go through the handler of the importing file.
+  let handler = Handler::with_emitter_writer(Box::new(io::stderr()), Some(cm.clone()));
+
+  HANDLER.set(&handler, || {
+    let mut module = Parser::new(get_syntax(file_path), StringInput::from(&*fm), None)
+      .parse_module()
+      .unwrap_or_else(|error| {
+        error.into_diagnostic(&handler).emit();
+
+        panic!("Failed to parse theme file {}", file_path.display())
+      });
+
+    module.visit_mut_with(&mut ModuleTransformVisitor::new_nested(
+      SingleThreadedComments::default(),
+      Box::new(PluginPass {
+        cwd,
+        filename: FileName::Real(file_path.to_path_buf()),
+      }),
+      options,
+    ));
+
+    collect_defined_vars(&module)
+  })
+}
+
+pub(crate) fn get_syntax(file_path: &Path) -> Syntax {
+  match file_path
+    .extension()
+    .and_then(|extension| extension.to_str())
+  {
+    Some(extension @ ("ts" | "tsx" | "mts" | "cts")) => Syntax::Typescript(TsConfig {
+      tsx: extension == "tsx",
+      ..Default::default()
+    }),
+    _ => Syntax::Es(EsConfig {
+      jsx: true,
+      ..Default::default()
+    }),
+  }
+}
+
+/// Reads the objects `stylex.defineVars()` exports were compiled to, e.g.
+/// `export const vars = { color: "var(--x1)", __themeName__: "x2" }`.
+fn collect_defined_vars(module: &Module) -> ThemeFileVars {
+  module
+    .body
+    .iter()
+    .filter_map(|item| match item {
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => export_decl.decl.as_var(),
+      _ => None,
+    })
+    .flat_map(|var_decl| var_decl.decls.iter())
+    .filter_map(|decl| {
+      let export_name = decl.name.as_ident()?.sym.to_string();
+      let object = decl.init.as_ref()?.as_object()?;
+
+      let vars = object
+        .props
+        .iter()
+        .filter_map(|prop| {
+          let key_value = prop.as_prop()?.as_key_value()?;
+
+          let key = match &key_value.key {
+            PropName::Ident(ident) => ident.sym.to_string(),
+            PropName::Str(str) => str.value.to_string(),
+            _ => return None,
+          };
+
+          let value = get_string_val_from_lit(key_value.value.as_lit()?)?;
+
+          Some((key, value))
+        })
+        .collect::<IndexMap<String, String>>();
+
+      vars
+        .contains_key(THEME_NAME_KEY)
+        .then(|| (export_name, Arc::new(vars)))
+    })
+    .collect()
+}
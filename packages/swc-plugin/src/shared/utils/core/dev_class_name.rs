@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{collections::HashMap, path::Path};
 
 use indexmap::IndexMap;
 
@@ -10,18 +10,20 @@ use crate::shared::{
     state_manager::StateManager,
     types::{FlatCompiledStyles, StylesObjectMap},
   },
+  utils::common::extract_filename_with_ext_from_path,
 };
 
 pub(crate) fn inject_dev_class_names(
   obj: &StylesObjectMap,
   var_name: &Option<String>,
+  namespace_lines: &HashMap<String, usize>,
   state: &StateManager,
 ) -> StylesObjectMap {
   let mut result: StylesObjectMap = IndexMap::new();
 
   for (key, value) in obj.iter() {
     let dev_class_name =
-      namespace_to_dev_class_name(key, var_name, state.get_short_filename().as_str());
+      namespace_to_dev_class_name(key, var_name, namespace_lines.get(key).copied(), state);
 
     let mut dev_class = IndexMap::new();
 
@@ -38,16 +40,43 @@ pub(crate) fn inject_dev_class_names(
   result
 }
 
+/// Replaces the `$$css: true` marker of every namespace with the location it was defined at,
+/// which `props()` and `attrs()` collect into the `data-style-src` attribute.
+pub(crate) fn inject_debug_data_props(
+  obj: &StylesObjectMap,
+  namespace_lines: &HashMap<String, usize>,
+  state: &StateManager,
+) -> StylesObjectMap {
+  let file_name = extract_filename_with_ext_from_path(&state._state.filename)
+    .unwrap_or_else(|| "UnknownFile".to_string());
+
+  let mut result = obj.clone();
+
+  for (key, value) in result.iter_mut() {
+    if let Some(compiled_key) = value.get_mut(COMPILED_KEY) {
+      let source = match namespace_lines.get(key) {
+        Some(line) => format!("{}:{}", file_name, line),
+        None => file_name.clone(),
+      };
+
+      **compiled_key = FlatCompiledStylesValue::String(source);
+    }
+  }
+
+  result
+}
+
 pub(crate) fn convert_to_test_styles(
   obj: &IndexMap<String, Box<FlatCompiledStyles>>,
   var_name: &Option<String>,
+  namespace_lines: &HashMap<String, usize>,
   state: &StateManager,
 ) -> IndexMap<String, Box<FlatCompiledStyles>> {
   let mut result: IndexMap<String, Box<FlatCompiledStyles>> = IndexMap::new();
 
   for (key, _value) in obj.iter() {
     let dev_class_name =
-      namespace_to_dev_class_name(key, var_name, state.get_short_filename().as_str());
+      namespace_to_dev_class_name(key, var_name, namespace_lines.get(key).copied(), state);
 
     let mut dev_class = IndexMap::new();
 
@@ -70,24 +99,37 @@ pub(crate) fn convert_to_test_styles(
 fn namespace_to_dev_class_name(
   namespace: &str,
   var_name: &Option<String>,
-  filename: &str,
+  line: Option<usize>,
+  state: &StateManager,
 ) -> String {
+  let filename = state.get_short_filename();
+
   // Get the basename of the file without the extension
-  let basename = Path::new(filename)
+  let basename = Path::new(&filename)
     .file_stem()
     .and_then(|os_str| os_str.to_str())
     .unwrap_or("");
 
   // Build up the class name, and sanitize it of disallowed characters
-  let class_name = format!(
-    "{}__{}{}",
-    basename,
-    var_name
-      .as_ref()
-      .map(|var_name| format!("{}.", var_name))
-      .unwrap_or_default(),
-    namespace
-  );
+  let class_name = match &state.options.dev_class_name_format {
+    Some(format) => format
+      .replace("[file]", basename)
+      .replace("[varName]", var_name.as_deref().unwrap_or_default())
+      .replace("[namespace]", namespace)
+      .replace(
+        "[line]",
+        &line.map(|line| line.to_string()).unwrap_or_default(),
+      ),
+    None => format!(
+      "{}__{}{}",
+      basename,
+      var_name
+        .as_ref()
+        .map(|var_name| format!("{}.", var_name))
+        .unwrap_or_default(),
+      namespace
+    ),
+  };
   let sanitized_class_name = SANITIZE_CLASS_NAME_REGEX
     .replace_all(&class_name, "$1 $2")
     .to_string();
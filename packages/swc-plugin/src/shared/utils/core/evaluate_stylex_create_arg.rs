@@ -1,20 +1,26 @@
 use indexmap::IndexMap;
 use swc_core::{
-  common::DUMMY_SP,
+  common::{EqIgnoreSpan, Span, Spanned, DUMMY_SP},
   ecma::ast::{
-    ArrowExpr, BinExpr, BinaryOp, BindingIdent, BlockStmtOrExpr, CallExpr, Callee, CondExpr, Expr,
-    ExprOrSpread, Ident, KeyValueProp, ObjectLit, Pat, Prop, PropOrSpread, UnaryExpr, UnaryOp,
+    ArrowExpr, BinExpr, BinaryOp, BindingIdent, BlockStmtOrExpr, CallExpr, Callee, CondExpr, Decl,
+    Expr, ExprOrSpread, Ident, KeyValueProp, Lit, ObjectLit, Pat, Prop, PropName, PropOrSpread,
+    ReturnStmt, Stmt, UnaryExpr, UnaryOp,
   },
 };
 
 use crate::shared::{
   constants::{
-    length_units::LENGTH_UNITS, messages::ILLEGAL_NAMESPACE_VALUE, time_units::get_time_units,
+    length_units::LENGTH_UNITS,
+    messages::{
+      ILLEGAL_NAMESPACE_VALUE, INVALID_DYNAMIC_STYLE_FUNCTION_BODY, INVALID_SPREAD,
+      NON_STATIC_VALUE,
+    },
+    time_units::get_time_units,
   },
   enums::data_structures::evaluate_result_value::EvaluateResultValue,
   structures::{
-    evaluate_result::EvaluateResult, functions::FunctionMap, state_manager::StateManager,
-    types::EvaluateResultFns,
+    dynamic_style::DynamicStyle, evaluate_result::EvaluateResult, functions::FunctionMap,
+    state_manager::StateManager, types::EvaluateResultFns,
   },
   utils::{
     ast::{
@@ -24,8 +30,9 @@ use crate::shared::{
       },
       factories::{object_expression_factory, prop_or_spread_expression_factory},
     },
-    common::{create_hash, normalize_expr},
+    common::{create_hash, get_key_values_from_object, normalize_expr},
     css::common::get_number_suffix,
+    diagnostics::span_panic,
     js::evaluate::{evaluate, evaluate_obj_key},
     validators::validate_dynamic_style_params,
   },
@@ -44,7 +51,42 @@ pub fn evaluate_stylex_create_arg(
 
       for prop in &mut style_object.props {
         match prop {
-          PropOrSpread::Spread(_) => unimplemented!("Spread"),
+          PropOrSpread::Spread(spread) => {
+            let spread_result = evaluate(&spread.expr, traversal_state, functions);
+
+            let spread_object = spread_result
+              .value
+              .filter(|_| spread_result.confident)
+              .and_then(|value| value.as_expr().and_then(|expr| expr.as_object()).cloned())
+              .unwrap_or_else(|| span_panic(spread.expr.span(), INVALID_SPREAD));
+
+            for key_value in get_key_values_from_object(&spread_object) {
+              let key_result = evaluate_obj_key(&key_value, traversal_state, functions);
+
+              let key = match key_result.value {
+                Some(key) if key_result.confident => key,
+                _ => {
+                  let key_span = key_value.key.span();
+
+                  span_panic(
+                    if key_span.is_dummy() {
+                      spread.expr.span()
+                    } else {
+                      key_span
+                    },
+                    NON_STATIC_VALUE,
+                  )
+                }
+              };
+
+              let namespace = match key_value.value.as_object() {
+                Some(object) => get_key_values_from_object(object),
+                None => span_panic(spread.expr.span(), ILLEGAL_NAMESPACE_VALUE),
+              };
+
+              insert_namespace(&mut result_value, key.as_expr().unwrap(), namespace);
+            }
+          }
           PropOrSpread::Prop(prop) => {
             let mut prop = prop.clone();
 
@@ -66,65 +108,71 @@ pub fn evaluate_stylex_create_arg(
 
                 let key = key_result.value.unwrap();
 
-                let key_expr = key.as_expr().unwrap();
+                let key_expr = &with_key_span(key.as_expr().unwrap(), &key_value_prop.key);
 
                 let value_path = &mut key_value_prop.value;
 
                 match value_path.as_mut() {
                   Expr::Arrow(fn_path) => {
-                    let all_params = fn_path.params.clone();
-                    validate_dynamic_style_params(&all_params);
-
-                    let params = all_params
-                      .into_iter()
-                      .filter_map(|param| param.as_ident().cloned())
-                      .collect::<Vec<BindingIdent>>();
-
-                    if let BlockStmtOrExpr::Expr(expr) = fn_path.body.as_mut() {
-                      if let Expr::Object(fn_body_object) = normalize_expr(expr) {
-                        let eval_result = evaluate_partial_object_recursively(
-                          fn_body_object,
-                          traversal_state,
-                          functions,
-                          None,
-                        );
-
-                        if !eval_result.confident {
-                          return Box::new(EvaluateResult {
-                            confident: eval_result.confident,
-                            deopt: eval_result.deopt,
-                            value: eval_result.value,
-                            inline_styles: None,
-                            fns: None,
-                          });
-                        }
+                    let params = fn_path.params.clone();
+                    validate_dynamic_style_params(&params);
 
-                        let value = eval_result
-                          .value
-                          .and_then(|value| value.as_expr().cloned())
-                          .and_then(|expr| expr.as_object().cloned())
-                          .expect("Value not an object");
-
-                        let key = expr_to_str(key_expr, traversal_state, functions);
-
-                        fns.insert(key, (params, eval_result.inline_styles.unwrap_or_default()));
-
-                        result_value.insert(
-                          Box::new(key_expr.clone()),
-                          value
-                            .props
-                            .into_iter()
-                            .filter_map(|prop| {
-                              prop.as_prop().and_then(|prop| prop.as_key_value()).cloned()
-                            })
-                            .collect(),
-                        );
-                      } else {
-                        return evaluate(path, traversal_state, functions);
+                    let (stmts, fn_body_object) = match fn_path.body.as_mut() {
+                      BlockStmtOrExpr::Expr(expr) => (vec![], normalize_expr(expr)),
+                      BlockStmtOrExpr::BlockStmt(block) => {
+                        split_dynamic_style_block(&mut block.stmts, block.span)
                       }
-                    } else {
-                      unimplemented!("Block statement")
+                    };
+
+                    let Expr::Object(fn_body_object) = fn_body_object else {
+                      return evaluate(path, traversal_state, functions);
+                    };
+
+                    let eval_result = evaluate_partial_object_recursively(
+                      fn_body_object,
+                      traversal_state,
+                      functions,
+                      None,
+                    );
+
+                    if !eval_result.confident {
+                      return Box::new(EvaluateResult {
+                        confident: eval_result.confident,
+                        deopt: eval_result.deopt,
+                        value: eval_result.value,
+                        inline_styles: None,
+                        fns: None,
+                      });
                     }
+
+                    let value = eval_result
+                      .value
+                      .and_then(|value| value.as_expr().cloned())
+                      .and_then(|expr| expr.as_object().cloned())
+                      .expect("Value not an object");
+
+                    let key = expr_to_str(key_expr, traversal_state, functions);
+
+                    fns.insert(
+                      key,
+                      DynamicStyle {
+                        params,
+                        stmts,
+                        inline_styles: eval_result.inline_styles.unwrap_or_default(),
+                      },
+                    );
+
+                    insert_namespace(
+                      &mut result_value,
+                      key_expr,
+                      value
+                        .props
+                        .into_iter()
+                        .filter_map(|prop| {
+                          prop.as_prop().and_then(|prop| prop.as_key_value()).cloned()
+                        })
+                        .collect(),
+                    );
                   }
                   _ => {
                     let val = evaluate(value_path, traversal_state, functions);
@@ -136,33 +184,20 @@ pub fn evaluate_stylex_create_arg(
                     let value_to_insert = match val.value.unwrap().as_ref() {
                       EvaluateResultValue::Expr(expr) => match expr.as_ref() {
                         Expr::Object(obj_expr) => {
-                          let mut obj_expr_props: Vec<KeyValueProp> = vec![];
-
-                          for prop in obj_expr.clone().props {
-                            match prop {
-                              PropOrSpread::Spread(_) => unimplemented!("Spread"),
-                              PropOrSpread::Prop(mut prop) => {
-                                transform_shorthand_to_key_values(&mut prop);
-
-                                match prop.as_ref() {
-                                  Prop::KeyValue(obj_expr_prop_kv) => {
-                                    obj_expr_props.push(obj_expr_prop_kv.clone())
-                                  }
-
-                                  _ => unimplemented!(),
-                                }
-                              }
-                            }
+                          let mut obj_expr = obj_expr.clone();
+
+                          if let Expr::Object(source) = value_path.as_ref() {
+                            with_key_spans(&mut obj_expr, source);
                           }
 
-                          obj_expr_props
+                          get_key_values_from_object(&obj_expr)
                         }
                         _ => panic!("{}", ILLEGAL_NAMESPACE_VALUE),
                       },
                       _ => panic!("{}", ILLEGAL_NAMESPACE_VALUE),
                     };
 
-                    result_value.insert(Box::new(key_expr.clone()), value_to_insert);
+                    insert_namespace(&mut result_value, key_expr, value_to_insert);
 
                     continue;
                   }
@@ -188,6 +223,100 @@ pub fn evaluate_stylex_create_arg(
   }
 }
 
+/// Splits the block body of a dynamic style function into the variable declarations that
+/// compute intermediate values and the style object it returns.
+fn split_dynamic_style_block(stmts: &mut [Stmt], span: Span) -> (Vec<Stmt>, &mut Expr) {
+  let Some((Stmt::Return(ReturnStmt { arg: Some(arg), .. }), decls)) = stmts.split_last_mut()
+  else {
+    span_panic(span, INVALID_DYNAMIC_STYLE_FUNCTION_BODY)
+  };
+
+  if let Some(stmt) = decls
+    .iter()
+    .find(|stmt| !matches!(stmt, Stmt::Decl(Decl::Var(_))))
+  {
+    span_panic(stmt.span(), INVALID_DYNAMIC_STYLE_FUNCTION_BODY);
+  }
+
+  (decls.to_vec(), normalize_expr(arg))
+}
+
+/// Attaches the span of the namespace key in the source to its evaluated name, so validation
+/// errors can point at it.
+fn with_key_span(key_expr: &Expr, key: &PropName) -> Expr {
+  let mut key_expr = key_expr.clone();
+
+  if let Expr::Lit(Lit::Str(str)) = &mut key_expr {
+    str.span = key.span();
+  }
+
+  key_expr
+}
+
+/// Attaches the spans of the property keys in the source to the evaluated style object, so
+/// validation errors can point at the offending key. Keys that come from spreads or computed
+/// names keep their dummy span.
+fn with_key_spans(evaluated: &mut ObjectLit, source: &ObjectLit) {
+  for prop in evaluated.props.iter_mut() {
+    let Some(key_value) = prop.as_mut_prop().and_then(|prop| prop.as_mut_key_value()) else {
+      continue;
+    };
+
+    let Some(key) = static_key_str(&key_value.key) else {
+      continue;
+    };
+
+    let Some(source_key_value) = source
+      .props
+      .iter()
+      .rev()
+      .filter_map(|prop| prop.as_prop().and_then(|prop| prop.as_key_value()))
+      .find(|source_key_value| static_key_str(&source_key_value.key) == Some(key))
+    else {
+      continue;
+    };
+
+    let span = source_key_value.key.span();
+
+    match &mut key_value.key {
+      PropName::Ident(ident) => ident.span = span,
+      PropName::Str(str) => str.span = span,
+      _ => {}
+    }
+
+    if let (Expr::Object(evaluated), Expr::Object(source)) =
+      (key_value.value.as_mut(), source_key_value.value.as_ref())
+    {
+      with_key_spans(evaluated, source);
+    }
+  }
+}
+
+fn static_key_str(key: &PropName) -> Option<&str> {
+  match key {
+    PropName::Ident(ident) => Some(&ident.sym),
+    PropName::Str(str) => Some(&str.value),
+    _ => None,
+  }
+}
+
+/// Later duplicates of a namespace replace the earlier one in place, like object keys do.
+fn insert_namespace(
+  namespaces: &mut IndexMap<Box<Expr>, Vec<KeyValueProp>>,
+  key: &Expr,
+  value: Vec<KeyValueProp>,
+) {
+  match namespaces
+    .iter_mut()
+    .find(|(existing_key, _)| existing_key.as_ref().eq_ignore_span(key))
+  {
+    Some((_, existing_value)) => *existing_value = value,
+    None => {
+      namespaces.insert(Box::new(key.clone()), value);
+    }
+  }
+}
+
 fn evaluate_partial_object_recursively(
   path: &ObjectLit,
   traversal_state: &mut StateManager,
@@ -1,7 +1,7 @@
 use crate::shared::structures::{
   application_order::ApplicationOrder, legacy_expand_shorthands_order::LegacyExpandShorthandsOrder,
   order::Order, order_pair::OrderPair, pre_rule::PreRuleValue,
-  property_specificity_order::PropertySpecificityOrder, stylex_options::StyleResolution,
+  property_specificity::PropertySpecificity, stylex_options::StyleResolution,
   stylex_state_options::StyleXStateOptions,
 };
 
@@ -33,7 +33,7 @@ pub(crate) fn flat_map_expanded_shorthands(
     StyleResolution::LegacyExpandShorthands => {
       LegacyExpandShorthandsOrder::get_expansion_fn(key.as_str())
     }
-    StyleResolution::PropertySpecificity => PropertySpecificityOrder::get_expansion_fn(key.as_str()),
+    StyleResolution::PropertySpecificity => PropertySpecificity::get_expansion_fn(key.as_str()),
   };
 
   if let Some(expansion_fn) = expansion_fn {
@@ -87,6 +87,9 @@ pub(crate) fn convert_object_to_ast(obj: &NestedStringObject) -> Expr {
           FlatCompiledStylesValue::Bool(value) => {
             prop_or_spread_expression_factory(key.as_str(), bool_to_expression(*value))
           }
+          FlatCompiledStylesValue::Expr(value) => {
+            prop_or_spread_expression_factory(key.as_str(), *value.clone())
+          }
           _ => unreachable!("Unsupported value type"),
         };
 
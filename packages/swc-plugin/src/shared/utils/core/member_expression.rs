@@ -1,12 +1,13 @@
 use swc_core::ecma::ast::{Expr, Id, Lit, MemberExpr, MemberProp, ObjectLit, Prop, PropOrSpread};
 
 use crate::shared::{
+  constants::messages::INVALID_SPREAD,
   enums::data_structures::{
     evaluate_result_value::EvaluateResultValue,
     style_vars_to_keep::{NonNullProp, NonNullProps, StyleVarsToKeep},
   },
   structures::{functions::FunctionMap, state_manager::StateManager},
-  utils::{common::increase_ident_count, js::evaluate::evaluate},
+  utils::{common::increase_ident_count, diagnostics::span_panic, js::evaluate::evaluate},
 };
 
 pub(crate) fn member_expression(
@@ -73,7 +74,7 @@ pub(crate) fn member_expression(
             let namespaces = props
               .iter()
               .filter_map(|item| match item {
-                PropOrSpread::Spread(_) => unimplemented!("Spread"),
+                PropOrSpread::Spread(spread) => span_panic(spread.dot3_token, INVALID_SPREAD),
                 PropOrSpread::Prop(prop) => match prop.as_ref() {
                   Prop::KeyValue(key_value) => match key_value.value.as_ref() {
                     Expr::Lit(Lit::Null(_)) => None,
@@ -1,5 +1,6 @@
 pub(crate) mod attrs;
 pub(crate) mod convert_style_to_class_name;
+pub(crate) mod cross_file_parsing;
 pub(crate) mod define_vars_utils;
 pub(crate) mod dev_class_name;
 pub mod evaluate_stylex_create_arg;
@@ -9,6 +10,7 @@ pub(crate) mod js_to_expr;
 pub(crate) mod make_string_expression;
 pub(crate) mod member_expression;
 pub(crate) mod parse_nullable_style;
+pub mod process_stylex_rules;
 pub(crate) mod props;
 pub(crate) mod stylex;
 pub(crate) mod stylex_merge;
@@ -1,15 +1,30 @@
+use std::collections::HashMap;
+
 use indexmap::IndexMap;
-use swc_core::ecma::ast::{Expr, Ident, Lit, MemberExpr, MemberProp};
+use swc_core::ecma::{
+  ast::{ArrowExpr, CallExpr, Callee, Expr, Ident, Lit, MemberExpr, MemberProp, Pat},
+  atoms::Atom,
+  visit::{Fold, FoldWith},
+};
 
 use crate::shared::{
   enums::data_structures::flat_compiled_styles_value::FlatCompiledStylesValue,
   structures::state_manager::StateManager,
-  utils::common::{get_string_val_from_lit, reduce_ident_count},
+  utils::{
+    ast::factories::ident_factory,
+    common::{get_string_val_from_lit, reduce_ident_count},
+  },
 };
 
 #[derive(Debug, PartialEq, Clone)]
 pub(crate) enum StyleObject {
   Style(IndexMap<String, Box<FlatCompiledStylesValue>>),
+  /// A dynamic style function called with static arguments:
+  /// the compiled styles together with the inline styles it sets.
+  Dynamic(
+    IndexMap<String, Box<FlatCompiledStylesValue>>,
+    IndexMap<String, Box<Expr>>,
+  ),
   Nullable,
   Other,
 }
@@ -92,6 +107,135 @@ pub(crate) fn parse_nullable_style(
       }
       StyleObject::Other
     }
+    Expr::Call(call) => parse_dynamic_style(call, state, should_reduce_count),
     _ => StyleObject::Other,
   }
 }
+
+/// The member expression a style argument reads from the `stylex.create` result,
+/// either directly or as the callee of a dynamic style function.
+pub(crate) fn get_style_member(expr: &Expr) -> Option<&MemberExpr> {
+  match expr {
+    Expr::Member(member) => Some(member),
+    Expr::Call(CallExpr {
+      callee: Callee::Expr(callee),
+      ..
+    }) => callee.as_member(),
+    _ => None,
+  }
+}
+
+/// Resolves `styles.namespace(...args)` of a dynamic style function at compile time.
+///
+/// The inline styles are copied with the parameters replaced by the arguments, so only
+/// identifiers and literals are accepted, as they can be repeated without side effects.
+/// Functions with a block body, default values or destructuring are left to run at runtime.
+fn parse_dynamic_style(
+  call: &CallExpr,
+  state: &mut StateManager,
+  should_reduce_count: bool,
+) -> StyleObject {
+  let Some(member) = call.callee.as_expr().and_then(|callee| callee.as_member()) else {
+    return StyleObject::Other;
+  };
+
+  let (Some(obj_ident), MemberProp::Ident(prop_ident)) = (member.obj.as_ident(), &member.prop)
+  else {
+    return StyleObject::Other;
+  };
+
+  let Some(dynamic_style) = state
+    .style_fns
+    .get(obj_ident.sym.as_str())
+    .and_then(|fns| fns.get(prop_ident.sym.as_str()))
+    .filter(|dynamic_style| dynamic_style.is_inlinable())
+    .cloned()
+  else {
+    return StyleObject::Other;
+  };
+
+  let Some(compiled_styles) = state
+    .style_map
+    .get(obj_ident.sym.as_str())
+    .and_then(|styles| styles.get(prop_ident.sym.as_str()))
+    .cloned()
+  else {
+    return StyleObject::Other;
+  };
+
+  let are_args_static = call.args.iter().all(|arg| {
+    arg.spread.is_none()
+      && matches!(
+        arg.expr.as_ref(),
+        Expr::Ident(_) | Expr::Lit(Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_))
+      )
+  });
+
+  if !are_args_static || call.args.len() > dynamic_style.params.len() {
+    return StyleObject::Other;
+  }
+
+  if should_reduce_count {
+    reduce_ident_count(state, obj_ident);
+  }
+
+  let mut params_replacer = ParamsReplacer {
+    params: dynamic_style
+      .params
+      .iter()
+      .filter_map(|param| param.as_ident())
+      .enumerate()
+      .map(|(index, param)| {
+        let arg = call
+          .args
+          .get(index)
+          .map(|arg| *arg.expr.clone())
+          .unwrap_or_else(|| Expr::Ident(ident_factory("undefined")));
+
+        (param.id.sym.clone(), arg)
+      })
+      .collect(),
+  };
+
+  let inline_styles = dynamic_style
+    .inline_styles
+    .into_iter()
+    .map(|(key, value)| (key, value.fold_with(&mut params_replacer)))
+    .collect();
+
+  StyleObject::Dynamic(*compiled_styles, inline_styles)
+}
+
+struct ParamsReplacer {
+  params: HashMap<Atom, Expr>,
+}
+
+impl Fold for ParamsReplacer {
+  fn fold_expr(&mut self, expr: Expr) -> Expr {
+    match &expr {
+      Expr::Ident(ident) => match self.params.get(&ident.sym) {
+        Some(arg) => arg.clone(),
+        None => expr,
+      },
+      _ => expr.fold_children_with(self),
+    }
+  }
+
+  fn fold_arrow_expr(&mut self, arrow: ArrowExpr) -> ArrowExpr {
+    // Parameters of nested functions, like the unit helpers of numeric values, shadow ours
+    let shadowed = arrow
+      .params
+      .iter()
+      .filter_map(|param| match param {
+        Pat::Ident(ident) => self.params.remove_entry(&ident.sym),
+        _ => None,
+      })
+      .collect::<Vec<(Atom, Expr)>>();
+
+    let arrow = arrow.fold_children_with(self);
+
+    self.params.extend(shadowed);
+
+    arrow
+  }
+}
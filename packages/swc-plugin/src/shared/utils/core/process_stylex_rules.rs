@@ -0,0 +1,427 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+use sourcemap::SourceMapBuilder;
+
+use crate::shared::{
+  constants::messages::CLASS_NAME_HASH_COLLISION,
+  structures::{
+    meta_data::MetaData,
+    source_location::SourceLocation,
+    stylesheet_options::{RtlSelector, StyleSheetOptions},
+  },
+};
+
+const CONDITIONAL_AT_RULES: [&str; 4] = ["@media", "@supports", "@container", "@layer"];
+
+/// Stylesheet built by [`process_stylex_rules_with_source_map`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleSheet {
+  pub css: String,
+  /// Version 3 source map, serialized as JSON.
+  pub source_map: String,
+}
+
+/// Two files generated different rules under the same class name, i.e. a hash collision.
+#[derive(Debug, Clone)]
+pub struct ClassNameCollision {
+  pub class_name: String,
+  /// The rule seen first, followed by the one colliding with it.
+  pub rules: [MetaData; 2],
+}
+
+impl fmt::Display for ClassNameCollision {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let describe = |rule: &MetaData| match rule.get_source() {
+      Some(source) => format!("`{}` ({})", rule.get_css(), source),
+      None => format!("`{}`", rule.get_css()),
+    };
+
+    write!(
+      f,
+      "{} \"{}\" is generated by both {} and {}.",
+      CLASS_NAME_HASH_COLLISION,
+      self.class_name,
+      describe(&self.rules[0]),
+      describe(&self.rules[1])
+    )
+  }
+}
+
+impl std::error::Error for ClassNameCollision {}
+
+/// Builds the final stylesheet from the `MetaData` collected across all transformed files.
+///
+/// Rules are deduplicated by class name (failing with a [`ClassNameCollision`] when the same
+/// class name carries different CSS), sorted by priority and grouped into priority buckets
+/// (`floor(priority / 1000)`). Rules that have an RTL variant are emitted twice, scoped to each
+/// direction according to `options.rtl_selector`. Keyframes can't be scoped, so their RTL variant
+/// is emitted as `<name>-rtl` and the rules animating with them switch to it in RTL.
+///
+/// With `options.use_css_layers`, every bucket is wrapped in an `@layer priorityN` block and the
+/// layer order is declared up front. Layer order then decides precedence, so the repeated class
+/// selectors that `generate_css_rule` emits for at-rules are collapsed back to a single class.
+///
+/// With `options.dev`, every rule with a known source location is preceded by a
+/// `/* src/Button.tsx:12 */` comment.
+pub fn process_stylex_rules(
+  rules: &[MetaData],
+  options: &StyleSheetOptions,
+) -> Result<String, ClassNameCollision> {
+  Ok(write_stylesheet(rules, options)?.css)
+}
+
+/// Same as [`process_stylex_rules`], also mapping every rule back to the style that produced it.
+///
+/// Rules without a source location, like the ones of files transformed without a source map,
+/// are left unmapped.
+pub fn process_stylex_rules_with_source_map(
+  rules: &[MetaData],
+  options: &StyleSheetOptions,
+) -> Result<StyleSheet, ClassNameCollision> {
+  let writer = write_stylesheet(rules, options)?;
+
+  let mut builder = SourceMapBuilder::new(None);
+
+  for (line, column, source) in writer.mappings {
+    builder.add(
+      line,
+      column,
+      source.line.saturating_sub(1) as u32,
+      source.column as u32,
+      Some(source.file.as_str()),
+      None,
+      false,
+    );
+  }
+
+  let mut source_map = vec![];
+
+  builder
+    .into_sourcemap()
+    .to_writer(&mut source_map)
+    .expect("Failed to serialize the StyleX source map");
+
+  Ok(StyleSheet {
+    css: writer.css,
+    source_map: String::from_utf8(source_map).expect("Source map is not valid UTF-8"),
+  })
+}
+
+/// Collects the generated CSS along with the position each rule was written at.
+#[derive(Default)]
+struct StyleSheetWriter<'a> {
+  css: String,
+  line: u32,
+  column: u32,
+  mappings: Vec<(u32, u32, &'a SourceLocation)>,
+}
+
+impl<'a> StyleSheetWriter<'a> {
+  fn write(&mut self, chunk: &str) {
+    match chunk.rfind('\n') {
+      Some(index) => {
+        self.line += chunk.matches('\n').count() as u32;
+        self.column = chunk[index + 1..].chars().count() as u32;
+      }
+      None => self.column += chunk.chars().count() as u32,
+    }
+
+    self.css.push_str(chunk);
+  }
+
+  fn write_rule(&mut self, css: &str, source: Option<&'a SourceLocation>) {
+    if let Some(source) = source {
+      self.mappings.push((self.line, self.column, source));
+    }
+
+    self.write(css);
+  }
+}
+
+fn write_stylesheet<'a>(
+  rules: &'a [MetaData],
+  options: &StyleSheetOptions,
+) -> Result<StyleSheetWriter<'a>, ClassNameCollision> {
+  let rtl_selector = options.rtl_selector.unwrap_or_default();
+  let use_css_layers = options.use_css_layers.unwrap_or(false);
+  let dev = options.dev.unwrap_or(false);
+
+  let mut unique_rules: IndexMap<&str, &MetaData> = IndexMap::new();
+
+  for rule in rules {
+    if let Some(existing) = unique_rules.insert(rule.get_class_name(), rule) {
+      check_same_rule(existing, rule)?;
+    }
+  }
+
+  let mut sorted_rules = unique_rules.into_values().collect::<Vec<&MetaData>>();
+
+  let rtl_keyframes = rtl_keyframes_names(&sorted_rules);
+
+  sorted_rules.sort_by(|a, b| a.get_priority().total_cmp(b.get_priority()));
+
+  let mut grouped_rules: Vec<Vec<&MetaData>> = vec![];
+  let mut last_group = None;
+
+  for rule in sorted_rules {
+    let group = priority_group(*rule.get_priority());
+
+    match grouped_rules.last_mut() {
+      Some(current_group) if last_group == Some(group) => current_group.push(rule),
+      _ => grouped_rules.push(vec![rule]),
+    }
+
+    last_group = Some(group);
+  }
+
+  let mut writer = StyleSheetWriter::default();
+
+  if use_css_layers {
+    writer.write(&format!(
+      "\n@layer {};\n",
+      (1..=grouped_rules.len())
+        .map(|index| format!("priority{}", index))
+        .collect::<Vec<String>>()
+        .join(", ")
+    ));
+  }
+
+  for (index, group) in grouped_rules.iter().enumerate() {
+    if index > 0 {
+      writer.write("\n");
+    }
+
+    let wrap_in_layer = use_css_layers && *group[0].get_priority() > 0.0;
+
+    if wrap_in_layer {
+      writer.write(&format!("@layer priority{}{{\n", index + 1));
+    }
+
+    for (rule_index, rule) in group.iter().enumerate() {
+      let source = rule.get_source();
+
+      for (css_index, css) in rule_to_css(rule, rtl_selector, use_css_layers, &rtl_keyframes)
+        .iter()
+        .enumerate()
+      {
+        if rule_index > 0 || css_index > 0 {
+          writer.write("\n");
+        }
+
+        if let Some(source) = source.filter(|_| dev && css_index == 0) {
+          writer.write(&format!("/* {} */\n", source));
+        }
+
+        writer.write_rule(css, source);
+      }
+    }
+
+    if wrap_in_layer {
+      writer.write("\n}");
+    }
+  }
+
+  Ok(writer)
+}
+
+fn check_same_rule(existing: &MetaData, rule: &MetaData) -> Result<(), ClassNameCollision> {
+  if existing.get_css() == rule.get_css() && existing.get_css_rtl() == rule.get_css_rtl() {
+    return Ok(());
+  }
+
+  Err(ClassNameCollision {
+    class_name: rule.get_class_name().to_string(),
+    rules: [existing.clone(), rule.clone()],
+  })
+}
+
+fn priority_group(priority: f64) -> i64 {
+  (priority / 1000.0).floor() as i64
+}
+
+fn rule_to_css(
+  rule: &MetaData,
+  rtl_selector: RtlSelector,
+  use_css_layers: bool,
+  rtl_keyframes: &[&str],
+) -> Vec<String> {
+  let class_name = rule.get_class_name();
+
+  let ltr = if use_css_layers {
+    collapse_repeated_class(rule.get_css(), class_name)
+  } else {
+    rule.get_css().to_string()
+  };
+
+  // Keyframes can't be scoped to a direction, so their RTL variant gets a name of its own
+  // and the rules that animate with them switch to it in RTL.
+  if let Some(name) = keyframes_name(&ltr) {
+    return match rule.get_css_rtl() {
+      Some(rtl) if rtl_keyframes.contains(&name) => {
+        vec![ltr.clone(), rename_keyframes(rtl, rtl_keyframes)]
+      }
+      _ => vec![ltr],
+    };
+  }
+
+  let rtl = match rule.get_css_rtl() {
+    Some(rtl) => Some(rename_keyframes(rtl, rtl_keyframes)),
+    None => Some(rename_keyframes(rule.get_css(), rtl_keyframes))
+      .filter(|rtl| rtl.as_str() != rule.get_css()),
+  };
+
+  let Some(rtl) = rtl else {
+    return vec![ltr];
+  };
+
+  let rtl = if use_css_layers {
+    collapse_repeated_class(&rtl, class_name)
+  } else {
+    rtl
+  };
+
+  // Other non-conditional at-rules have no selector to scope, so only the LTR variant is kept.
+  if ltr.starts_with('@') && !is_conditional_at_rule(&ltr) {
+    return vec![ltr];
+  }
+
+  match rtl_selector {
+    RtlSelector::DirAttribute => vec![
+      map_selectors(&ltr, |selector| {
+        format!("html:not([dir='rtl']) {}", selector)
+      }),
+      map_selectors(&rtl, |selector| format!("html[dir='rtl'] {}", selector)),
+    ],
+    RtlSelector::DirPseudoClass => vec![
+      map_selectors(&ltr, |selector| add_pseudo_class(selector, ":dir(ltr)")),
+      map_selectors(&rtl, |selector| add_pseudo_class(selector, ":dir(rtl)")),
+    ],
+  }
+}
+
+/// Names of the keyframes whose RTL variant differs, which are emitted as `<name>-rtl` in RTL.
+fn rtl_keyframes_names<'a>(rules: &[&'a MetaData]) -> Vec<&'a str> {
+  rules
+    .iter()
+    .filter(|rule| {
+      rule
+        .get_css_rtl()
+        .is_some_and(|rtl| rtl.as_str() != rule.get_css())
+    })
+    .filter_map(|rule| keyframes_name(rule.get_css()))
+    .collect()
+}
+
+fn keyframes_name(css: &str) -> Option<&str> {
+  let name = css.strip_prefix("@keyframes ")?;
+
+  Some(name[..name.find('{')?].trim())
+}
+
+/// Replaces every reference to one of the `keyframes` by the name of its RTL variant.
+fn rename_keyframes(css: &str, keyframes: &[&str]) -> String {
+  keyframes.iter().fold(css.to_string(), |css, name| {
+    replace_name(&css, name, &format!("{}-rtl", name))
+  })
+}
+
+/// Replaces `name` where it is a whole CSS identifier, not a prefix of a longer one.
+fn replace_name(css: &str, name: &str, replacement: &str) -> String {
+  let is_name_char = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+
+  let mut result = String::with_capacity(css.len());
+  let mut rest = css;
+
+  while let Some(index) = rest.find(name) {
+    let (before, after) = rest.split_at(index);
+    let after = &after[name.len()..];
+
+    let is_whole_name = !before.ends_with(is_name_char) && !after.starts_with(is_name_char);
+
+    result.push_str(before);
+    result.push_str(if is_whole_name { replacement } else { name });
+
+    rest = after;
+  }
+
+  result.push_str(rest);
+
+  result
+}
+
+fn is_conditional_at_rule(css: &str) -> bool {
+  CONDITIONAL_AT_RULES
+    .iter()
+    .any(|at_rule| css.starts_with(at_rule))
+}
+
+/// Applies `transform` to every comma separated selector of the innermost style rule,
+/// skipping the preludes of any wrapping at-rules.
+fn map_selectors(css: &str, transform: impl Fn(&str) -> String) -> String {
+  let mut selector_start = 0;
+
+  while css[selector_start..].starts_with('@') {
+    match css[selector_start..].find('{') {
+      Some(index) => selector_start += index + 1,
+      None => return css.to_string(),
+    }
+  }
+
+  let Some(selector_end) = css[selector_start..].find('{').map(|i| i + selector_start) else {
+    return css.to_string();
+  };
+
+  let selectors = css[selector_start..selector_end]
+    .split(',')
+    .map(|selector| transform(selector.trim()))
+    .collect::<Vec<String>>()
+    .join(", ");
+
+  format!(
+    "{}{}{}",
+    &css[..selector_start],
+    selectors,
+    &css[selector_end..]
+  )
+}
+
+/// Pseudo-classes must precede pseudo-elements, so `pseudo_class` is inserted before the first `::`.
+fn add_pseudo_class(selector: &str, pseudo_class: &str) -> String {
+  match selector.find("::") {
+    Some(index) => format!(
+      "{}{}{}",
+      &selector[..index],
+      pseudo_class,
+      &selector[index..]
+    ),
+    None => format!("{}{}", selector, pseudo_class),
+  }
+}
+
+/// Turns `.x1.x1.x1:hover` into `.x1:hover`, leaving longer class names such as `.x1.x12` intact.
+fn collapse_repeated_class(css: &str, class_name: &str) -> String {
+  let class_selector = format!(".{}", class_name);
+  let mut result = String::with_capacity(css.len());
+  let mut rest = css;
+
+  while let Some(index) = rest.find(&class_selector) {
+    let (before, after) = rest.split_at(index);
+
+    result.push_str(before);
+    result.push_str(&class_selector);
+
+    rest = &after[class_selector.len()..];
+
+    while rest.starts_with(&class_selector)
+      && !rest[class_selector.len()..]
+        .starts_with(|c: char| c.is_alphanumeric() || c == '-' || c == '_')
+    {
+      rest = &rest[class_selector.len()..];
+    }
+  }
+
+  result.push_str(rest);
+
+  result
+}
@@ -5,7 +5,10 @@ use crate::{
     enums::data_structures::{
       flat_compiled_styles_value::FlatCompiledStylesValue, fn_result::FnResult,
     },
-    utils::core::js_to_expr::NestedStringObject,
+    utils::{
+      ast::factories::{object_expression_factory, prop_or_spread_expression_factory},
+      core::js_to_expr::NestedStringObject,
+    },
   },
   transform::styleq::common::{styleq, StyleQResult},
 };
@@ -16,6 +19,7 @@ pub(crate) fn props(styles: &Vec<ResolvedArg>) -> Option<FnResult> {
   let StyleQResult {
     class_name,
     inline_style,
+    data_style_src,
   } = styleq(styles);
 
   let mut props_map: IndexMap<String, Box<FlatCompiledStylesValue>> = IndexMap::new();
@@ -27,8 +31,25 @@ pub(crate) fn props(styles: &Vec<ResolvedArg>) -> Option<FnResult> {
     );
   }
 
-  if let Some(_inline_style) = inline_style {
-    unimplemented!("Inline style");
+  if let Some(inline_style) = inline_style {
+    props_map.insert(
+      "style".to_string(),
+      Box::new(FlatCompiledStylesValue::Expr(Box::new(
+        object_expression_factory(
+          inline_style
+            .into_iter()
+            .map(|(key, value)| prop_or_spread_expression_factory(key.as_str(), *value))
+            .collect(),
+        ),
+      ))),
+    );
+  }
+
+  if let Some(data_style_src) = data_style_src {
+    props_map.insert(
+      "data-style-src".to_string(),
+      Box::new(FlatCompiledStylesValue::String(data_style_src)),
+    );
   }
 
   Some(FnResult::Props(
@@ -10,7 +10,7 @@ use crate::shared::{
     common::{reduce_ident_count, reduce_member_expression_count},
     core::{
       make_string_expression::make_string_expression,
-      parse_nullable_style::{parse_nullable_style, ResolvedArg, StyleObject},
+      parse_nullable_style::{get_style_member, parse_nullable_style, ResolvedArg, StyleObject},
     },
   },
 };
@@ -48,15 +48,21 @@ pub(crate) fn stylex_merge(
     let arg = arg.expr.as_ref();
 
     match &arg {
-      Expr::Member(member) => {
+      Expr::Member(_) | Expr::Call(_) => {
         let resolved = parse_nullable_style(arg, state, false);
 
+        let Some(member) = get_style_member(arg) else {
+          bail_out_index = Some(current_index);
+          bail_out = true;
+          break;
+        };
+
         match resolved {
           StyleObject::Other => {
             bail_out_index = Some(current_index);
             bail_out = true;
           }
-          StyleObject::Style(_) => {
+          StyleObject::Style(_) | StyleObject::Dynamic(_, _) => {
             resolved_args.push(ResolvedArg::StyleObject(
               resolved,
               member
@@ -90,16 +96,9 @@ pub(crate) fn stylex_merge(
           bail_out_index = Some(current_index);
           bail_out = true;
         } else {
-          let ident = match alt.as_ref() {
-            Expr::Ident(ident) => ident,
-            Expr::Member(meber) => meber.obj.as_ident().expect("Member obj is not an ident"),
-            _ => panic!("Illegal argument"),
-          };
+          let member = get_style_member(alt).expect("Illegal argument");
 
-          let member = match alt.as_ref() {
-            Expr::Member(meber) => meber,
-            _ => panic!("Illegal argument"),
-          };
+          let ident = member.obj.as_ident().expect("Member obj is not an ident");
 
           resolved_args.push(ResolvedArg::ConditionalStyle(
             test.clone(),
@@ -128,16 +127,9 @@ pub(crate) fn stylex_merge(
           bail_out_index = Some(current_index);
           bail_out = true;
         } else {
-          let ident = match right.as_ref() {
-            Expr::Ident(ident) => ident,
-            Expr::Member(meber) => meber.obj.as_ident().expect("Member obj is not an ident"),
-            _ => panic!("Illegal argument"),
-          };
-
-          let member = match right.as_ref() {
-            Expr::Member(meber) => meber,
-            _ => panic!("Illegal argument"),
-          };
+          let member = get_style_member(right).expect("Illegal argument");
+
+          let ident = member.obj.as_ident().expect("Member obj is not an ident");
 
           resolved_args.push(ResolvedArg::ConditionalStyle(
             left.clone(),
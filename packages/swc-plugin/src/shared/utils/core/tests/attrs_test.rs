@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod attrs {
+  use indexmap::IndexMap;
+  use swc_core::{
+    common::DUMMY_SP,
+    ecma::ast::{BinExpr, BinaryOp, Expr, MemberExpr, MemberProp, UnaryExpr, UnaryOp},
+  };
+
+  use crate::shared::{
+    constants::common::COMPILED_KEY,
+    enums::data_structures::flat_compiled_styles_value::FlatCompiledStylesValue,
+    utils::{
+      ast::{
+        convertors::{
+          ident_to_expression, null_to_expression, number_to_expression, string_to_expression,
+        },
+        factories::ident_factory,
+      },
+      core::{
+        attrs::attrs,
+        parse_nullable_style::{ResolvedArg, StyleObject},
+      },
+    },
+  };
+
+  fn dynamic_style(inline_style: IndexMap<String, Box<Expr>>) -> ResolvedArg {
+    let mut compiled_style = IndexMap::new();
+
+    compiled_style.insert(
+      "color".to_string(),
+      Box::new(FlatCompiledStylesValue::String("x19dipnz".to_string())),
+    );
+    compiled_style.insert(
+      "backgroundColor".to_string(),
+      Box::new(FlatCompiledStylesValue::String("x1t6ud8v".to_string())),
+    );
+    compiled_style.insert(
+      COMPILED_KEY.to_string(),
+      Box::new(FlatCompiledStylesValue::Bool(true)),
+    );
+
+    ResolvedArg::StyleObject(
+      StyleObject::Dynamic(compiled_style, inline_style),
+      ident_factory("styles"),
+      MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(ident_to_expression("styles")),
+        prop: MemberProp::Ident(ident_factory("dynamic")),
+      },
+    )
+  }
+
+  fn concat(left: Expr, right: Expr) -> Expr {
+    Expr::Bin(BinExpr {
+      span: DUMMY_SP,
+      op: BinaryOp::Add,
+      left: Box::new(left),
+      right: Box::new(right),
+    })
+  }
+
+  #[test]
+  fn leaves_nullish_inline_styles_out_of_style_attribute() {
+    let mut inline_style = IndexMap::new();
+
+    inline_style.insert("--color".to_string(), Box::new(null_to_expression()));
+    inline_style.insert(
+      "--background-color".to_string(),
+      Box::new(string_to_expression("blue")),
+    );
+    inline_style.insert(
+      "--width".to_string(),
+      Box::new(ident_to_expression("undefined")),
+    );
+    inline_style.insert(
+      "--height".to_string(),
+      Box::new(Expr::Unary(UnaryExpr {
+        span: DUMMY_SP,
+        op: UnaryOp::Void,
+        arg: Box::new(number_to_expression(0.0)),
+      })),
+    );
+
+    let result = attrs(&vec![dynamic_style(inline_style)]).unwrap();
+
+    let values = result
+      ._as_attrs()
+      .and_then(|attrs| attrs.as_values())
+      .unwrap();
+
+    assert_eq!(
+      values.get("class").and_then(|class| class.as_string()),
+      Some(&"x19dipnz x1t6ud8v".to_string())
+    );
+    assert_eq!(
+      values.get("style").map(|style| style.as_ref()),
+      Some(&FlatCompiledStylesValue::Expr(Box::new(concat(
+        concat(
+          string_to_expression("--background-color:"),
+          string_to_expression("blue")
+        ),
+        string_to_expression(";")
+      ))))
+    );
+  }
+}
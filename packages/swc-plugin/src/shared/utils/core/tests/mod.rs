@@ -1,2 +1,4 @@
+mod attrs_test;
 mod convert_to_class_name_test;
+mod process_stylex_rules_test;
 mod stylex_tests;
@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod process_stylex_rules {
+  use crate::shared::{
+    structures::{
+      injectable_style::InjectableStyle,
+      meta_data::MetaData,
+      source_location::SourceLocation,
+      stylesheet_options::{RtlSelector, StyleSheetOptions},
+    },
+    utils::core::process_stylex_rules::{
+      process_stylex_rules, process_stylex_rules_with_source_map,
+    },
+  };
+
+  fn rule(class_name: &str, ltr: &str, rtl: Option<&str>, priority: f64) -> MetaData {
+    MetaData::new(
+      class_name.to_string(),
+      InjectableStyle {
+        ltr: ltr.to_string(),
+        rtl: rtl.map(|rtl| rtl.to_string()),
+        priority: Some(priority),
+      },
+    )
+  }
+
+  fn located_rule(class_name: &str, ltr: &str, priority: f64, line: usize) -> MetaData {
+    let mut rule = rule(class_name, ltr, None, priority);
+
+    rule.set_source(Some(SourceLocation {
+      file: "src/Button.tsx".to_string(),
+      line,
+      column: 4,
+    }));
+
+    rule
+  }
+
+  #[test]
+  fn sorts_rules_by_priority() {
+    let rules = vec![
+      rule("x1", ".x1:hover{color:red}", None, 3130.0),
+      rule("x2", ".x2{color:blue}", None, 3000.0),
+      rule("x3", ":root{--x3:1px;}", None, 1.0),
+    ];
+
+    assert_eq!(
+      process_stylex_rules(&rules, &StyleSheetOptions::default()).unwrap(),
+      ":root{--x3:1px;}\n.x2{color:blue}\n.x1:hover{color:red}"
+    );
+  }
+
+  #[test]
+  fn dedupes_rules_by_class_name() {
+    let rules = vec![
+      rule("x1", ".x1{color:red}", None, 3000.0),
+      rule("x2", ".x2{margin:0}", None, 1000.0),
+      rule("x1", ".x1{color:red}", None, 3000.0),
+    ];
+
+    assert_eq!(
+      process_stylex_rules(&rules, &StyleSheetOptions::default()).unwrap(),
+      ".x2{margin:0}\n.x1{color:red}"
+    );
+  }
+
+  #[test]
+  fn reports_class_name_collisions_between_files() {
+    let rules = vec![
+      located_rule("x1", ".x1{color:red}", 3000.0, 3),
+      located_rule("x1", ".x1{color:blue}", 3000.0, 7),
+    ];
+
+    let collision = process_stylex_rules(&rules, &StyleSheetOptions::default()).unwrap_err();
+
+    assert_eq!(collision.class_name, "x1");
+    assert!(collision.to_string().ends_with(
+      "\"x1\" is generated by both `.x1{color:red}` (src/Button.tsx:3) and `.x1{color:blue}` (src/Button.tsx:7)."
+    ));
+  }
+
+  #[test]
+  fn scopes_rtl_rules_with_dir_attribute() {
+    let rules = vec![
+      rule(
+        "x1",
+        ".x1{margin-left:0}",
+        Some(".x1{margin-right:0}"),
+        3000.0,
+      ),
+      rule(
+        "x2",
+        "@media (min-width: 800px){.x2.x2{left:0}}",
+        Some("@media (min-width: 800px){.x2.x2{right:0}}"),
+        3200.0,
+      ),
+    ];
+
+    assert_eq!(
+      process_stylex_rules(&rules, &StyleSheetOptions::default()).unwrap(),
+      "html:not([dir='rtl']) .x1{margin-left:0}\n\
+       html[dir='rtl'] .x1{margin-right:0}\n\
+       @media (min-width: 800px){html:not([dir='rtl']) .x2.x2{left:0}}\n\
+       @media (min-width: 800px){html[dir='rtl'] .x2.x2{right:0}}"
+    );
+  }
+
+  #[test]
+  fn scopes_rtl_rules_with_dir_pseudo_class() {
+    let rules = vec![rule(
+      "x1",
+      ".x1::before{margin-left:0}",
+      Some(".x1::before{margin-right:0}"),
+      8000.0,
+    )];
+
+    let options = StyleSheetOptions {
+      rtl_selector: Some(RtlSelector::DirPseudoClass),
+      ..Default::default()
+    };
+
+    assert_eq!(
+      process_stylex_rules(&rules, &options).unwrap(),
+      ".x1:dir(ltr)::before{margin-left:0}\n.x1:dir(rtl)::before{margin-right:0}"
+    );
+  }
+
+  #[test]
+  fn renames_rtl_keyframes_and_their_animations() {
+    let rules = vec![
+      rule(
+        "x1-B",
+        "@keyframes x1-B{from{left:0;}}",
+        Some("@keyframes x1-B{from{right:0;}}"),
+        1.0,
+      ),
+      rule(
+        "x2-B",
+        "@keyframes x2-B{from{opacity:0;}}",
+        Some("@keyframes x2-B{from{opacity:0;}}"),
+        1.0,
+      ),
+      rule("x3", ".x3{animation-name:x1-B}", None, 3000.0),
+      rule("x4", ".x4{animation-name:x2-B}", None, 3000.0),
+      rule("x5", ".x5{animation-name:x1-Bx}", None, 3000.0),
+    ];
+
+    assert_eq!(
+      process_stylex_rules(&rules, &StyleSheetOptions::default()).unwrap(),
+      [
+        "@keyframes x1-B{from{left:0;}}",
+        "@keyframes x1-B-rtl{from{right:0;}}",
+        "@keyframes x2-B{from{opacity:0;}}",
+        "html:not([dir='rtl']) .x3{animation-name:x1-B}",
+        "html[dir='rtl'] .x3{animation-name:x1-B-rtl}",
+        ".x4{animation-name:x2-B}",
+        ".x5{animation-name:x1-Bx}",
+      ]
+      .join("\n")
+    );
+  }
+
+  #[test]
+  fn wraps_priority_groups_in_css_layers() {
+    let rules = vec![
+      rule("x1", ":root{--x1:1px;}", None, 0.0),
+      rule("x2", ".x2{color:blue}", None, 3000.0),
+      rule(
+        "x3",
+        "@media (min-width: 800px){.x3.x3{color:red}}",
+        None,
+        3200.0,
+      ),
+      rule("x4", ".x4:hover{color:red}", None, 4130.0),
+    ];
+
+    let options = StyleSheetOptions {
+      use_css_layers: Some(true),
+      ..Default::default()
+    };
+
+    assert_eq!(
+      process_stylex_rules(&rules, &options).unwrap(),
+      "\n@layer priority1, priority2, priority3;\n\
+       :root{--x1:1px;}\n\
+       @layer priority2{\n.x2{color:blue}\n@media (min-width: 800px){.x3{color:red}}\n}\n\
+       @layer priority3{\n.x4:hover{color:red}\n}"
+    );
+  }
+
+  #[test]
+  fn keeps_distinct_classes_with_shared_prefix_in_css_layers() {
+    let rules = vec![rule(
+      "x1",
+      "@media (min-width: 800px){.x1.x1.x12{color:red}}",
+      None,
+      3200.0,
+    )];
+
+    let options = StyleSheetOptions {
+      use_css_layers: Some(true),
+      ..Default::default()
+    };
+
+    assert_eq!(
+      process_stylex_rules(&rules, &options).unwrap(),
+      "\n@layer priority1;\n@layer priority1{\n@media (min-width: 800px){.x1.x12{color:red}}\n}"
+    );
+  }
+
+  #[test]
+  fn annotates_rules_with_source_comments_in_dev() {
+    let rules = vec![
+      located_rule("x1", ".x1{color:red}", 3000.0, 12),
+      rule("x2", ".x2{margin:0}", None, 1000.0),
+    ];
+
+    let options = StyleSheetOptions {
+      dev: Some(true),
+      ..Default::default()
+    };
+
+    assert_eq!(
+      process_stylex_rules(&rules, &options).unwrap(),
+      ".x2{margin:0}\n/* src/Button.tsx:12 */\n.x1{color:red}"
+    );
+  }
+
+  #[test]
+  fn maps_rules_back_to_their_source() {
+    let rules = vec![
+      located_rule("x1", ".x1{color:red}", 3000.0, 12),
+      rule("x2", ".x2{margin:0}", None, 1000.0),
+      located_rule("x3", ".x3:hover{color:blue}", 3130.0, 20),
+    ];
+
+    let style_sheet =
+      process_stylex_rules_with_source_map(&rules, &StyleSheetOptions::default()).unwrap();
+
+    assert_eq!(
+      style_sheet.css,
+      ".x2{margin:0}\n.x1{color:red}\n.x3:hover{color:blue}"
+    );
+
+    let source_map = sourcemap::SourceMap::from_slice(style_sheet.source_map.as_bytes())
+      .expect("Source map should be valid");
+
+    assert!(source_map.lookup_token(0, 0).is_none());
+
+    let token = source_map.lookup_token(1, 0).unwrap();
+
+    assert_eq!(token.get_source(), Some("src/Button.tsx"));
+    assert_eq!((token.get_src_line(), token.get_src_col()), (11, 4));
+
+    let token = source_map.lookup_token(2, 0).unwrap();
+
+    assert_eq!((token.get_src_line(), token.get_src_col()), (19, 4));
+  }
+}
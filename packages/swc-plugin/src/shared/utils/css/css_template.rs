@@ -0,0 +1,224 @@
+use indexmap::IndexMap;
+use swc_core::{
+  common::Span,
+  css::ast::{AtRule, ComponentValue, Declaration, DeclarationName, QualifiedRule, Rule},
+  ecma::ast::Expr,
+};
+
+use crate::shared::{
+  constants::messages::{INVALID_CSS_TEMPLATE, INVALID_CSS_TEMPLATE_SELECTOR},
+  utils::{
+    ast::{
+      convertors::{null_to_expression, string_to_expression},
+      factories::{object_expression_factory, prop_or_spread_expression_factory},
+    },
+    css::common::swc_parse_css,
+    diagnostics::span_panic,
+  },
+};
+
+/// Selector the template body is nested in, so that its declarations and nested rules
+/// parse as the contents of a single style rule.
+const WRAPPER_PREFIX: &str = "* {";
+
+/// Value of a property of a template by condition, where the unconditional value is `default`.
+enum StyleValue {
+  Value(String),
+  Conditions(IndexMap<String, StyleValue>),
+}
+
+/// Converts the body of a css`...` template into the style object of a `stylex.create()`
+/// namespace, e.g. `color: red; &:hover { color: blue; }` into
+/// `{ color: { default: "red", ":hover": "blue" } }`.
+///
+/// Nested blocks can only be `&`-prefixed pseudo selectors and at-rules.
+pub(crate) fn css_template_to_style_object(template: &str, span: Span) -> Expr {
+  let source = format!("{}{}\n}}", WRAPPER_PREFIX, template);
+
+  let (stylesheet, errors) = swc_parse_css(&source);
+
+  let stylesheet = match stylesheet {
+    Ok(stylesheet) if errors.is_empty() => stylesheet,
+    _ => span_panic(span, INVALID_CSS_TEMPLATE),
+  };
+
+  let [Rule::QualifiedRule(rule)] = stylesheet.rules.as_slice() else {
+    span_panic(span, INVALID_CSS_TEMPLATE)
+  };
+
+  let mut styles: IndexMap<String, StyleValue> = IndexMap::new();
+
+  collect_block(&rule.block.value, &[], &source, span, &mut styles);
+
+  style_values_to_object(styles)
+}
+
+fn collect_block(
+  values: &[ComponentValue],
+  conditions: &[String],
+  source: &str,
+  span: Span,
+  styles: &mut IndexMap<String, StyleValue>,
+) {
+  for value in values {
+    match value {
+      ComponentValue::Declaration(declaration) => {
+        let (property, value) = declaration_entry(declaration, source);
+
+        let style_value = styles
+          .entry(property)
+          .or_insert_with(|| StyleValue::Conditions(IndexMap::new()));
+
+        insert_conditional_value(style_value, conditions, value);
+      }
+      ComponentValue::QualifiedRule(rule) => {
+        let condition = selector_condition(rule, source, span);
+
+        collect_block(
+          &rule.block.value,
+          &[conditions, &[condition]].concat(),
+          source,
+          span,
+          styles,
+        );
+      }
+      ComponentValue::AtRule(at_rule) => {
+        let Some(block) = &at_rule.block else {
+          span_panic(span, INVALID_CSS_TEMPLATE)
+        };
+
+        let condition = at_rule_condition(at_rule, source);
+
+        collect_block(
+          &block.value,
+          &[conditions, &[condition]].concat(),
+          source,
+          span,
+          styles,
+        );
+      }
+      _ => span_panic(span, INVALID_CSS_TEMPLATE),
+    }
+  }
+}
+
+fn insert_conditional_value(style_value: &mut StyleValue, conditions: &[String], value: String) {
+  let StyleValue::Conditions(values) = style_value else {
+    unreachable!("Only values by condition can be nested")
+  };
+
+  match conditions.split_first() {
+    None => {
+      values.insert("default".to_string(), StyleValue::Value(value));
+    }
+    Some((condition, conditions)) => {
+      let nested = values
+        .entry(condition.clone())
+        .or_insert_with(|| StyleValue::Conditions(IndexMap::new()));
+
+      insert_conditional_value(nested, conditions, value);
+    }
+  }
+}
+
+fn declaration_entry(declaration: &Declaration, source: &str) -> (String, String) {
+  let (property, name_end) = match &declaration.name {
+    // Vendor prefixed properties keep their dashed name, like in object styles.
+    DeclarationName::Ident(ident) if ident.value.starts_with('-') => {
+      (ident.value.to_string(), ident.span.hi)
+    }
+    DeclarationName::Ident(ident) => (camel_case(&ident.value), ident.span.hi),
+    DeclarationName::DashedIdent(ident) => (
+      source[ident.span.lo.0 as usize..ident.span.hi.0 as usize].to_string(),
+      ident.span.hi,
+    ),
+  };
+
+  let value = source[name_end.0 as usize..declaration.span.hi.0 as usize]
+    .trim_start()
+    .trim_start_matches(':')
+    .trim();
+
+  (property, value.to_string())
+}
+
+fn selector_condition(rule: &QualifiedRule, source: &str, span: Span) -> String {
+  let selector = source_text(source, rule.span.lo.0, rule.block.span.lo.0);
+
+  match selector.strip_prefix('&') {
+    Some(pseudo) if pseudo.starts_with(':') => pseudo.to_string(),
+    _ => span_panic(span, INVALID_CSS_TEMPLATE_SELECTOR),
+  }
+}
+
+fn at_rule_condition(at_rule: &AtRule, source: &str) -> String {
+  let block_start = at_rule
+    .block
+    .as_ref()
+    .map_or(at_rule.span.hi.0, |block| block.span.lo.0);
+
+  source_text(source, at_rule.span.lo.0, block_start)
+}
+
+/// Text of the source between two byte positions, with whitespace collapsed.
+fn source_text(source: &str, lo: u32, hi: u32) -> String {
+  source[lo as usize..hi as usize]
+    .split_whitespace()
+    .collect::<Vec<&str>>()
+    .join(" ")
+}
+
+fn camel_case(property: &str) -> String {
+  let mut parts = property.split('-');
+
+  let first = parts.next().unwrap_or_default().to_string();
+
+  parts.fold(first, |mut result, part| {
+    let mut chars = part.chars();
+
+    if let Some(first) = chars.next() {
+      result.extend(first.to_uppercase());
+      result.push_str(chars.as_str());
+    }
+
+    result
+  })
+}
+
+fn style_values_to_object(values: IndexMap<String, StyleValue>) -> Expr {
+  object_expression_factory(
+    values
+      .into_iter()
+      .map(|(key, value)| prop_or_spread_expression_factory(&key, style_value_to_expr(value)))
+      .collect(),
+  )
+}
+
+fn style_value_to_expr(value: StyleValue) -> Expr {
+  match value {
+    StyleValue::Value(value) => string_to_expression(&value),
+    StyleValue::Conditions(values) => {
+      if let (1, Some(StyleValue::Value(value))) = (values.len(), values.get("default")) {
+        return string_to_expression(value);
+      }
+
+      let mut props = vec![];
+
+      // A property that is only set for some conditions doesn't apply by default.
+      if !values.contains_key("default") {
+        props.push(prop_or_spread_expression_factory(
+          "default",
+          null_to_expression(),
+        ));
+      }
+
+      props.extend(
+        values
+          .into_iter()
+          .map(|(key, value)| prop_or_spread_expression_factory(&key, style_value_to_expr(value))),
+      );
+
+      object_expression_factory(props)
+    }
+  }
+}
@@ -1,4 +1,5 @@
 pub(crate) mod common;
+pub(crate) mod css_template;
 pub(crate) mod normalizers;
 pub(crate) mod parser;
 pub(crate) mod tests;
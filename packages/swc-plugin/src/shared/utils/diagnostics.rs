@@ -0,0 +1,26 @@
+use std::panic;
+
+use swc_core::common::{errors::HANDLER, Span};
+
+/// Reports `message` at `span` through the SWC diagnostic handler and aborts the transform.
+///
+/// The handler prints the error with file, line and column next to the offending source,
+/// the same way parser errors are shown. The transform then unwinds without running the panic
+/// hook, like the handler's fatal errors do, so hosts only see the diagnostic. When no handler is
+/// installed (e.g. the plugin is used as a plain library) the message is raised as a panic.
+pub(crate) fn span_panic(span: Span, message: &str) -> ! {
+  if !HANDLER.is_set() {
+    panic!("{}", message)
+  }
+
+  HANDLER.with(|handler| handler.struct_span_err(span, message).emit());
+
+  panic::resume_unwind(Box::new(message.to_string()))
+}
+
+/// Same as `assert!`, but reports the failure at `span` via [`span_panic`].
+pub(crate) fn span_assert(condition: bool, span: Span, message: &str) {
+  if !condition {
+    span_panic(span, message);
+  }
+}
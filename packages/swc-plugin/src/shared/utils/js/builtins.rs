@@ -0,0 +1,830 @@
+use indexmap::IndexMap;
+use swc_core::ecma::ast::{
+  BinaryOp, Expr, ExprOrSpread, Lit, Prop, PropName, PropOrSpread, UnaryOp,
+};
+
+use crate::shared::{
+  enums::data_structures::evaluate_result_value::EvaluateResultValue,
+  structures::{state_manager::StateManager, types::EvaluationCallback},
+  utils::ast::{
+    convertors::{
+      bool_to_expression, ident_to_expression, null_to_expression, number_to_expression,
+      string_to_expression,
+    },
+    factories::{
+      array_expression_factory, object_expression_factory, prop_or_spread_expression_factory,
+    },
+  },
+  utils::js::local_functions::LocalFunctionError,
+};
+
+/// Static JavaScript value the built-ins operate on.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsValue {
+  Undefined,
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+  Array(Vec<JsValue>),
+  Object(IndexMap<String, JsValue>),
+}
+
+/// Outcome of a built-in call.
+pub(crate) enum BuiltinResult {
+  Value(JsValue),
+  /// The built-in is supported, but not with these arguments (e.g. a regular expression).
+  Deopt,
+  /// The built-in is not part of the supported subset, with its qualified name.
+  Unsupported(String),
+  /// A callback exceeded the evaluation limit of local functions.
+  LimitExceeded,
+}
+
+impl From<LocalFunctionError> for BuiltinResult {
+  fn from(error: LocalFunctionError) -> Self {
+    match error {
+      LocalFunctionError::NotStatic => BuiltinResult::Deopt,
+      LocalFunctionError::LimitExceeded => BuiltinResult::LimitExceeded,
+    }
+  }
+}
+
+impl JsValue {
+  pub(crate) fn from_result(value: &EvaluateResultValue) -> Option<Self> {
+    match value {
+      EvaluateResultValue::Expr(expr) => Self::from_expr(expr),
+      EvaluateResultValue::Vec(items) => items
+        .iter()
+        .map(|item| match item {
+          Some(item) => Self::from_result(item),
+          None => Some(JsValue::Undefined),
+        })
+        .collect::<Option<Vec<JsValue>>>()
+        .map(JsValue::Array),
+      EvaluateResultValue::Entries(entries) => entries
+        .iter()
+        .map(|(key, value)| {
+          let key = Self::from_expr(&Expr::Lit(*key.clone()))?.to_js_string();
+          let value = Self::from_expr(&Expr::Lit(*value.clone()))?;
+
+          Some((key, value))
+        })
+        .collect::<Option<IndexMap<String, JsValue>>>()
+        .map(JsValue::Object),
+      _ => None,
+    }
+  }
+
+  pub(crate) fn from_expr(expr: &Expr) -> Option<Self> {
+    match expr {
+      Expr::Lit(Lit::Str(str)) => Some(JsValue::String(str.value.to_string())),
+      Expr::Lit(Lit::Num(num)) => Some(JsValue::Number(num.value)),
+      Expr::Lit(Lit::Bool(bool)) => Some(JsValue::Bool(bool.value)),
+      Expr::Lit(Lit::Null(_)) => Some(JsValue::Null),
+      Expr::Ident(ident) => match ident.sym.as_ref() {
+        "undefined" => Some(JsValue::Undefined),
+        "NaN" => Some(JsValue::Number(f64::NAN)),
+        "Infinity" => Some(JsValue::Number(f64::INFINITY)),
+        _ => None,
+      },
+      Expr::Unary(unary) if unary.op == UnaryOp::Minus => match Self::from_expr(&unary.arg)? {
+        JsValue::Number(value) => Some(JsValue::Number(-value)),
+        _ => None,
+      },
+      Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+        .quasis
+        .first()
+        .and_then(|quasi| quasi.cooked.as_ref())
+        .map(|cooked| JsValue::String(cooked.to_string())),
+      Expr::Paren(paren) => Self::from_expr(&paren.expr),
+      Expr::Array(array) => array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+          Some(ExprOrSpread { spread: None, expr }) => Self::from_expr(expr),
+          Some(_) => None,
+          None => Some(JsValue::Undefined),
+        })
+        .collect::<Option<Vec<JsValue>>>()
+        .map(JsValue::Array),
+      Expr::Object(object) => object
+        .props
+        .iter()
+        .map(|prop| {
+          let PropOrSpread::Prop(prop) = prop else {
+            return None;
+          };
+
+          let Prop::KeyValue(key_value) = prop.as_ref() else {
+            return None;
+          };
+
+          let key = match &key_value.key {
+            PropName::Ident(ident) => ident.sym.to_string(),
+            PropName::Str(str) => str.value.to_string(),
+            PropName::Num(num) => number_to_js_string(num.value),
+            _ => return None,
+          };
+
+          Some((key, Self::from_expr(&key_value.value)?))
+        })
+        .collect::<Option<IndexMap<String, JsValue>>>()
+        .map(JsValue::Object),
+      _ => None,
+    }
+  }
+
+  pub(crate) fn into_expr(self) -> Expr {
+    match self {
+      JsValue::Undefined => ident_to_expression("undefined"),
+      JsValue::Null => null_to_expression(),
+      JsValue::Bool(value) => bool_to_expression(value),
+      JsValue::Number(value) => number_to_expression(value),
+      JsValue::String(value) => string_to_expression(&value),
+      JsValue::Array(items) => array_expression_factory(
+        items
+          .into_iter()
+          .map(|item| {
+            Some(ExprOrSpread {
+              spread: None,
+              expr: Box::new(item.into_expr()),
+            })
+          })
+          .collect(),
+      ),
+      JsValue::Object(props) => object_expression_factory(
+        props
+          .into_iter()
+          .map(|(key, value)| prop_or_spread_expression_factory(&key, value.into_expr()))
+          .collect(),
+      ),
+    }
+  }
+
+  /// JS `ToString`.
+  fn to_js_string(&self) -> String {
+    match self {
+      JsValue::Undefined => "undefined".to_string(),
+      JsValue::Null => "null".to_string(),
+      JsValue::Bool(value) => value.to_string(),
+      JsValue::Number(value) => number_to_js_string(*value),
+      JsValue::String(value) => value.clone(),
+      JsValue::Array(items) => join(items, ","),
+      JsValue::Object(_) => "[object Object]".to_string(),
+    }
+  }
+
+  /// JS `ToNumber`.
+  fn to_number(&self) -> f64 {
+    match self {
+      JsValue::Undefined => f64::NAN,
+      JsValue::Null => 0.0,
+      JsValue::Bool(value) => f64::from(u8::from(*value)),
+      JsValue::Number(value) => *value,
+      JsValue::String(value) => {
+        let value = value.trim();
+
+        if value.is_empty() {
+          0.0
+        } else {
+          value.parse::<f64>().unwrap_or(f64::NAN)
+        }
+      }
+      JsValue::Array(_) => JsValue::String(self.to_js_string()).to_number(),
+      JsValue::Object(_) => f64::NAN,
+    }
+  }
+
+  pub(crate) fn is_truthy(&self) -> bool {
+    match self {
+      JsValue::Undefined | JsValue::Null => false,
+      JsValue::Bool(value) => *value,
+      JsValue::Number(value) => *value != 0.0 && !value.is_nan(),
+      JsValue::String(value) => !value.is_empty(),
+      JsValue::Array(_) | JsValue::Object(_) => true,
+    }
+  }
+
+  /// JS `SameValueZero`, used by `includes`. Arrays and objects are never equal, as every
+  /// evaluated literal is a distinct object.
+  fn same_value_zero(&self, other: &JsValue) -> bool {
+    match (self, other) {
+      (JsValue::Number(a), JsValue::Number(b)) => a == b || (a.is_nan() && b.is_nan()),
+      (JsValue::Array(_), _) | (JsValue::Object(_), _) => false,
+      _ => self == other,
+    }
+  }
+}
+
+/// Evaluates `+` and the equality operators when an operand is a string.
+pub(crate) fn string_binary_op(op: BinaryOp, left: &JsValue, right: &JsValue) -> Option<JsValue> {
+  if !matches!(left, JsValue::String(_)) && !matches!(right, JsValue::String(_)) {
+    return None;
+  }
+
+  let result = match op {
+    BinaryOp::Add => JsValue::String(format!("{}{}", left.to_js_string(), right.to_js_string())),
+    BinaryOp::EqEqEq => JsValue::Bool(left == right),
+    BinaryOp::NotEqEq => JsValue::Bool(left != right),
+    BinaryOp::EqEq | BinaryOp::NotEq => {
+      let is_equal = match (left, right) {
+        (JsValue::String(left), JsValue::String(right)) => left == right,
+        (JsValue::Array(_) | JsValue::Object(_), _)
+        | (_, JsValue::Array(_) | JsValue::Object(_)) => return None,
+        _ => left.to_number() == right.to_number(),
+      };
+
+      JsValue::Bool(is_equal == (op == BinaryOp::EqEq))
+    }
+    _ => return None,
+  };
+
+  Some(result)
+}
+
+/// Whether `object.method` is one of the functions `call_static` evaluates.
+pub(crate) fn is_static_builtin(object: &str, method: &str) -> bool {
+  matches!(
+    (object, method),
+    (
+      "Math",
+      "abs"
+        | "sign"
+        | "trunc"
+        | "floor"
+        | "ceil"
+        | "round"
+        | "sqrt"
+        | "cbrt"
+        | "pow"
+        | "hypot"
+        | "min"
+        | "max"
+    ) | (
+      "Object",
+      "keys" | "values" | "entries" | "fromEntries" | "assign"
+    ) | ("Number", "isInteger" | "isFinite" | "isNaN" | "parseFloat")
+  )
+}
+
+/// Evaluates a call of a function of the `Math`, `Object` or `Number` globals.
+pub(crate) fn call_static(object: &str, method: &str, args: Vec<JsValue>) -> BuiltinResult {
+  let number = |index: usize| args.get(index).map_or(f64::NAN, JsValue::to_number);
+
+  let result = match (object, method) {
+    ("Math", "abs") => JsValue::Number(number(0).abs()),
+    ("Math", "sign") => JsValue::Number(match number(0) {
+      value if value.is_nan() || value == 0.0 => value,
+      value => value.signum(),
+    }),
+    ("Math", "trunc") => JsValue::Number(number(0).trunc()),
+    ("Math", "floor") => JsValue::Number(number(0).floor()),
+    ("Math", "ceil") => JsValue::Number(number(0).ceil()),
+    // Halves are rounded towards +∞, unlike `f64::round`.
+    ("Math", "round") => JsValue::Number((number(0) + 0.5).floor()),
+    ("Math", "sqrt") => JsValue::Number(number(0).sqrt()),
+    ("Math", "cbrt") => JsValue::Number(number(0).cbrt()),
+    ("Math", "pow") => JsValue::Number(number(0).powf(number(1))),
+    ("Math", "hypot") => JsValue::Number(
+      args
+        .iter()
+        .map(|arg| arg.to_number().powi(2))
+        .sum::<f64>()
+        .sqrt(),
+    ),
+    ("Math", "min") => JsValue::Number(args.iter().map(JsValue::to_number).fold(
+      f64::INFINITY,
+      |min, value| {
+        if value.is_nan() || min.is_nan() {
+          f64::NAN
+        } else {
+          min.min(value)
+        }
+      },
+    )),
+    ("Math", "max") => JsValue::Number(args.iter().map(JsValue::to_number).fold(
+      f64::NEG_INFINITY,
+      |max, value| {
+        if value.is_nan() || max.is_nan() {
+          f64::NAN
+        } else {
+          max.max(value)
+        }
+      },
+    )),
+    ("Object", "keys" | "values" | "entries") => {
+      let Some(JsValue::Object(props)) = args.first() else {
+        return BuiltinResult::Deopt;
+      };
+
+      JsValue::Array(
+        props
+          .iter()
+          .map(|(key, value)| match method {
+            "keys" => JsValue::String(key.clone()),
+            "values" => value.clone(),
+            _ => JsValue::Array(vec![JsValue::String(key.clone()), value.clone()]),
+          })
+          .collect(),
+      )
+    }
+    ("Object", "fromEntries") => {
+      let Some(JsValue::Array(entries)) = args.first() else {
+        return BuiltinResult::Deopt;
+      };
+
+      let mut props = IndexMap::new();
+
+      for entry in entries {
+        let JsValue::Array(entry) = entry else {
+          return BuiltinResult::Deopt;
+        };
+
+        props.insert(
+          entry.first().unwrap_or(&JsValue::Undefined).to_js_string(),
+          entry.get(1).cloned().unwrap_or(JsValue::Undefined),
+        );
+      }
+
+      JsValue::Object(props)
+    }
+    // Only called on a new object literal (see `evaluate`), so nothing is mutated.
+    ("Object", "assign") => {
+      let mut props = IndexMap::new();
+
+      for arg in &args {
+        match arg {
+          JsValue::Object(arg_props) => props.extend(
+            arg_props
+              .iter()
+              .map(|(key, value)| (key.clone(), value.clone())),
+          ),
+          JsValue::Undefined | JsValue::Null => {}
+          _ => return BuiltinResult::Deopt,
+        }
+      }
+
+      JsValue::Object(props)
+    }
+    ("Number", "isInteger") => JsValue::Bool(match args.first() {
+      Some(JsValue::Number(value)) => value.is_finite() && value.fract() == 0.0,
+      _ => false,
+    }),
+    ("Number", "isFinite") => {
+      JsValue::Bool(matches!(args.first(), Some(JsValue::Number(value)) if value.is_finite()))
+    }
+    ("Number", "isNaN") => {
+      JsValue::Bool(matches!(args.first(), Some(JsValue::Number(value)) if value.is_nan()))
+    }
+    ("Number", "parseFloat") => JsValue::Number(parse_float(
+      &args.first().unwrap_or(&JsValue::Undefined).to_js_string(),
+    )),
+    _ => return BuiltinResult::Unsupported(format!("{}.{}", object, method)),
+  };
+
+  BuiltinResult::Value(result)
+}
+
+/// Evaluates a method call on an array, string or number value.
+///
+/// `args` are evaluated but not converted yet, as array methods take callbacks.
+pub(crate) fn call_method(
+  receiver: JsValue,
+  method: &str,
+  args: &[Box<EvaluateResultValue>],
+  state: &mut StateManager,
+) -> BuiltinResult {
+  let values = args
+    .iter()
+    .map(|arg| JsValue::from_result(arg))
+    .collect::<Vec<Option<JsValue>>>();
+
+  let value = |index: usize| match values.get(index) {
+    Some(Some(value)) => Some(value.clone()),
+    Some(None) => None,
+    None => Some(JsValue::Undefined),
+  };
+
+  macro_rules! value {
+    ($index:expr) => {
+      match value($index) {
+        Some(value) => value,
+        None => return BuiltinResult::Deopt,
+      }
+    };
+  }
+
+  let result = match receiver {
+    JsValue::Array(items) => {
+      let callback = || match args.first().map(|arg| arg.as_ref()) {
+        Some(EvaluateResultValue::Callback(callback)) => Some(callback.clone()),
+        _ => None,
+      };
+
+      match method {
+        "reduce" => {
+          let Some(callback) = callback() else {
+            return BuiltinResult::Deopt;
+          };
+
+          let mut items = items.iter().cloned().enumerate();
+
+          let mut accumulator = match args.get(1) {
+            Some(_) => value!(1),
+            None => match items.next() {
+              Some((_, item)) => item,
+              None => return BuiltinResult::Deopt,
+            },
+          };
+
+          for (index, item) in items {
+            accumulator = match call_callback(
+              &callback,
+              vec![accumulator, item, JsValue::Number(index as f64)],
+              state,
+            ) {
+              Ok(result) => result,
+              Err(error) => return error.into(),
+            };
+          }
+
+          accumulator
+        }
+        "some" | "every" | "find" | "findIndex" => {
+          let Some(callback) = callback() else {
+            return BuiltinResult::Deopt;
+          };
+
+          let mut found = None;
+
+          for (index, item) in items.iter().enumerate() {
+            let result = match call_callback(
+              &callback,
+              vec![item.clone(), JsValue::Number(index as f64)],
+              state,
+            ) {
+              Ok(result) => result,
+              Err(error) => return error.into(),
+            };
+
+            if result.is_truthy() != (method == "every") {
+              found = Some(index);
+              break;
+            }
+          }
+
+          match method {
+            "some" => JsValue::Bool(found.is_some()),
+            "every" => JsValue::Bool(found.is_none()),
+            "find" => found.map_or(JsValue::Undefined, |index| items[index].clone()),
+            _ => JsValue::Number(found.map_or(-1.0, |index| index as f64)),
+          }
+        }
+        "flat" => {
+          let depth = match value!(0) {
+            JsValue::Undefined => 1.0,
+            depth => depth.to_number(),
+          };
+
+          JsValue::Array(flatten(items, depth))
+        }
+        "includes" => {
+          let search = value!(0);
+
+          JsValue::Bool(items.iter().any(|item| item.same_value_zero(&search)))
+        }
+        "indexOf" => {
+          let search = value!(0);
+
+          JsValue::Number(
+            items
+              .iter()
+              .position(|item| {
+                *item == search && !matches!(item, JsValue::Array(_) | JsValue::Object(_))
+              })
+              .map_or(-1.0, |index| index as f64),
+          )
+        }
+        "join" => {
+          let separator = match value!(0) {
+            JsValue::Undefined => ",".to_string(),
+            separator => separator.to_js_string(),
+          };
+
+          JsValue::String(join(&items, &separator))
+        }
+        "slice" => {
+          let (start, end) = slice_range(items.len(), &value!(0), &value!(1));
+
+          JsValue::Array(items[start..end.max(start)].to_vec())
+        }
+        "concat" => {
+          let mut result = items;
+
+          for index in 0..args.len() {
+            match value!(index) {
+              JsValue::Array(arg_items) => result.extend(arg_items),
+              arg => result.push(arg),
+            }
+          }
+
+          JsValue::Array(result)
+        }
+        _ => return BuiltinResult::Unsupported(format!("Array.prototype.{}", method)),
+      }
+    }
+    JsValue::String(string) => {
+      let chars = string.chars().collect::<Vec<char>>();
+
+      match method {
+        "slice" | "substring" => {
+          let (start, end) = match method {
+            "slice" => slice_range(chars.len(), &value!(0), &value!(1)),
+            _ => substring_range(chars.len(), &value!(0), &value!(1)),
+          };
+
+          JsValue::String(chars[start..end.max(start)].iter().collect())
+        }
+        "toUpperCase" => JsValue::String(string.to_uppercase()),
+        "toLowerCase" => JsValue::String(string.to_lowercase()),
+        "trim" => JsValue::String(string.trim().to_string()),
+        "trimStart" => JsValue::String(string.trim_start().to_string()),
+        "trimEnd" => JsValue::String(string.trim_end().to_string()),
+        "padStart" | "padEnd" => {
+          let length = value!(0).to_number();
+          let fill = match value!(1) {
+            JsValue::Undefined => " ".to_string(),
+            fill => fill.to_js_string(),
+          };
+
+          let missing = if length.is_nan() {
+            0
+          } else {
+            (length as usize).saturating_sub(chars.len())
+          };
+
+          let padding = fill
+            .chars()
+            .cycle()
+            .take(if fill.is_empty() { 0 } else { missing })
+            .collect::<String>();
+
+          JsValue::String(match method {
+            "padStart" => format!("{}{}", padding, string),
+            _ => format!("{}{}", string, padding),
+          })
+        }
+        "replace" | "replaceAll" => {
+          let (JsValue::String(pattern), JsValue::String(replacement)) = (value!(0), value!(1))
+          else {
+            return BuiltinResult::Deopt;
+          };
+
+          // Replacement patterns like `$&` are not supported.
+          if replacement.contains('$') {
+            return BuiltinResult::Deopt;
+          }
+
+          JsValue::String(match method {
+            "replace" => string.replacen(&pattern, &replacement, 1),
+            _ => string.replace(&pattern, &replacement),
+          })
+        }
+        "split" => {
+          let parts = match value!(0) {
+            JsValue::Undefined => vec![JsValue::String(string.clone())],
+            separator => {
+              let separator = separator.to_js_string();
+
+              if separator.is_empty() {
+                chars
+                  .iter()
+                  .map(|char| JsValue::String(char.to_string()))
+                  .collect()
+              } else {
+                string
+                  .split(separator.as_str())
+                  .map(|part| JsValue::String(part.to_string()))
+                  .collect()
+              }
+            }
+          };
+
+          let limit = match value!(1) {
+            JsValue::Undefined => parts.len(),
+            limit => limit.to_number() as usize,
+          };
+
+          JsValue::Array(parts.into_iter().take(limit).collect())
+        }
+        "startsWith" => JsValue::Bool(string.starts_with(&value!(0).to_js_string())),
+        "endsWith" => JsValue::Bool(string.ends_with(&value!(0).to_js_string())),
+        "includes" => JsValue::Bool(string.contains(&value!(0).to_js_string())),
+        "indexOf" => {
+          let search = value!(0).to_js_string();
+
+          JsValue::Number(
+            string
+              .find(&search)
+              .map_or(-1.0, |index| string[..index].chars().count() as f64),
+          )
+        }
+        "repeat" => {
+          let count = value!(0).to_number();
+
+          if !(0.0..=1e6).contains(&count) {
+            return BuiltinResult::Deopt;
+          }
+
+          JsValue::String(string.repeat(count as usize))
+        }
+        _ => return BuiltinResult::Unsupported(format!("String.prototype.{}", method)),
+      }
+    }
+    JsValue::Number(number) => match method {
+      "toFixed" => {
+        let digits = value!(0).to_number();
+        let digits = if digits.is_nan() { 0.0 } else { digits.trunc() };
+
+        if !(0.0..=100.0).contains(&digits) {
+          return BuiltinResult::Deopt;
+        }
+
+        JsValue::String(to_fixed(number, digits as usize))
+      }
+      "toString" => match value!(0) {
+        JsValue::Undefined => JsValue::String(number_to_js_string(number)),
+        radix if radix.to_number() == 10.0 => JsValue::String(number_to_js_string(number)),
+        _ => return BuiltinResult::Deopt,
+      },
+      _ => return BuiltinResult::Unsupported(format!("Number.prototype.{}", method)),
+    },
+    _ => return BuiltinResult::Deopt,
+  };
+
+  BuiltinResult::Value(result)
+}
+
+fn call_callback(
+  callback: &EvaluationCallback,
+  args: Vec<JsValue>,
+  state: &mut StateManager,
+) -> Result<JsValue, LocalFunctionError> {
+  let args = args
+    .into_iter()
+    .map(|arg| Some(EvaluateResultValue::Expr(Box::new(arg.into_expr()))))
+    .collect();
+
+  JsValue::from_expr(&callback(args, state)?).ok_or(LocalFunctionError::NotStatic)
+}
+
+fn join(items: &[JsValue], separator: &str) -> String {
+  items
+    .iter()
+    .map(|item| match item {
+      JsValue::Undefined | JsValue::Null => String::new(),
+      item => item.to_js_string(),
+    })
+    .collect::<Vec<String>>()
+    .join(separator)
+}
+
+fn flatten(items: Vec<JsValue>, depth: f64) -> Vec<JsValue> {
+  items
+    .into_iter()
+    .flat_map(|item| match item {
+      JsValue::Array(nested) if depth >= 1.0 => flatten(nested, depth - 1.0),
+      item => vec![item],
+    })
+    .collect()
+}
+
+/// Bounds of `Array.prototype.slice` and `String.prototype.slice`, where negative indices
+/// count from the end.
+fn slice_range(length: usize, start: &JsValue, end: &JsValue) -> (usize, usize) {
+  let resolve = |value: &JsValue, default: usize| match value {
+    JsValue::Undefined => default,
+    value => {
+      let index = value.to_number();
+      let index = if index.is_nan() { 0.0 } else { index.trunc() };
+
+      if index < 0.0 {
+        (length as f64 + index).max(0.0) as usize
+      } else {
+        (index as usize).min(length)
+      }
+    }
+  };
+
+  (resolve(start, 0), resolve(end, length))
+}
+
+/// Bounds of `String.prototype.substring`, which clamps negative indices and swaps reversed ones.
+fn substring_range(length: usize, start: &JsValue, end: &JsValue) -> (usize, usize) {
+  let resolve = |value: &JsValue, default: usize| match value {
+    JsValue::Undefined => default,
+    value => {
+      let index = value.to_number();
+
+      if index.is_nan() {
+        0
+      } else {
+        index.clamp(0.0, length as f64) as usize
+      }
+    }
+  };
+
+  let (start, end) = (resolve(start, 0), resolve(end, length));
+
+  (start.min(end), start.max(end))
+}
+
+/// JS `Number.prototype.toString()` for radix 10.
+pub(crate) fn number_to_js_string(value: f64) -> String {
+  if value.is_nan() {
+    return "NaN".to_string();
+  }
+
+  if value.is_infinite() {
+    return if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+  }
+
+  if value == 0.0 {
+    return "0".to_string();
+  }
+
+  let abs = value.abs();
+
+  if (1e-7..1e21).contains(&abs) {
+    return format!("{}", value);
+  }
+
+  // Exponential notation, with an explicit sign for positive exponents like JS.
+  let exponential = format!("{:e}", value);
+
+  match exponential.split_once('e') {
+    Some((mantissa, exponent)) if !exponent.starts_with('-') => {
+      format!("{}e+{}", mantissa, exponent)
+    }
+    _ => exponential,
+  }
+}
+
+/// JS `Number.prototype.toFixed()`, which rounds halves away from zero
+/// while `format!` rounds them to even.
+fn to_fixed(value: f64, digits: usize) -> String {
+  if !value.is_finite() || value.abs() >= 1e21 {
+    return number_to_js_string(value);
+  }
+
+  let exact = format!("{:.*}", digits + 30, value.abs());
+  let (_, rest) = exact.split_at(exact.len() - 30);
+
+  let is_half = rest.starts_with('5') && rest[1..].chars().all(|char| char == '0');
+
+  let abs = if is_half {
+    f64::from_bits(value.abs().to_bits() + 1)
+  } else {
+    value.abs()
+  };
+
+  let formatted = format!("{:.*}", digits, abs);
+
+  if value < 0.0
+    && formatted
+      .chars()
+      .any(|char| char.is_ascii_digit() && char != '0')
+  {
+    format!("-{}", formatted)
+  } else {
+    formatted
+  }
+}
+
+/// JS `parseFloat`: the longest numeric prefix of the trimmed string.
+fn parse_float(value: &str) -> f64 {
+  let value = value.trim_start();
+
+  if value.starts_with("Infinity") || value.starts_with("+Infinity") {
+    return f64::INFINITY;
+  }
+
+  if value.starts_with("-Infinity") {
+    return f64::NEG_INFINITY;
+  }
+
+  (1..=value.len())
+    .rev()
+    .filter(|end| value.is_char_boundary(*end))
+    .find_map(|end| {
+      let prefix = &value[..end];
+
+      if prefix.ends_with(|char: char| char.is_ascii_digit() || char == '.') {
+        prefix.parse::<f64>().ok()
+      } else {
+        None
+      }
+    })
+    .unwrap_or(f64::NAN)
+}
@@ -1,26 +1,27 @@
 use core::panic;
-use std::{
-  collections::{HashMap, HashSet},
-  rc::Rc,
-};
+use std::{path::Path, rc::Rc};
 
 use indexmap::IndexMap;
 use swc_core::{
-  common::{EqIgnoreSpan, DUMMY_SP},
+  common::{EqIgnoreSpan, Span, Spanned, DUMMY_SP},
   ecma::{
     ast::{
-      ArrayLit, BlockStmtOrExpr, Callee, ComputedPropName, Expr, ExprOrSpread, Id, Ident,
-      KeyValueProp, Lit, MemberProp, ModuleExportName, Number, ObjectLit, Prop, PropName,
-      PropOrSpread, TplElement, VarDeclarator,
+      ArrayLit, BinExpr, BinaryOp, CallExpr, Callee, ComputedPropName, Expr, ExprOrSpread, Id,
+      Ident, ImportSpecifier, KeyValueProp, Lit, MemberProp, ModuleExportName, Number, ObjectLit,
+      Prop, PropName, PropOrSpread, Str, TplElement, UnaryOp, VarDeclarator,
     },
     utils::{drop_span, ident::IdentLike, ExprExt},
+    visit::{Visit, VisitWith},
   },
 };
 
 use crate::shared::{
   constants::{
     common::{INVALID_METHODS, VALID_CALLEES},
-    messages::{BUILT_IN_FUNCTION, ILLEGAL_PROP_ARRAY_VALUE},
+    messages::{
+      BUILT_IN_FUNCTION, FUNCTION_EVALUATION_LIMIT, ILLEGAL_PROP_ARRAY_VALUE, INVALID_SPREAD,
+      UNDEFINED_THEME_VARIABLE, UNEXPORTED_THEME_VARS,
+    },
   },
   enums::{
     data_structures::{
@@ -28,7 +29,7 @@ use crate::shared::{
       import_path_resolution::{ImportPathResolution, ImportPathResolutionType},
       value_with_default::ValueWithDefault,
     },
-    js::{ArrayJS, MathJS, ObjectJS, StringJS},
+    js::{ArrayJS, ObjectJS, StringJS},
     misc::VarDeclAction,
   },
   structures::{
@@ -39,24 +40,29 @@ use crate::shared::{
     state::EvaluationState,
     state_manager::{add_import_expression, StateManager},
     theme_ref::ThemeRef,
-    types::{FunctionMapIdentifiers, FunctionMapMemberExpression},
   },
   utils::{
     ast::{
       convertors::{
-        big_int_to_expression, binary_expr_to_num, expr_to_num, expr_to_str, number_to_expression,
-        string_to_expression, transform_shorthand_to_key_values,
-      },
-      factories::{
-        array_expression_factory, ident_factory, lit_str_factory, object_expression_factory,
+        big_int_to_expression, binary_expr_to_num, bool_to_expression, expr_to_num, expr_to_str,
+        ident_to_expression, number_to_expression, string_to_expression,
+        transform_shorthand_to_key_values,
       },
+      factories::array_expression_factory,
     },
     common::{
-      char_code_at, deep_merge_props, get_import_by_ident, get_key_str, get_string_val_from_lit,
+      char_code_at, get_import_by_ident, get_key_str, get_string_val_from_lit,
       get_var_decl_by_ident, get_var_decl_from, normalize_expr, remove_duplicates,
-      sort_numbers_factory,
     },
-    js::native_functions::{evaluate_filter, evaluate_join, evaluate_map},
+    core::cross_file_parsing::get_theme_file_vars,
+    css::css_template::css_template_to_style_object,
+    diagnostics::span_panic,
+    js::{
+      builtins::{self, BuiltinResult, JsValue},
+      local_functions::{result_to_expr, LocalFunction, LocalFunctionError},
+      module_constants::get_module_constant,
+      native_functions::{evaluate_filter, evaluate_join, evaluate_map},
+    },
   },
 };
 
@@ -120,13 +126,7 @@ pub fn evaluate(
   traversal_state: &mut StateManager,
   fns: &FunctionMap,
 ) -> Box<EvaluateResult> {
-  let mut state = Box::new(EvaluationState {
-    confident: true,
-    deopt_path: None,
-    added_imports: HashSet::new(),
-    functions: fns.clone(),
-    traversal_state: traversal_state.clone(),
-  });
+  let mut state = EvaluationState::new(traversal_state, fns);
 
   let mut value = evaluate_cached(path, &mut state, fns);
 
@@ -134,17 +134,68 @@ pub fn evaluate(
     value = None;
   }
 
-  *traversal_state = state.traversal_state;
+  // Nested expressions are evaluated without spans, so look the deopt up in the evaluated
+  // expression, or fall back to it, to keep the deopt reportable at a source location.
+  let deopt = state.deopt_path.map(|deopt_path| {
+    if !deopt_path.span().is_dummy() {
+      return deopt_path;
+    }
+
+    match find_spanned_expr(path, &deopt_path) {
+      Some(spanned_deopt_path) => Box::new(spanned_deopt_path),
+      None if path.span().is_dummy() => deopt_path,
+      None => Box::new(path.clone()),
+    }
+  });
+
+  if state.limit_exceeded {
+    let span = deopt.as_ref().map_or(path.span(), |deopt| deopt.span());
+
+    span_panic(span, FUNCTION_EVALUATION_LIMIT);
+  }
 
   Box::new(EvaluateResult {
     confident: state.confident,
     value,
-    deopt: state.deopt_path,
+    deopt,
     inline_styles: None,
     fns: None,
   })
 }
 
+/// The first expression of `path` that equals `target`, which lost its spans, with its spans.
+fn find_spanned_expr(path: &Expr, target: &Expr) -> Option<Expr> {
+  let mut finder = SpannedExprFinder {
+    target,
+    found: None,
+  };
+
+  path.visit_with(&mut finder);
+
+  finder.found
+}
+
+struct SpannedExprFinder<'a> {
+  target: &'a Expr,
+  found: Option<Expr>,
+}
+
+impl Visit for SpannedExprFinder<'_> {
+  fn visit_expr(&mut self, expr: &Expr) {
+    if self.found.is_some() {
+      return;
+    }
+
+    if !expr.span().is_dummy() && expr.eq_ignore_span(self.target) {
+      self.found = Some(expr.clone());
+
+      return;
+    }
+
+    expr.visit_children_with(self);
+  }
+}
+
 fn deopt(path: &Expr, state: &mut EvaluationState) -> Option<Box<EvaluateResultValue>> {
   if state.confident {
     state.confident = false;
@@ -166,102 +217,26 @@ fn _evaluate(
   let path = normalize_expr(path);
 
   let result: Option<Box<EvaluateResultValue>> = match path {
-    Expr::Arrow(arrow) => {
-      let body = arrow.body.clone();
-      let params = arrow.params.clone();
-
-      let ident_params = params
-        .clone()
-        .into_iter()
-        .filter_map(|param| {
-          if param.is_ident() {
-            Some(param.as_ident().unwrap().to_id())
-          } else {
-            None
-          }
-        })
-        .collect::<Vec<Id>>();
-
-      match body.as_ref() {
-        BlockStmtOrExpr::Expr(body_expr) => {
-          if ident_params.len() == params.len() {
-            let arrow_closure_fabric =
-              |functions: FunctionMapIdentifiers,
-               ident_params: Vec<Id>,
-               body_expr: Box<Expr>,
-               traversal_state: StateManager| {
-                move |cb_args: Vec<Option<EvaluateResultValue>>| {
-                  let mut functions = functions.clone();
-
-                  let mut member_expressions: FunctionMapMemberExpression = HashMap::new();
-
-                  ident_params.iter().enumerate().for_each(|(index, ident)| {
-                    if let Some(arg) = cb_args.get(index) {
-                      let expr = arg
-                        .clone()
-                        .and_then(|arg| arg.as_expr().cloned())
-                        .expect("Argument is not an expression");
-
-                      let cl = |arg: Expr| move || arg.clone();
-
-                      let result = (cl)(expr);
-                      let function = FunctionConfig {
-                        fn_ptr: FunctionType::Mapper(Rc::new(result)),
-                        takes_path: false,
-                      };
-                      functions.insert(
-                        Box::new(ident.clone()),
-                        Box::new(FunctionConfigType::Regular(function.clone())),
-                      );
-
-                      member_expressions.insert(
-                        Box::new(ImportSources::Regular("entry".to_string())),
-                        Box::new(functions.clone()),
-                      );
-                    }
-                  });
-
-                  let mut local_state = traversal_state.clone();
-
-                  let result = evaluate(
-                    &body_expr,
-                    &mut local_state,
-                    &FunctionMap {
-                      identifiers: functions,
-                      member_expressions,
-                    },
-                  );
-
-                  let value = result.value;
-
-                  let expr = match value {
-                    Some(res) => res
-                      .as_expr()
-                      .expect("Evaluation result must be an expression")
-                      .clone(),
-                    None => unreachable!("Evaluation result must be non optional"),
-                  };
-
-                  expr
-                }
-              };
+    Expr::Arrow(_) | Expr::Fn(_) => {
+      let Some(function) = LocalFunction::from_expr(path) else {
+        return deopt(path, state);
+      };
 
-            let functions = state.functions.identifiers.clone();
+      let scope = state.functions.identifiers.clone();
 
-            let arrow_closure = Rc::new(arrow_closure_fabric(
-              functions,
-              ident_params,
-              Box::new(*body_expr.clone()),
-              state.traversal_state.clone(),
-            ));
+      let closure = move |cb_args: Vec<Option<EvaluateResultValue>>,
+                          traversal_state: &mut StateManager| {
+        let args = cb_args
+          .into_iter()
+          .take(function.param_count())
+          .map(|arg| arg.as_ref().and_then(result_to_expr))
+          .collect::<Option<Vec<Expr>>>()
+          .ok_or(LocalFunctionError::NotStatic)?;
 
-            return Some(Box::new(EvaluateResultValue::Callback(arrow_closure)));
-          }
+        function.call(args, traversal_state, &scope)
+      };
 
-          None
-        }
-        BlockStmtOrExpr::BlockStmt(_) => None,
-      }
+      return Some(Box::new(EvaluateResultValue::Callback(Rc::new(closure))));
     }
     Expr::Ident(ident) => {
       let ident_id = ident.to_id();
@@ -287,43 +262,64 @@ fn _evaluate(
 
       None
     }
-    Expr::TsAs(_) => unimplemented!("TsAs"),
-    Expr::TsSatisfies(_) => unimplemented!("TsSatisfies"),
-    Expr::Seq(_) => unimplemented!("Seq"),
+    Expr::TsAs(ts_as) => evaluate_cached(&ts_as.expr, state, fns),
+    Expr::TsSatisfies(ts_satisfies) => evaluate_cached(&ts_satisfies.expr, state, fns),
+    Expr::TsConstAssertion(ts_const) => evaluate_cached(&ts_const.expr, state, fns),
+    Expr::TsNonNull(ts_non_null) => evaluate_cached(&ts_non_null.expr, state, fns),
+    Expr::Seq(seq) => match seq.exprs.last() {
+      Some(last) => evaluate_cached(last, state, fns),
+      None => deopt(path, state),
+    },
     Expr::Lit(lit_path) => Some(Box::new(EvaluateResultValue::Expr(Box::new(Expr::Lit(
       lit_path.clone(),
     ))))),
     Expr::Tpl(tpl) => evaluate_quasis(&Expr::Tpl(tpl.clone()), &tpl.quasis, false, state, fns),
-    #[allow(dead_code)]
-    Expr::TaggedTpl(_tagged_tpl) => {
-      unimplemented!("TaggedTpl");
-      // TODO: Uncomment this for implementation of TaggedTpl
-      // evaluate_quasis(
-      //   &Expr::TaggedTpl(_tagged_tpl.clone()),
-      //   &_tagged_tpl.tpl.quasis,
-      //   false,
-      //   state,
-      // )
+    Expr::TaggedTpl(tagged_tpl) => {
+      if !is_css_tag(&tagged_tpl.tag, state.traversal_state) {
+        return deopt(path, state);
+      }
+
+      let template = evaluate_quasis(
+        &Expr::TaggedTpl(tagged_tpl.clone()),
+        &tagged_tpl.tpl.quasis,
+        false,
+        state,
+        fns,
+      )?;
+
+      let Some(template) = template
+        .as_expr()
+        .and_then(|expr| expr.as_lit())
+        .and_then(get_string_val_from_lit)
+      else {
+        return deopt(path, state);
+      };
+
+      Some(Box::new(EvaluateResultValue::Expr(Box::new(
+        css_template_to_style_object(&template, tagged_tpl.span),
+      ))))
+    }
+    Expr::Cond(cond) => {
+      let test = evaluate_cached(&cond.test, state, fns);
+
+      if !state.confident {
+        return None;
+      }
+
+      match test.as_deref().and_then(is_truthy) {
+        Some(true) => evaluate_cached(&cond.cons, state, fns),
+        Some(false) => evaluate_cached(&cond.alt, state, fns),
+        None => deopt(path, state),
+      }
     }
-    Expr::Cond(_) => unimplemented!("Cond"),
     Expr::Paren(_) => {
       panic!("Paren must be normalized before evaluation")
     }
     Expr::Member(member) => {
+      // The path is evaluated without spans, like the indexed callees.
       let parent_is_call_expr = state
         .traversal_state
-        .all_call_expressions
-        .clone()
-        .into_iter()
-        .any(|call_expr| {
-          if let Some(callee) = call_expr.callee.as_expr() {
-            callee
-              .as_ref()
-              .eq_ignore_span(&Expr::Member(member.clone()))
-          } else {
-            false
-          }
-        });
+        .is_callee(&Expr::Member(member.clone()));
 
       let evaluated_value = if parent_is_call_expr {
         None
@@ -388,8 +384,12 @@ fn _evaluate(
                 panic!("Property not found");
               };
 
-              let Expr::Ident(ident) = ident.as_expr().clone() else {
-                panic!("Member not found");
+              // `object.key`, `object["key"]` or `object[0]`.
+              let key = match ident.as_ref() {
+                Expr::Ident(ident) => ident.sym.to_string(),
+                Expr::Lit(Lit::Str(str)) => str.value.to_string(),
+                Expr::Lit(Lit::Num(num)) => builtins::number_to_js_string(num.value),
+                _ => panic!("Member not found"),
               };
 
               let property = props.iter().find(|prop| match prop {
@@ -402,11 +402,7 @@ fn _evaluate(
                   transform_shorthand_to_key_values(&mut prop);
 
                   match prop.as_ref() {
-                    Prop::KeyValue(key_value) => {
-                      let key = get_key_str(key_value);
-
-                      ident.sym == key
-                    }
+                    Prop::KeyValue(key_value) => get_key_str(key_value) == key,
                     _ => unimplemented!("Prop"),
                   }
                 }
@@ -426,6 +422,31 @@ fn _evaluate(
             }
             _ => unimplemented!("Expression"),
           },
+          EvaluateResultValue::Vec(items) => {
+            let Some(EvaluateResultValue::Expr(property)) = propery.as_deref() else {
+              return deopt(path, state);
+            };
+
+            // `array.length`, or `array[index]` with an integer index within bounds.
+            let item = match property.as_ref() {
+              Expr::Ident(Ident { sym: key, .. }) | Expr::Lit(Lit::Str(Str { value: key, .. }))
+                if key == "length" =>
+              {
+                EvaluateResultValue::Expr(Box::new(number_to_expression(items.len() as f64)))
+              }
+              Expr::Lit(Lit::Num(Number { value, .. }))
+                if *value >= 0.0 && value.fract() == 0.0 =>
+              {
+                match items.get(*value as usize) {
+                  Some(Some(item)) => item.clone(),
+                  _ => return deopt(path, state),
+                }
+              }
+              _ => return deopt(path, state),
+            };
+
+            return Some(Box::new(item));
+          }
           EvaluateResultValue::FunctionConfigMap(fc_map) => {
             let key = match propery {
               Some(propery) => match propery.as_ref() {
@@ -459,11 +480,14 @@ fn _evaluate(
               None => panic!("Member not found"),
             };
 
-            let mut cloned_theme_ref = theme_ref.clone();
-
-            let (value, updated_state) = &cloned_theme_ref.get(&key);
+            if !theme_ref.is_defined(&key) {
+              span_panic(
+                member.span,
+                &format!("{} `{}`", UNDEFINED_THEME_VARIABLE, key),
+              );
+            }
 
-            state.traversal_state.combine(updated_state);
+            let value = theme_ref.clone().get(&key);
 
             return Some(Box::new(EvaluateResultValue::Expr(Box::new(
               string_to_expression(value.as_str()),
@@ -475,12 +499,50 @@ fn _evaluate(
         None
       }
     }
-    Expr::Unary(_) => unimplemented!("Unary"),
+    Expr::Unary(unary) => {
+      if unary.op == UnaryOp::Void {
+        return Some(Box::new(EvaluateResultValue::Expr(Box::new(
+          ident_to_expression("undefined"),
+        ))));
+      }
+
+      if unary.op == UnaryOp::TypeOf && matches!(unary.arg.as_ref(), Expr::Arrow(_) | Expr::Fn(_)) {
+        return Some(Box::new(EvaluateResultValue::Expr(Box::new(
+          string_to_expression("function"),
+        ))));
+      }
+
+      let arg = evaluate_cached(&unary.arg, state, fns);
+
+      if !state.confident {
+        return None;
+      }
+
+      let Some(arg) = arg else {
+        return deopt(path, state);
+      };
+
+      let result = match unary.op {
+        UnaryOp::Bang => is_truthy(&arg).map(|value| bool_to_expression(!value)),
+        UnaryOp::Plus => to_number(&arg).map(number_to_expression),
+        UnaryOp::Minus => to_number(&arg).map(|value| number_to_expression(-value)),
+        UnaryOp::Tilde => {
+          to_number(&arg).map(|value| number_to_expression(!to_int32(value) as f64))
+        }
+        UnaryOp::TypeOf => type_of(&arg).map(string_to_expression),
+        _ => None,
+      };
+
+      match result {
+        Some(result) => Some(Box::new(EvaluateResultValue::Expr(Box::new(result)))),
+        None => deopt(path, state),
+      }
+    }
     Expr::Array(arr_path) => {
       let mut arr: Vec<Option<EvaluateResultValue>> = vec![];
 
       for elem in arr_path.elems.iter().flatten() {
-        let elem_value = evaluate(&elem.expr, &mut state.traversal_state, &state.functions);
+        let elem_value = evaluate(&elem.expr, state.traversal_state, state.functions);
 
         if elem_value.confident {
           arr.push(elem_value.value.map(|value| *value));
@@ -506,11 +568,9 @@ fn _evaluate(
             let new_props = spread_expression
               .and_then(|spread| spread.as_expr().cloned())
               .and_then(|expr| expr.object())
-              .expect("Spread must be an object");
+              .unwrap_or_else(|| span_panic(prop.expr.span(), INVALID_SPREAD));
 
-            let merged_object = deep_merge_props(props, new_props.props);
-
-            props = merged_object;
+            props.extend(new_props.props);
 
             continue;
           }
@@ -531,7 +591,7 @@ fn _evaluate(
                   PropName::Num(num) => Some(num.value.to_string()),
                   PropName::Computed(computed) => {
                     let evaluated_result =
-                      evaluate(&computed.expr, &mut state.traversal_state, &state.functions);
+                      evaluate(&computed.expr, state.traversal_state, state.functions);
 
                     if !evaluated_result.confident {
                       if evaluated_result.deopt.is_some() {
@@ -546,8 +606,8 @@ fn _evaluate(
                         .value
                         .and_then(|value| value.as_expr().cloned())
                         .expect("Property must be an expression"),
-                      &mut state.traversal_state,
-                      &state.functions,
+                      state.traversal_state,
+                      state.functions,
                     ))
                   }
                   PropName::BigInt(big_int) => Some(big_int.value.to_string()),
@@ -555,8 +615,8 @@ fn _evaluate(
 
                 let value = evaluate(
                   &path_key_value.value,
-                  &mut state.traversal_state,
-                  &state.functions,
+                  state.traversal_state,
+                  state.functions,
                 );
 
                 if !value.confident {
@@ -597,12 +657,14 @@ fn _evaluate(
                             })
                             .or_else(|| entry.as_expr().cloned())
                         })
-                        .expect(ILLEGAL_PROP_ARRAY_VALUE);
+                        .unwrap_or_else(|| {
+                          span_panic(path_key_value.value.span(), ILLEGAL_PROP_ARRAY_VALUE)
+                        });
 
                       let expr = match expr {
                         Expr::Array(array) => Expr::Array(array),
                         Expr::Lit(lit) => Expr::Lit(lit),
-                        _ => panic!("{}", ILLEGAL_PROP_ARRAY_VALUE,),
+                        _ => span_panic(path_key_value.value.span(), ILLEGAL_PROP_ARRAY_VALUE),
                       };
 
                       elems.push(Some(ExprOrSpread {
@@ -652,6 +714,10 @@ fn _evaluate(
       )))));
     }
     Expr::Bin(bin) => {
+      if let Some(result) = evaluate_string_binary_expr(bin, state, fns) {
+        return result;
+      }
+
       if let Some(result) = binary_expr_to_num(bin, state, fns) {
         let result = number_to_expression(result);
 
@@ -665,10 +731,9 @@ fn _evaluate(
       let mut func: Option<Box<FunctionConfig>> = None;
 
       if let Callee::Expr(callee_expr) = &call.callee {
-        if get_binding(callee_expr, &mut state.traversal_state).is_none()
-          && is_valid_callee(callee_expr)
+        if get_binding(callee_expr, state.traversal_state).is_none() && is_valid_callee(callee_expr)
         {
-          panic!("{}", BUILT_IN_FUNCTION)
+          span_panic(call.span, BUILT_IN_FUNCTION)
         } else if let Expr::Ident(ident) = callee_expr.as_ref() {
           let ident_id = ident.to_id();
 
@@ -677,6 +742,11 @@ fn _evaluate(
               FunctionConfigType::Map(_) => unimplemented!("FunctionConfigType::Map"),
               FunctionConfigType::Regular(fc) => func = Some(Box::new(fc.clone())),
             }
+          } else if let Some(function) = get_var_decl_from(state.traversal_state, ident)
+            .and_then(|decl| decl.init.as_deref())
+            .and_then(LocalFunction::from_expr)
+          {
+            return evaluate_local_function_call(call, &function, state, fns);
           }
         }
 
@@ -688,319 +758,14 @@ fn _evaluate(
             let obj_ident = object.as_ident().unwrap();
 
             if property.is_ident() {
-              if is_valid_callee(object) && !is_invalid_method(property) {
+              if is_valid_callee(object) && !is_invalid_method(property)
+                || is_pure_object_assign(object, property, call)
+              {
                 let callee_name = get_callee_name(object);
 
                 let method_name = get_method_name(property);
 
-                match callee_name.as_str() {
-                  "Math" => {
-                    let Some(first_arg) = &call.args.first() else {
-                      panic!("Math.{} requires an argument", method_name)
-                    };
-
-                    if first_arg.spread.is_some() {
-                      unimplemented!("Spread")
-                    }
-
-                    match method_name.as_ref() {
-                      "pow" => {
-                        func = Some(Box::new(FunctionConfig {
-                          fn_ptr: FunctionType::Callback(Box::new(CallbackType::Math(MathJS::Pow))),
-                          takes_path: false,
-                        }));
-
-                        let Some(second_arg) = call.args.get(1) else {
-                          panic!("Math.pow requires an second argument")
-                        };
-
-                        if second_arg.spread.is_some() {
-                          unimplemented!("Spread")
-                        }
-                        let cached_first_arg = evaluate_cached(&first_arg.expr, state, fns);
-                        let cached_second_arg = evaluate_cached(&second_arg.expr, state, fns);
-
-                        context = Some(Box::new(vec![Some(EvaluateResultValue::Vec(vec![
-                          cached_first_arg.map(|arg| *arg),
-                          cached_second_arg.map(|arg| *arg),
-                        ]))]));
-                      }
-                      "round" | "ceil" | "floor" => {
-                        func = Some(Box::new(FunctionConfig {
-                          fn_ptr: FunctionType::Callback(Box::new(CallbackType::Math(
-                            match method_name.as_ref() {
-                              "round" => MathJS::Round,
-                              "ceil" => MathJS::Ceil,
-                              "floor" => MathJS::Floor,
-                              _ => unreachable!("Invalid method: {}", method_name),
-                            },
-                          ))),
-                          takes_path: false,
-                        }));
-
-                        let cached_first_arg = evaluate_cached(&first_arg.expr, state, fns);
-
-                        context = Some(Box::new(vec![Some(EvaluateResultValue::Expr(Box::new(
-                          cached_first_arg
-                            .and_then(|arg| arg.as_expr().cloned())
-                            .expect("First argument should be an expression"),
-                        )))]));
-                      }
-
-                      "min" | "max" => {
-                        func = Some(Box::new(FunctionConfig {
-                          fn_ptr: FunctionType::Callback(Box::new(CallbackType::Math(
-                            match method_name.as_ref() {
-                              "min" => MathJS::Min,
-                              "max" => MathJS::Max,
-                              _ => unreachable!("Invalid method: {}", method_name),
-                            },
-                          ))),
-                          takes_path: false,
-                        }));
-
-                        let cached_first_arg = evaluate_cached(&first_arg.expr, state, fns);
-
-                        let mut result = vec![cached_first_arg];
-
-                        result.extend(
-                          call
-                            .args
-                            .iter()
-                            .skip(1)
-                            .map(|arg| evaluate_cached(&arg.expr, state, fns))
-                            .collect::<Vec<Option<Box<EvaluateResultValue>>>>(),
-                        );
-
-                        context = Some(Box::new(vec![Some(EvaluateResultValue::Vec(
-                          result
-                            .into_iter()
-                            .map(|arg| arg.map(|boxed_arg| *boxed_arg))
-                            .collect(),
-                        ))]));
-                      }
-                      _ => {
-                        panic!("{} - {}:{}", BUILT_IN_FUNCTION, callee_name, method_name)
-                      }
-                    }
-                  }
-                  "Object" => {
-                    let args = &call.args;
-
-                    let Some(arg) = args.first() else {
-                      panic!("Object.{} requires an argument", method_name)
-                    };
-
-                    if arg.spread.is_some() {
-                      unimplemented!("Spread")
-                    }
-
-                    let cached_arg = evaluate_cached(&arg.expr, state, fns);
-
-                    match method_name.as_ref() {
-                      "fromEntries" => {
-                        func = Some(Box::new(FunctionConfig {
-                          fn_ptr: FunctionType::Callback(Box::new(CallbackType::Object(
-                            ObjectJS::FromEntries,
-                          ))),
-                          takes_path: false,
-                        }));
-
-                        let mut entries_result = IndexMap::new();
-
-                        match cached_arg
-                          .expect("Object.entries requires an argument")
-                          .as_ref()
-                        {
-                          EvaluateResultValue::Expr(expr) => {
-                            let array = expr
-                              .as_array()
-                              .cloned()
-                              .expect("Object.entries requires an object");
-
-                            let entries = array
-                              .elems
-                              .into_iter()
-                              .flatten()
-                              .collect::<Vec<ExprOrSpread>>();
-
-                            for entry in entries {
-                              assert!(entry.spread.is_none(), "Spread");
-
-                              let array = entry.expr.as_array().expect("Entry must be an array");
-
-                              let elems =
-                                array.elems.iter().flatten().collect::<Vec<&ExprOrSpread>>();
-
-                              let key = elems
-                                .first()
-                                .and_then(|e| e.expr.as_lit())
-                                .expect("Key must be a literal");
-
-                              let value = elems
-                                .get(1)
-                                .and_then(|e| e.expr.as_lit())
-                                .expect("Value must be a literal");
-
-                              entries_result.insert(Box::new(key.clone()), Box::new(value.clone()));
-                            }
-                          }
-                          EvaluateResultValue::Vec(vec) => {
-                            for entry in vec.clone() {
-                              let entry = entry
-                                .and_then(|entry| entry.as_vec().cloned())
-                                .expect("Entry must be some");
-
-                              let key = entry
-                                .first()
-                                .and_then(|item| item.clone())
-                                .and_then(|item| item.as_expr().cloned())
-                                .and_then(|expr| expr.as_lit().cloned())
-                                .expect("Key must be a literal");
-
-                              let value = entry
-                                .get(1)
-                                .and_then(|item| item.clone())
-                                .and_then(|item| item.as_expr().cloned())
-                                .and_then(|expr| expr.as_lit().cloned())
-                                .expect("Value must be a literal");
-
-                              entries_result.insert(
-                                Box::new(key.clone().clone()),
-                                Box::new(value.clone().clone()),
-                              );
-                            }
-                          }
-                          _ => {
-                            panic!("Object.entries requires an object")
-                          }
-                        };
-
-                        context = Some(Box::new(vec![Some(EvaluateResultValue::Entries(
-                          entries_result,
-                        ))]));
-                      }
-                      "keys" => {
-                        func = Some(Box::new(FunctionConfig {
-                          fn_ptr: FunctionType::Callback(Box::new(CallbackType::Object(
-                            ObjectJS::Keys,
-                          ))),
-                          takes_path: false,
-                        }));
-
-                        let object = cached_arg
-                          .and_then(|arg| arg.as_expr().cloned())
-                          .and_then(|expr| expr.as_object().cloned())
-                          .expect("Object.entries requires an object");
-
-                        let mut keys = vec![];
-
-                        for prop in &object.props {
-                          let expr = prop.as_prop().cloned().expect("Spread");
-
-                          let key_values = expr
-                            .as_key_value()
-                            .expect("Object.entries requires an object");
-
-                          let key = get_key_str(key_values);
-
-                          keys.push(Some(ExprOrSpread {
-                            spread: None,
-                            expr: Box::new(string_to_expression(key.as_str())),
-                          }));
-                        }
-
-                        context = Some(Box::new(vec![Some(EvaluateResultValue::Expr(Box::new(
-                          Expr::Array(ArrayLit {
-                            span: DUMMY_SP,
-                            elems: keys,
-                          }),
-                        )))]));
-                      }
-                      "values" => {
-                        func = Some(Box::new(FunctionConfig {
-                          fn_ptr: FunctionType::Callback(Box::new(CallbackType::Object(
-                            ObjectJS::Values,
-                          ))),
-                          takes_path: false,
-                        }));
-
-                        let object = cached_arg
-                          .and_then(|arg| arg.as_expr().cloned())
-                          .and_then(|expr| expr.as_object().cloned())
-                          .expect("Object.entries requires an object");
-
-                        let mut values = vec![];
-
-                        for prop in &object.props {
-                          let expr = prop.as_prop().cloned().expect("Spread");
-
-                          let key_values = expr
-                            .as_key_value()
-                            .expect("Object.entries requires an object");
-
-                          let value = key_values
-                            .value
-                            .as_lit()
-                            .expect("Object value should be a literal");
-
-                          values.push(Some(ExprOrSpread {
-                            spread: None,
-                            expr: Box::new(Expr::from(value.clone())),
-                          }));
-                        }
-
-                        context = Some(Box::new(vec![Some(EvaluateResultValue::Expr(Box::new(
-                          Expr::Array(ArrayLit {
-                            span: DUMMY_SP,
-                            elems: values,
-                          }),
-                        )))]));
-                      }
-                      "entries" => {
-                        func = Some(Box::new(FunctionConfig {
-                          fn_ptr: FunctionType::Callback(Box::new(CallbackType::Object(
-                            ObjectJS::Entries,
-                          ))),
-                          takes_path: false,
-                        }));
-
-                        let object = cached_arg
-                          .and_then(|arg| arg.as_expr().cloned())
-                          .and_then(|expr| expr.as_object().cloned())
-                          .expect("Object.entries requires an object");
-
-                        let mut entries: IndexMap<Box<Lit>, Box<Lit>> = IndexMap::new();
-
-                        for prop in &object.props {
-                          let expr = prop.as_prop().map(|prop| *prop.clone()).expect("Spread");
-
-                          let key_values = expr
-                            .as_key_value()
-                            .expect("Object.entries requires an object");
-
-                          let value = key_values
-                            .value
-                            .as_lit()
-                            .expect("Object value should be a literal");
-
-                          let key = get_key_str(key_values);
-
-                          entries.insert(
-                            Box::new(lit_str_factory(key.as_str())),
-                            Box::new(value.clone()),
-                          );
-                        }
-
-                        context = Some(Box::new(vec![Some(EvaluateResultValue::Entries(entries))]));
-                      }
-                      _ => {
-                        panic!("{} - {}:{}", BUILT_IN_FUNCTION, callee_name, method_name)
-                      }
-                    }
-                  }
-                  _ => panic!("{} - {}", BUILT_IN_FUNCTION, callee_name),
-                }
+                return evaluate_static_builtin(call, &callee_name, &method_name, state, fns);
               } else {
                 let prop_ident = property.as_ident().unwrap();
 
@@ -1061,13 +826,13 @@ fn _evaluate(
 
             if property.is_ident() {
               if let Lit::Bool(_) = obj_lit {
-                unimplemented!("{}", BUILT_IN_FUNCTION)
+                span_panic(call.span, BUILT_IN_FUNCTION)
               }
             }
           }
 
           if func.is_none() {
-            let parsed_obj = evaluate(object, &mut state.traversal_state, &state.functions);
+            let parsed_obj = evaluate(object, state.traversal_state, state.functions);
 
             if parsed_obj.confident {
               if property.is_ident() {
@@ -1092,7 +857,15 @@ fn _evaluate(
                         "filter" => CallbackType::Array(ArrayJS::Filter),
                         "join" => CallbackType::Array(ArrayJS::Join),
                         "entries" => CallbackType::Object(ObjectJS::Entries),
-                        _ => unimplemented!("Array method '{}' implemented yet", prop_name),
+                        _ => {
+                          return evaluate_builtin_method(
+                            call,
+                            value.as_ref(),
+                            &prop_name,
+                            state,
+                            fns,
+                          )
+                        }
                       })),
                       takes_path: false,
                     }));
@@ -1106,7 +879,15 @@ fn _evaluate(
                           "map" => CallbackType::Array(ArrayJS::Map),
                           "filter" => CallbackType::Array(ArrayJS::Filter),
                           "entries" => CallbackType::Object(ObjectJS::Entries),
-                          _ => unimplemented!("Method '{}' implemented yet", prop_name),
+                          _ => {
+                            return evaluate_builtin_method(
+                              call,
+                              value.as_ref(),
+                              &prop_name,
+                              state,
+                              fns,
+                            )
+                          }
                         })),
                         takes_path: false,
                       }));
@@ -1127,7 +908,15 @@ fn _evaluate(
                         fn_ptr: FunctionType::Callback(Box::new(match prop_name.as_str() {
                           "concat" => CallbackType::String(StringJS::Concat),
                           "charCodeAt" => CallbackType::String(StringJS::CharCodeAt),
-                          _ => unimplemented!("Method '{}' implemented yet", prop_name),
+                          _ => {
+                            return evaluate_builtin_method(
+                              call,
+                              value.as_ref(),
+                              &prop_name,
+                              state,
+                              fns,
+                            )
+                          }
                         })),
                         takes_path: false,
                       }));
@@ -1136,7 +925,9 @@ fn _evaluate(
                         expr.clone(),
                       ))]));
                     }
-                    _ => unimplemented!("Expression evaluation not implemented"),
+                    _ => {
+                      return evaluate_builtin_method(call, value.as_ref(), &prop_name, state, fns)
+                    }
                   },
                   EvaluateResultValue::FunctionConfig(fc) => match fc.fn_ptr {
                     FunctionType::StylexFnsFactory(sxfns) => {
@@ -1188,7 +979,7 @@ fn _evaluate(
               return Some(Box::new(EvaluateResultValue::Expr(Box::new(func_result))));
             }
             FunctionType::StylexExprFn(func) => {
-              let func_result = (func)(args.first().unwrap().clone(), &mut state.traversal_state);
+              let func_result = (func)(args.first().unwrap().clone(), state.traversal_state);
 
               return Some(Box::new(EvaluateResultValue::Expr(Box::new(func_result))));
             }
@@ -1234,7 +1025,7 @@ fn _evaluate(
             FunctionType::StylexExprFn(func) => {
               let func_result = (func)(
                 args.first().and_then(|arg| arg.as_expr().cloned()).unwrap(),
-                &mut state.traversal_state,
+                state.traversal_state,
               );
 
               return Some(Box::new(EvaluateResultValue::Expr(Box::new(func_result))));
@@ -1288,18 +1079,19 @@ fn _evaluate(
 
               match func.as_ref() {
                 CallbackType::Array(ArrayJS::Map) => {
-                  return evaluate_map(&args, &context);
+                  return match evaluate_map(&args, &context, state.traversal_state) {
+                    Ok(result) => Some(result),
+                    Err(error) => local_function_error(call, error, state),
+                  };
                 }
                 CallbackType::Array(ArrayJS::Filter) => {
-                  return evaluate_filter(&args, &context);
+                  return match evaluate_filter(&args, &context, state.traversal_state) {
+                    Ok(result) => Some(result),
+                    Err(error) => local_function_error(call, error, state),
+                  };
                 }
                 CallbackType::Array(ArrayJS::Join) => {
-                  return evaluate_join(
-                    &args,
-                    &context,
-                    &mut state.traversal_state,
-                    &state.functions,
-                  );
+                  return evaluate_join(&args, &context, state.traversal_state, state.functions);
                 }
                 CallbackType::Object(ObjectJS::Entries) => {
                   let Some(Some(eval_result)) = context.first() else {
@@ -1333,110 +1125,6 @@ fn _evaluate(
                     array_expression_factory(entry_elems),
                   ))));
                 }
-                CallbackType::Object(ObjectJS::Keys) => {
-                  let Some(Some(EvaluateResultValue::Expr(keys))) = context.first() else {
-                    panic!("Object.keys requires an argument")
-                  };
-
-                  return Some(Box::new(EvaluateResultValue::Expr(keys.clone())));
-                }
-                CallbackType::Object(ObjectJS::Values) => {
-                  let Some(Some(EvaluateResultValue::Expr(values))) = context.first() else {
-                    panic!("Object.keys requires an argument")
-                  };
-
-                  return Some(Box::new(EvaluateResultValue::Expr(values.clone())));
-                }
-                CallbackType::Object(ObjectJS::FromEntries) => {
-                  let Some(Some(EvaluateResultValue::Entries(entries))) = context.first() else {
-                    panic!("Object.fromEntries requires an argument")
-                  };
-
-                  let mut entry_elems = vec![];
-
-                  for (key, value) in entries {
-                    let ident = if let Lit::Str(lit_str) = key.as_ref() {
-                      ident_factory(lit_str.value.as_str())
-                    } else {
-                      panic!("Expected a string literal")
-                    };
-
-                    let prop = PropOrSpread::Prop(Box::new(Prop::from(KeyValueProp {
-                      key: PropName::Ident(ident),
-                      value: Box::new(Expr::from(*value.clone())),
-                    })));
-
-                    entry_elems.push(prop);
-                  }
-
-                  return Some(Box::new(EvaluateResultValue::Expr(Box::new(
-                    object_expression_factory(entry_elems),
-                  ))));
-                }
-                CallbackType::Math(MathJS::Pow) => {
-                  let Some(Some(EvaluateResultValue::Vec(args))) = context.first() else {
-                    panic!("Math.pow requires an argument")
-                  };
-
-                  let num_args = args
-                    .iter()
-                    .flatten()
-                    .map(|arg| {
-                      arg
-                        .as_expr()
-                        .map(|expr| expr_to_num(expr, &mut state.traversal_state, fns))
-                        .expect("All arguments must be a number")
-                    })
-                    .collect::<Vec<f64>>();
-
-                  let result = num_args.first().unwrap().powf(*num_args.get(1).unwrap());
-
-                  // let trancated_num = trancate_f64(result);
-
-                  return Some(Box::new(EvaluateResultValue::Expr(Box::new(
-                    number_to_expression(result),
-                  ))));
-                }
-                CallbackType::Math(MathJS::Round | MathJS::Floor | MathJS::Ceil) => {
-                  let Some(Some(EvaluateResultValue::Expr(expr))) = context.first() else {
-                    panic!("Math.(round | ceil | floor) requires an argument")
-                  };
-
-                  let num = expr_to_num(expr.as_ref(), &mut state.traversal_state, fns);
-
-                  let result = match func.as_ref() {
-                    CallbackType::Math(MathJS::Round) => num.round(),
-                    CallbackType::Math(MathJS::Ceil) => num.ceil(),
-                    CallbackType::Math(MathJS::Floor) => num.floor(),
-                    _ => unreachable!("Invalid function type"),
-                  };
-
-                  return Some(Box::new(EvaluateResultValue::Expr(Box::new(
-                    number_to_expression(result),
-                  ))));
-                }
-                CallbackType::Math(MathJS::Min | MathJS::Max) => {
-                  let Some(Some(EvaluateResultValue::Vec(args))) = context.first() else {
-                    panic!("Math.pow requires an argument")
-                  };
-
-                  let num_args = args_to_numbers(args, state, fns);
-
-                  let result = match func.as_ref() {
-                    CallbackType::Math(MathJS::Min) => {
-                      num_args.iter().cloned().min_by(sort_numbers_factory())
-                    }
-                    CallbackType::Math(MathJS::Max) => {
-                      num_args.iter().cloned().max_by(sort_numbers_factory())
-                    }
-                    _ => unreachable!("Invalid function type"),
-                  }
-                  .unwrap();
-
-                  return Some(Box::new(EvaluateResultValue::Expr(Box::new(
-                    number_to_expression(result),
-                  ))));
-                }
                 CallbackType::String(StringJS::Concat) => {
                   let Some(Some(EvaluateResultValue::Expr(base_str))) = context.first() else {
                     panic!("String concat requires an argument")
@@ -1447,13 +1135,13 @@ fn _evaluate(
                     .map(|arg| {
                       arg
                         .as_expr()
-                        .map(|expr| expr_to_str(expr, &mut state.traversal_state, fns))
+                        .map(|expr| expr_to_str(expr, state.traversal_state, fns))
                         .expect("All arguments must be a string")
                     })
                     .collect::<Vec<String>>()
                     .join("");
 
-                  let base_str = expr_to_str(base_str, &mut state.traversal_state, fns);
+                  let base_str = expr_to_str(base_str, state.traversal_state, fns);
 
                   return Some(Box::new(EvaluateResultValue::Expr(Box::new(
                     string_to_expression(format!("{}{}", base_str, str_args).as_str()),
@@ -1464,14 +1152,14 @@ fn _evaluate(
                     panic!("String concat requires an argument")
                   };
 
-                  let base_str = expr_to_str(base_str, &mut state.traversal_state, fns);
+                  let base_str = expr_to_str(base_str, state.traversal_state, fns);
 
                   let num_args = args
                     .iter()
                     .map(|arg| {
                       arg
                         .as_expr()
-                        .map(|expr| expr_to_num(expr, &mut state.traversal_state, fns))
+                        .map(|expr| expr_to_num(expr, state.traversal_state, fns))
                         .expect("First argument must be a number")
                     })
                     .collect::<Vec<f64>>();
@@ -1506,8 +1194,8 @@ fn _evaluate(
 
     let binding = get_var_decl_by_ident(
       ident,
-      &mut state.traversal_state,
-      &state.functions,
+      state.traversal_state,
+      state.functions,
       VarDeclAction::Reduce,
     );
 
@@ -1533,49 +1221,45 @@ fn _evaluate(
           )))));
         }
 
-        let binding =
-          get_import_by_ident(ident, &mut state.traversal_state).and_then(|import_decl| {
-            if import_decl
+        let binding = get_import_by_ident(ident, state.traversal_state).and_then(|import_decl| {
+          let imported_name =
+            import_decl
               .specifiers
               .iter()
-              .any(|import| import.is_named())
-            {
-              Some(import_decl)
-            } else {
-              None
-            }
-          });
-
-        if let Some(import_path) = binding {
-          let import_specifier = import_path
-            .specifiers
-            .iter()
-            .find_map(|import| {
-              if let Some(name_import) = import.as_named() {
-                if ident.sym == name_import.local.sym {
-                  return Some(name_import);
+              .find_map(|specifier| match specifier {
+                ImportSpecifier::Named(named) if named.local.sym == ident.sym => {
+                  Some(match &named.imported {
+                    Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                    Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                    None => named.local.sym.to_string(),
+                  })
                 }
-              }
-              None
-            })
-            .expect("Import specifier not found");
-          let imported = import_specifier
-            .imported
-            .clone()
-            .unwrap_or(ModuleExportName::Ident(import_specifier.local.clone()));
+                ImportSpecifier::Default(default) if default.local.sym == ident.sym => {
+                  Some("default".to_string())
+                }
+                _ => None,
+              })?;
 
+          Some((import_decl, imported_name))
+        });
+
+        if let Some((import_path, imported_name)) = binding {
           let abs_path = &state
             .traversal_state
-            .import_path_resolver(&import_path.src.value);
-
-          let imported_name = match imported {
-            ModuleExportName::Ident(ident) => ident.sym.to_string(),
-            ModuleExportName::Str(str) => str.value.to_string(),
-          };
+            .import_path_resolver(&import_path.src.value, import_path.src.span);
 
           let return_value = match abs_path {
-            ImportPathResolution::Tuple(ImportPathResolutionType::ThemeNameRef, value) => {
-              evaluate_theme_ref(value, imported_name, &state.traversal_state)
+            ImportPathResolution::Tuple(ImportPathResolutionType::ThemeNameRef, value)
+              if imported_name != "default" =>
+            {
+              evaluate_theme_ref(value, imported_name, ident.span, state.traversal_state)
+            }
+            ImportPathResolution::Tuple(ImportPathResolutionType::FilePath, value) => {
+              return match get_module_constant(Path::new(value), &imported_name) {
+                Ok(Some(constant)) => evaluate_cached(&constant, state, fns),
+                Ok(None) => deopt(path, state),
+                Err(error) => span_panic(ident.span, &error),
+              };
             }
             _ => {
               return deopt(path, state);
@@ -1610,26 +1294,6 @@ fn _evaluate(
   result
 }
 
-fn args_to_numbers(
-  args: &[Option<EvaluateResultValue>],
-  state: &mut EvaluationState,
-  fns: &FunctionMap,
-) -> Vec<f64> {
-  args
-    .iter()
-    .flat_map(|arg| match arg {
-      Some(arg) => match arg {
-        EvaluateResultValue::Expr(expr) => {
-          vec![expr_to_num(expr, &mut state.traversal_state, fns)]
-        }
-        EvaluateResultValue::Vec(vec) => args_to_numbers(vec, state, fns),
-        _ => unreachable!("Math.min/max requires a number"),
-      },
-      None => vec![],
-    })
-    .collect::<Vec<f64>>()
-}
-
 fn get_binding(callee: &Expr, state: &mut StateManager) -> Option<VarDeclarator> {
   match callee {
     Expr::Ident(ident) => get_var_decl_from(state, ident).cloned(),
@@ -1661,6 +1325,191 @@ fn is_invalid_method(prop: &MemberProp) -> bool {
   }
 }
 
+/// Evaluates `+` and equality operators with a string operand, which `binary_expr_to_num`
+/// would treat as numbers.
+fn evaluate_string_binary_expr(
+  bin: &BinExpr,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<Option<Box<EvaluateResultValue>>> {
+  if !matches!(
+    bin.op,
+    BinaryOp::Add | BinaryOp::EqEq | BinaryOp::EqEqEq | BinaryOp::NotEq | BinaryOp::NotEqEq
+  ) {
+    return None;
+  }
+
+  let left = evaluate_cached(&bin.left, state, fns);
+  let right = evaluate_cached(&bin.right, state, fns);
+
+  if !state.confident {
+    return Some(None);
+  }
+
+  let left = JsValue::from_result(left.as_deref()?)?;
+  let right = JsValue::from_result(right.as_deref()?)?;
+
+  builtins::string_binary_op(bin.op, &left, &right).map(|result| {
+    Some(Box::new(EvaluateResultValue::Expr(Box::new(
+      result.into_expr(),
+    ))))
+  })
+}
+
+/// Evaluates a call of a function declared in the file like `rem(16)`.
+fn evaluate_local_function_call(
+  call: &CallExpr,
+  function: &LocalFunction,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<Box<EvaluateResultValue>> {
+  let mut args = vec![];
+
+  for arg in &call.args {
+    let value = evaluate_cached(&arg.expr, state, fns);
+
+    if !state.confident {
+      return None;
+    }
+
+    match (arg.spread, value.as_deref().and_then(result_to_expr)) {
+      (None, Some(value)) => args.push(value),
+      _ => return deopt(&Expr::Call(call.clone()), state),
+    }
+  }
+
+  // Module functions only see module variables, not the locals of the calling function.
+  let scope = state
+    .functions
+    .identifiers
+    .iter()
+    .filter(|(id, _)| !state.traversal_state.function_scope.contains(id))
+    .map(|(id, function)| (id.clone(), function.clone()))
+    .collect();
+
+  match function.call(args, state.traversal_state, &scope) {
+    Ok(result) => Some(Box::new(EvaluateResultValue::Expr(Box::new(result)))),
+    Err(error) => local_function_error(call, error, state),
+  }
+}
+
+/// Deopts on the call when a local function or callback isn't static or exceeds the evaluation
+/// limit, which `evaluate` reports at the call once its spans are found again.
+fn local_function_error(
+  call: &CallExpr,
+  error: LocalFunctionError,
+  state: &mut EvaluationState,
+) -> Option<Box<EvaluateResultValue>> {
+  if error == LocalFunctionError::LimitExceeded && state.confident {
+    state.limit_exceeded = true;
+  }
+
+  deopt(&Expr::Call(call.clone()), state)
+}
+
+/// `Object.assign({}, ...)` is evaluated, since it only mutates the new object literal.
+fn is_pure_object_assign(object: &Expr, property: &MemberProp, call: &CallExpr) -> bool {
+  get_callee_name(object) == "Object"
+    && get_method_name(property) == "assign"
+    && call
+      .args
+      .first()
+      .is_some_and(|arg| arg.spread.is_none() && arg.expr.is_object())
+}
+
+/// Evaluates a call of a function of a global like `Math.max(...)`.
+fn evaluate_static_builtin(
+  call: &CallExpr,
+  callee_name: &str,
+  method_name: &str,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<Box<EvaluateResultValue>> {
+  if !builtins::is_static_builtin(callee_name, method_name) {
+    span_panic(
+      call.span,
+      &format!("{} - {}:{}", BUILT_IN_FUNCTION, callee_name, method_name),
+    )
+  }
+
+  let mut args = vec![];
+
+  for arg in &call.args {
+    let value = evaluate_cached(&arg.expr, state, fns);
+
+    if !state.confident {
+      return None;
+    }
+
+    let Some(value) = value.and_then(|value| JsValue::from_result(&value)) else {
+      return deopt(&Expr::Call(call.clone()), state);
+    };
+
+    match (arg.spread, value) {
+      (Some(_), JsValue::Array(items)) => args.extend(items),
+      (Some(_), _) => return deopt(&Expr::Call(call.clone()), state),
+      (None, value) => args.push(value),
+    }
+  }
+
+  builtin_result_to_value(
+    call,
+    builtins::call_static(callee_name, method_name, args),
+    state,
+  )
+}
+
+/// Evaluates a method call on an evaluated array, string or number like `"a-b".split("-")`.
+fn evaluate_builtin_method(
+  call: &CallExpr,
+  receiver: &EvaluateResultValue,
+  method_name: &str,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Option<Box<EvaluateResultValue>> {
+  let Some(receiver) = JsValue::from_result(receiver) else {
+    return deopt(&Expr::Call(call.clone()), state);
+  };
+
+  let mut args = vec![];
+
+  for arg in &call.args {
+    let value = evaluate_cached(&arg.expr, state, fns);
+
+    if !state.confident {
+      return None;
+    }
+
+    match (arg.spread, value) {
+      (None, Some(value)) => args.push(value),
+      _ => return deopt(&Expr::Call(call.clone()), state),
+    }
+  }
+
+  let result = builtins::call_method(receiver, method_name, &args, state.traversal_state);
+
+  builtin_result_to_value(call, result, state)
+}
+
+fn builtin_result_to_value(
+  call: &CallExpr,
+  result: BuiltinResult,
+  state: &mut EvaluationState,
+) -> Option<Box<EvaluateResultValue>> {
+  match result {
+    BuiltinResult::Value(value) => Some(Box::new(EvaluateResultValue::Expr(Box::new(
+      value.into_expr(),
+    )))),
+    BuiltinResult::Deopt => deopt(&Expr::Call(call.clone()), state),
+    BuiltinResult::LimitExceeded => {
+      local_function_error(call, LocalFunctionError::LimitExceeded, state)
+    }
+    BuiltinResult::Unsupported(name) => {
+      span_panic(call.span, &format!("{} - {}", BUILT_IN_FUNCTION, name))
+    }
+  }
+}
+
 fn get_method_name(prop: &MemberProp) -> String {
   match prop {
     MemberProp::Ident(ident_prop) => ident_prop.sym.to_string(),
@@ -1698,26 +1547,26 @@ pub(crate) fn evaluate_quasis(
       return None;
     };
 
-    str += (if raw {
-      elem.raw.to_string()
+    if raw {
+      str += &elem.raw;
     } else {
-      elem
-        .cooked
-        .clone()
-        .expect("Cooked should be some")
-        .to_string()
-    })
-    .as_str();
+      // Tagged templates may contain invalid escape sequences, which have no cooked value
+      let Some(cooked) = &elem.cooked else {
+        return deopt(tpl_expr, state);
+      };
+
+      str += cooked;
+    }
 
     let expr = exprs.get(i);
 
     if let Some(expr) = expr {
       let evaluated_expr = evaluate_cached(expr, state, fns);
 
-      if let Some(expr) = evaluated_expr {
-        let expr = expr.as_expr().expect("Expression not found");
-
-        let lit = expr.as_lit().expect("Literal not found");
+      if let Some(evaluated_expr) = evaluated_expr {
+        let Some(lit) = evaluated_expr.as_expr().and_then(|expr| expr.as_lit()) else {
+          return deopt(expr, state);
+        };
 
         let lit_str = get_string_val_from_lit(lit);
 
@@ -1737,6 +1586,103 @@ pub(crate) fn evaluate_quasis(
   ))))
 }
 
+/// Whether `tag` is the `css` export of StyleX, imported by name or used as `stylex.css`.
+fn is_css_tag(tag: &Expr, state: &StateManager) -> bool {
+  match tag {
+    Expr::Ident(ident) => state.stylex_css_import.contains(&ident.to_id()),
+    Expr::Member(member) => {
+      member
+        .prop
+        .as_ident()
+        .is_some_and(|prop| prop.sym == *"css")
+        && member.obj.as_ident().is_some_and(|obj| {
+          state
+            .stylex_import_stringified()
+            .contains(&obj.sym.to_string())
+        })
+    }
+    _ => false,
+  }
+}
+
+/// JS truthiness of an evaluated value, `None` when it can't be known statically.
+fn is_truthy(value: &EvaluateResultValue) -> Option<bool> {
+  match value {
+    EvaluateResultValue::Expr(expr) => match expr.as_ref() {
+      Expr::Lit(Lit::Str(str)) => Some(!str.value.is_empty()),
+      Expr::Lit(Lit::Num(num)) => Some(num.value != 0.0 && !num.value.is_nan()),
+      Expr::Lit(Lit::Bool(bool)) => Some(bool.value),
+      Expr::Lit(Lit::Null(_)) => Some(false),
+      Expr::Lit(Lit::BigInt(big_int)) => Some(big_int.value.to_string() != "0"),
+      Expr::Lit(Lit::Regex(_)) => Some(true),
+      Expr::Ident(ident) => match ident.sym.as_ref() {
+        "undefined" | "NaN" => Some(false),
+        "Infinity" => Some(true),
+        _ => None,
+      },
+      Expr::Object(_) | Expr::Array(_) | Expr::Arrow(_) | Expr::Fn(_) => Some(true),
+      _ => None,
+    },
+    _ => Some(true),
+  }
+}
+
+/// Result of the JS unary `+` on an evaluated value, `None` when it can't be known statically.
+fn to_number(value: &EvaluateResultValue) -> Option<f64> {
+  match value.as_expr()? {
+    Expr::Lit(Lit::Num(num)) => Some(num.value),
+    Expr::Lit(Lit::Bool(bool)) => Some(if bool.value { 1.0 } else { 0.0 }),
+    Expr::Lit(Lit::Null(_)) => Some(0.0),
+    Expr::Lit(Lit::Str(str)) => {
+      let value = str.value.trim();
+
+      if value.is_empty() {
+        Some(0.0)
+      } else {
+        Some(value.parse::<f64>().unwrap_or(f64::NAN))
+      }
+    }
+    Expr::Ident(ident) => match ident.sym.as_ref() {
+      "undefined" | "NaN" => Some(f64::NAN),
+      "Infinity" => Some(f64::INFINITY),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// ECMAScript `ToInt32` conversion used by the bitwise operators.
+fn to_int32(value: f64) -> i32 {
+  if !value.is_finite() {
+    return 0;
+  }
+
+  (value.trunc() as i64) as i32
+}
+
+/// Result of the JS `typeof` operator on an evaluated value.
+fn type_of(value: &EvaluateResultValue) -> Option<&'static str> {
+  match value {
+    EvaluateResultValue::Expr(expr) => match expr.as_ref() {
+      Expr::Lit(Lit::Str(_)) => Some("string"),
+      Expr::Lit(Lit::Num(_)) => Some("number"),
+      Expr::Lit(Lit::Bool(_)) => Some("boolean"),
+      Expr::Lit(Lit::BigInt(_)) => Some("bigint"),
+      Expr::Lit(Lit::Null(_) | Lit::Regex(_)) => Some("object"),
+      Expr::Ident(ident) => match ident.sym.as_ref() {
+        "undefined" => Some("undefined"),
+        "NaN" | "Infinity" => Some("number"),
+        _ => None,
+      },
+      Expr::Object(_) | Expr::Array(_) => Some("object"),
+      Expr::Arrow(_) | Expr::Fn(_) => Some("function"),
+      _ => None,
+    },
+    EvaluateResultValue::Callback(_) | EvaluateResultValue::FunctionConfig(_) => Some("function"),
+    _ => Some("object"),
+  }
+}
+
 pub(crate) fn evaluate_cached(
   path: &Expr,
   state: &mut EvaluationState,
@@ -1782,6 +1728,33 @@ pub(crate) fn evaluate_cached(
   }
 }
 
-fn evaluate_theme_ref(file_name: &str, export_name: String, state: &StateManager) -> ThemeRef {
-  ThemeRef::new(file_name.to_string(), export_name, state.clone())
+fn evaluate_theme_ref(
+  file_name: &str,
+  export_name: String,
+  span: Span,
+  state: &StateManager,
+) -> ThemeRef {
+  let theme_ref = ThemeRef::new(
+    file_name.to_string(),
+    export_name.clone(),
+    state.options.class_name_prefix.clone(),
+  );
+
+  let Some(theme_file_vars) = state
+    .get_cross_file_path(file_name)
+    .and_then(|file_path| get_theme_file_vars(&file_path, state))
+  else {
+    return theme_ref;
+  };
+
+  match theme_file_vars.get(&export_name) {
+    Some(defined_vars) => theme_ref.with_defined_vars(defined_vars.clone()),
+    None => span_panic(
+      span,
+      &format!(
+        "{} `{}` in {}",
+        UNEXPORTED_THEME_VARS, export_name, file_name
+      ),
+    ),
+  }
 }
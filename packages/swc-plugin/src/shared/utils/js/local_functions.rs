@@ -0,0 +1,287 @@
+use std::{collections::HashMap, mem::take, rc::Rc};
+
+use swc_core::ecma::ast::{ArrowExpr, BlockStmtOrExpr, Decl, Expr, Function, Id, Pat, Stmt};
+
+use crate::shared::{
+  constants::common::{MAX_FUNCTION_CALL_DEPTH, MAX_FUNCTION_STEPS},
+  enums::data_structures::evaluate_result_value::EvaluateResultValue,
+  structures::{
+    functions::{FunctionConfig, FunctionConfigType, FunctionMap, FunctionType},
+    state_manager::StateManager,
+    types::FunctionMapIdentifiers,
+  },
+  utils::{ast::convertors::ident_to_expression, js::builtins::JsValue},
+};
+
+use super::evaluate::evaluate;
+
+/// Why a call of a `LocalFunction` could not be evaluated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalFunctionError {
+  /// The body uses something other than constants, `if` and `return` or a non-static value.
+  NotStatic,
+  /// The call exceeded `MAX_FUNCTION_CALL_DEPTH` or `MAX_FUNCTION_STEPS`.
+  LimitExceeded,
+}
+
+/// A function defined in the transformed file, which the evaluator interprets when called.
+///
+/// Only pure functions are supported: the body may declare local variables and contain `if`
+/// and `return` statements, whose expressions are evaluated like any other static value.
+#[derive(Debug, Clone)]
+pub(crate) struct LocalFunction {
+  /// Names of the parameters with their default values.
+  params: Vec<(Id, Option<Box<Expr>>)>,
+  body: BlockStmtOrExpr,
+}
+
+impl LocalFunction {
+  pub(crate) fn from_arrow(arrow: &ArrowExpr) -> Option<Self> {
+    if arrow.is_async || arrow.is_generator {
+      return None;
+    }
+
+    Some(Self {
+      params: arrow.params.iter().map(param).collect::<Option<Vec<_>>>()?,
+      body: *arrow.body.clone(),
+    })
+  }
+
+  pub(crate) fn from_function(function: &Function) -> Option<Self> {
+    if function.is_async || function.is_generator {
+      return None;
+    }
+
+    Some(Self {
+      params: function
+        .params
+        .iter()
+        .map(|param_decl| param(&param_decl.pat))
+        .collect::<Option<Vec<_>>>()?,
+      body: BlockStmtOrExpr::BlockStmt(function.body.clone()?),
+    })
+  }
+
+  pub(crate) fn from_expr(expr: &Expr) -> Option<Self> {
+    match expr {
+      Expr::Arrow(arrow) => Self::from_arrow(arrow),
+      Expr::Fn(fn_expr) => Self::from_function(&fn_expr.function),
+      Expr::Paren(paren) => Self::from_expr(&paren.expr),
+      _ => None,
+    }
+  }
+
+  pub(crate) fn param_count(&self) -> usize {
+    self.params.len()
+  }
+
+  /// Calls the function with evaluated `args`, in the `scope` it was defined in.
+  pub(crate) fn call(
+    &self,
+    args: Vec<Expr>,
+    traversal_state: &mut StateManager,
+    scope: &FunctionMapIdentifiers,
+  ) -> Result<Expr, LocalFunctionError> {
+    if traversal_state.function_call_depth == 0 {
+      traversal_state.function_steps = 0;
+    }
+
+    if traversal_state.function_call_depth >= MAX_FUNCTION_CALL_DEPTH {
+      return Err(LocalFunctionError::LimitExceeded);
+    }
+
+    let scope_len = traversal_state.function_scope.len();
+
+    traversal_state.function_call_depth += 1;
+
+    let result = self.call_in_frame(args, traversal_state, scope);
+
+    traversal_state.function_call_depth -= 1;
+    traversal_state.function_scope.truncate(scope_len);
+
+    result
+  }
+
+  fn call_in_frame(
+    &self,
+    args: Vec<Expr>,
+    traversal_state: &mut StateManager,
+    scope: &FunctionMapIdentifiers,
+  ) -> Result<Expr, LocalFunctionError> {
+    step(traversal_state)?;
+
+    let mut scope = scope.clone();
+    let mut args = args.into_iter();
+
+    for (id, default) in &self.params {
+      let value = match (args.next(), default) {
+        (Some(arg), Some(default)) if is_undefined(&arg) => {
+          evaluate_in_scope(default, &scope, traversal_state)?
+        }
+        (Some(arg), _) => arg,
+        (None, Some(default)) => evaluate_in_scope(default, &scope, traversal_state)?,
+        (None, None) => ident_to_expression("undefined"),
+      };
+
+      bind(&mut scope, id, value, traversal_state);
+    }
+
+    match &self.body {
+      BlockStmtOrExpr::Expr(expr) => evaluate_in_scope(expr, &scope, traversal_state),
+      BlockStmtOrExpr::BlockStmt(block) => Ok(
+        execute(&block.stmts, &mut scope, traversal_state)?
+          .unwrap_or_else(|| ident_to_expression("undefined")),
+      ),
+    }
+  }
+}
+
+/// Converts an evaluated value to the expression bound to a variable.
+pub(crate) fn result_to_expr(value: &EvaluateResultValue) -> Option<Expr> {
+  match value {
+    EvaluateResultValue::Expr(expr) => Some(*expr.clone()),
+    value => JsValue::from_result(value).map(JsValue::into_expr),
+  }
+}
+
+fn param(pat: &Pat) -> Option<(Id, Option<Box<Expr>>)> {
+  match pat {
+    Pat::Ident(binding) => Some((binding.to_id(), None)),
+    Pat::Assign(assign) => Some((assign.left.as_ident()?.to_id(), Some(assign.right.clone()))),
+    _ => None,
+  }
+}
+
+/// Executes `stmts`, returning the value of the `return` statement that was reached, if any.
+fn execute(
+  stmts: &[Stmt],
+  scope: &mut FunctionMapIdentifiers,
+  traversal_state: &mut StateManager,
+) -> Result<Option<Expr>, LocalFunctionError> {
+  for stmt in stmts {
+    step(traversal_state)?;
+
+    match stmt {
+      Stmt::Decl(Decl::Var(var)) => {
+        for decl in &var.decls {
+          let Pat::Ident(binding) = &decl.name else {
+            return Err(LocalFunctionError::NotStatic);
+          };
+
+          let value = match &decl.init {
+            Some(init) => evaluate_in_scope(init, scope, traversal_state)?,
+            None => ident_to_expression("undefined"),
+          };
+
+          bind(scope, &binding.to_id(), value, traversal_state);
+        }
+      }
+      Stmt::Return(return_stmt) => {
+        return Ok(Some(match &return_stmt.arg {
+          Some(arg) => evaluate_in_scope(arg, scope, traversal_state)?,
+          None => ident_to_expression("undefined"),
+        }));
+      }
+      Stmt::If(if_stmt) => {
+        let test = evaluate_in_scope(&if_stmt.test, scope, traversal_state)?;
+
+        let is_truthy = JsValue::from_expr(&test)
+          .map(|test| test.is_truthy())
+          .ok_or(LocalFunctionError::NotStatic)?;
+
+        let branch = if is_truthy {
+          Some(&if_stmt.cons)
+        } else {
+          if_stmt.alt.as_ref()
+        };
+
+        if let Some(branch) = branch {
+          let result = execute(
+            std::slice::from_ref(branch.as_ref()),
+            &mut scope.clone(),
+            traversal_state,
+          )?;
+
+          if result.is_some() {
+            return Ok(result);
+          }
+        }
+      }
+      Stmt::Block(block) => {
+        let result = execute(&block.stmts, &mut scope.clone(), traversal_state)?;
+
+        if result.is_some() {
+          return Ok(result);
+        }
+      }
+      Stmt::Empty(_) => {}
+      _ => return Err(LocalFunctionError::NotStatic),
+    }
+  }
+
+  Ok(None)
+}
+
+fn step(traversal_state: &mut StateManager) -> Result<(), LocalFunctionError> {
+  traversal_state.function_steps += 1;
+
+  if traversal_state.function_steps > MAX_FUNCTION_STEPS {
+    return Err(LocalFunctionError::LimitExceeded);
+  }
+
+  Ok(())
+}
+
+fn bind(
+  scope: &mut FunctionMapIdentifiers,
+  id: &Id,
+  value: Expr,
+  traversal_state: &mut StateManager,
+) {
+  scope.insert(
+    Box::new(id.clone()),
+    Box::new(FunctionConfigType::Regular(FunctionConfig {
+      fn_ptr: FunctionType::Mapper(Rc::new(move || value.clone())),
+      takes_path: false,
+    })),
+  );
+
+  traversal_state.function_scope.push(id.clone());
+}
+
+fn evaluate_in_scope(
+  expr: &Expr,
+  scope: &FunctionMapIdentifiers,
+  traversal_state: &mut StateManager,
+) -> Result<Expr, LocalFunctionError> {
+  // Each call binds the parameters to other values, so the evaluated values
+  // and usage counts of the body must not leak into the traversal state.
+  let seen = take(&mut traversal_state.seen);
+  let var_decl_count_map = take(&mut traversal_state.var_decl_count_map);
+
+  let result = evaluate(
+    expr,
+    traversal_state,
+    &FunctionMap {
+      identifiers: scope.clone(),
+      member_expressions: HashMap::new(),
+    },
+  );
+
+  traversal_state.seen = seen;
+  traversal_state.var_decl_count_map = var_decl_count_map;
+
+  if !result.confident {
+    return Err(LocalFunctionError::NotStatic);
+  }
+
+  result
+    .value
+    .as_deref()
+    .and_then(result_to_expr)
+    .ok_or(LocalFunctionError::NotStatic)
+}
+
+fn is_undefined(expr: &Expr) -> bool {
+  matches!(expr, Expr::Ident(ident) if ident.sym == *"undefined")
+}
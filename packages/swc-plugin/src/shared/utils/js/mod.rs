@@ -1,2 +1,5 @@
+pub(crate) mod builtins;
+pub(crate) mod local_functions;
+pub(crate) mod module_constants;
 pub(crate) mod native_functions;
 pub mod evaluate;
@@ -0,0 +1,315 @@
+use std::{
+  collections::HashMap,
+  fs,
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::SystemTime,
+};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use swc_core::{
+  common::{sync::Lrc, FileName, SourceMap},
+  ecma::{
+    ast::{
+      ArrayLit, BinExpr, Decl, Expr, ExprOrSpread, KeyValueProp, Lit, Module, ModuleDecl,
+      ModuleExportName, ModuleItem, Number, ObjectLit, Prop, PropName, PropOrSpread, Stmt, Tpl,
+      UnaryExpr, UnaryOp, VarDeclKind,
+    },
+    parser::{Parser, StringInput},
+    utils::drop_span,
+  },
+};
+
+use crate::shared::{
+  constants::messages::INVALID_JSON_MODULE,
+  utils::{
+    ast::{
+      convertors::{
+        bool_to_expression, null_to_expression, number_to_expression, string_to_expression,
+      },
+      factories::{
+        array_expression_factory, object_expression_factory, prop_or_spread_expression_factory,
+      },
+    },
+    core::cross_file_parsing::get_syntax,
+  },
+};
+
+/// Static values of the exported `const` declarations of a module, by export name.
+type ModuleConstants = HashMap<String, Box<Expr>>;
+
+/// Modification time of a module together with the constants read from it,
+/// or the error of a module that must be valid, such as a JSON module.
+type CachedModule = (Option<SystemTime>, Result<Arc<ModuleConstants>, String>);
+
+static MODULES: Lazy<DashMap<PathBuf, CachedModule>> = Lazy::new(DashMap::new);
+
+/// Maximum depth of `const` references followed while inlining a value,
+/// which also stops self-referencing declarations.
+const MAX_REFERENCE_DEPTH: usize = 32;
+
+/// Returns the static value of the constant `export_name` exported by the module at `file_path`.
+///
+/// Only literals, templates, arrays, objects and arithmetic built from them (and from other
+/// top-level `const` declarations of the same module) are static. Anything else returns `None`.
+/// A JSON module that can't be parsed returns its error.
+pub(crate) fn get_module_constant(
+  file_path: &Path,
+  export_name: &str,
+) -> Result<Option<Box<Expr>>, String> {
+  let modified = fs::metadata(file_path)
+    .and_then(|metadata| metadata.modified())
+    .ok();
+
+  if let Some(module) = MODULES.get(file_path) {
+    if module.0 == modified {
+      return module
+        .1
+        .as_ref()
+        .map(|constants| constants.get(export_name).cloned())
+        .map_err(Clone::clone);
+    }
+  }
+
+  let constants = read_module_constants(file_path).map(Arc::new);
+
+  MODULES.insert(file_path.to_path_buf(), (modified, constants.clone()));
+
+  constants.map(|constants| constants.get(export_name).cloned())
+}
+
+fn read_module_constants(file_path: &Path) -> Result<ModuleConstants, String> {
+  let Ok(source_code) = fs::read_to_string(file_path) else {
+    return Ok(ModuleConstants::default());
+  };
+
+  if file_path
+    .extension()
+    .is_some_and(|extension| extension == "json")
+  {
+    return read_json_constants(&source_code)
+      .map_err(|error| format!("{} {}: {}", INVALID_JSON_MODULE, file_path.display(), error));
+  }
+
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Real(file_path.to_path_buf()), source_code);
+
+  // A module that can't be parsed has no static constants,
+  // its imports simply deopt like any other dynamic value.
+  let Ok(module) = Parser::new(get_syntax(file_path), StringInput::from(&*fm), None).parse_module()
+  else {
+    return Ok(ModuleConstants::default());
+  };
+
+  Ok(collect_constants(&module))
+}
+
+/// A JSON module exports its value as `default`, and the properties of a top-level object
+/// as named exports.
+fn read_json_constants(source_code: &str) -> Result<ModuleConstants, serde_json::Error> {
+  let value: Value = serde_json::from_str(source_code)?;
+
+  let mut constants = ModuleConstants::new();
+
+  if let Value::Object(object) = &value {
+    for (key, value) in object {
+      constants.insert(key.clone(), Box::new(json_to_expression(value)));
+    }
+  }
+
+  constants.insert("default".to_string(), Box::new(json_to_expression(&value)));
+
+  Ok(constants)
+}
+
+fn json_to_expression(value: &Value) -> Expr {
+  match value {
+    Value::Null => null_to_expression(),
+    Value::Bool(value) => bool_to_expression(*value),
+    Value::Number(value) => number_to_expression(value.as_f64().unwrap_or(f64::NAN)),
+    Value::String(value) => string_to_expression(value),
+    Value::Array(items) => array_expression_factory(
+      items
+        .iter()
+        .map(|item| {
+          Some(ExprOrSpread {
+            spread: None,
+            expr: Box::new(json_to_expression(item)),
+          })
+        })
+        .collect(),
+    ),
+    Value::Object(object) => object_expression_factory(
+      object
+        .iter()
+        .map(|(key, value)| prop_or_spread_expression_factory(key, json_to_expression(value)))
+        .collect(),
+    ),
+  }
+}
+
+fn collect_constants(module: &Module) -> ModuleConstants {
+  let mut declarations: HashMap<String, &Expr> = HashMap::new();
+  let mut exports: Vec<(String, String)> = vec![];
+
+  for item in &module.body {
+    let (var_decl, is_exported) = match item {
+      ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => (var_decl, false),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => match &export_decl.decl {
+        Decl::Var(var_decl) => (var_decl, true),
+        _ => continue,
+      },
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export))
+        if named_export.src.is_none() =>
+      {
+        for specifier in &named_export.specifiers {
+          let Some(named) = specifier.as_named() else {
+            continue;
+          };
+
+          let local = module_export_name(&named.orig);
+          let exported = named
+            .exported
+            .as_ref()
+            .map(module_export_name)
+            .unwrap_or_else(|| local.clone());
+
+          exports.push((exported, local));
+        }
+
+        continue;
+      }
+      _ => continue,
+    };
+
+    if var_decl.kind != VarDeclKind::Const {
+      continue;
+    }
+
+    for decl in &var_decl.decls {
+      let (Some(ident), Some(init)) = (decl.name.as_ident(), &decl.init) else {
+        continue;
+      };
+
+      let name = ident.sym.to_string();
+
+      declarations.insert(name.clone(), init);
+
+      if is_exported {
+        exports.push((name.clone(), name));
+      }
+    }
+  }
+
+  exports
+    .into_iter()
+    .filter_map(|(exported, local)| {
+      let value = inline_static_value(declarations.get(&local)?, &declarations, 0)?;
+
+      Some((exported, Box::new(drop_span(value))))
+    })
+    .collect()
+}
+
+fn module_export_name(name: &ModuleExportName) -> String {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.to_string(),
+    ModuleExportName::Str(str) => str.value.to_string(),
+  }
+}
+
+/// Copies `expr` with every reference to a top-level `const` replaced by its value,
+/// or returns `None` when it isn't fully static.
+fn inline_static_value(
+  expr: &Expr,
+  declarations: &HashMap<String, &Expr>,
+  depth: usize,
+) -> Option<Expr> {
+  if depth > MAX_REFERENCE_DEPTH {
+    return None;
+  }
+
+  let inline = |expr: &Expr| inline_static_value(expr, declarations, depth);
+
+  match expr {
+    Expr::Lit(Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_)) => Some(expr.clone()),
+    Expr::Paren(paren) => inline(&paren.expr),
+    Expr::TsAs(ts_as) => inline(&ts_as.expr),
+    Expr::TsConstAssertion(ts_const) => inline(&ts_const.expr),
+    Expr::TsSatisfies(ts_satisfies) => inline(&ts_satisfies.expr),
+    Expr::Ident(ident) => inline_static_value(
+      declarations.get(ident.sym.as_str())?,
+      declarations,
+      depth + 1,
+    ),
+    Expr::Unary(UnaryExpr {
+      op: op @ (UnaryOp::Minus | UnaryOp::Plus),
+      arg,
+      ..
+    }) => match inline(arg)? {
+      Expr::Lit(Lit::Num(num)) => Some(Expr::Lit(Lit::Num(Number::from(
+        if *op == UnaryOp::Minus {
+          -num.value
+        } else {
+          num.value
+        },
+      )))),
+      _ => None,
+    },
+    Expr::Bin(bin) => Some(Expr::Bin(BinExpr {
+      left: Box::new(inline(&bin.left)?),
+      right: Box::new(inline(&bin.right)?),
+      ..bin.clone()
+    })),
+    Expr::Tpl(tpl) => Some(Expr::Tpl(Tpl {
+      exprs: tpl
+        .exprs
+        .iter()
+        .map(|expr| inline(expr).map(Box::new))
+        .collect::<Option<Vec<Box<Expr>>>>()?,
+      ..tpl.clone()
+    })),
+    Expr::Array(array) => Some(Expr::Array(ArrayLit {
+      elems: array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+          Some(ExprOrSpread { spread: None, expr }) => Some(Some(ExprOrSpread {
+            spread: None,
+            expr: Box::new(inline(expr)?),
+          })),
+          _ => None,
+        })
+        .collect::<Option<Vec<Option<ExprOrSpread>>>>()?,
+      ..array.clone()
+    })),
+    Expr::Object(object) => Some(Expr::Object(ObjectLit {
+      props: object
+        .props
+        .iter()
+        .map(|prop| {
+          let key_value = match prop.as_prop()?.as_ref() {
+            Prop::KeyValue(key_value) => match &key_value.key {
+              PropName::Ident(_) | PropName::Str(_) | PropName::Num(_) => KeyValueProp {
+                key: key_value.key.clone(),
+                value: Box::new(inline(&key_value.value)?),
+              },
+              _ => return None,
+            },
+            Prop::Shorthand(ident) => KeyValueProp {
+              key: PropName::Ident(ident.clone()),
+              value: Box::new(inline(&Expr::Ident(ident.clone()))?),
+            },
+            _ => return None,
+          };
+
+          Some(PropOrSpread::Prop(Box::new(Prop::KeyValue(key_value))))
+        })
+        .collect::<Option<Vec<PropOrSpread>>>()?,
+      ..object.clone()
+    })),
+    _ => None,
+  }
+}
@@ -1,12 +1,14 @@
 use crate::shared::{
   enums::data_structures::evaluate_result_value::EvaluateResultValue,
-  structures::{functions::FunctionMap, state_manager::StateManager},
-  utils::ast::{
-    convertors::{expr_to_str, lit_to_num, string_to_expression},
-    factories::array_expression_factory,
+  structures::{functions::FunctionMap, state_manager::StateManager, types::EvaluationCallback},
+  utils::{
+    ast::{
+      convertors::{expr_to_str, lit_to_num, string_to_expression},
+      factories::array_expression_factory,
+    },
+    js::local_functions::LocalFunctionError,
   },
 };
-use std::rc::Rc;
 use swc_core::{
   common::DUMMY_SP,
   ecma::ast::{ArrayLit, Expr, ExprOrSpread},
@@ -15,10 +17,12 @@ use swc_core::{
 pub(crate) fn evaluate_map(
   funcs: &[Box<EvaluateResultValue>],
   args: &[Option<EvaluateResultValue>],
-) -> Option<Box<EvaluateResultValue>> {
-  let cb = funcs.first()?;
-
-  let cb = cb.as_callback()?;
+  state: &mut StateManager,
+) -> Result<Box<EvaluateResultValue>, LocalFunctionError> {
+  let cb = funcs
+    .first()
+    .and_then(|cb| cb.as_callback())
+    .ok_or(LocalFunctionError::NotStatic)?;
 
   let func_result = args
     .iter()
@@ -26,16 +30,18 @@ pub(crate) fn evaluate_map(
       let result = arg.as_ref()?;
 
       match result {
-        EvaluateResultValue::Expr(_) => Some(evaluate_map_cb(cb, arg)),
+        EvaluateResultValue::Expr(_) => Some(evaluate_map_cb(cb, arg, state)),
         EvaluateResultValue::Vec(vec) => {
-          let func_result = vec
+          let func_result = match vec
             .iter()
             .map(|expr| {
-              let expr = evaluate_map_cb(cb, expr);
-
-              EvaluateResultValue::Expr(Box::new(expr))
+              evaluate_map_cb(cb, expr, state).map(|expr| EvaluateResultValue::Expr(Box::new(expr)))
             })
-            .collect::<Vec<EvaluateResultValue>>();
+            .collect::<Result<Vec<EvaluateResultValue>, LocalFunctionError>>()
+          {
+            Ok(func_result) => func_result,
+            Err(error) => return Some(Err(error)),
+          };
 
           let elems = func_result
             .into_iter()
@@ -47,18 +53,18 @@ pub(crate) fn evaluate_map(
             })
             .collect::<Vec<Option<ExprOrSpread>>>();
 
-          Some(array_expression_factory(elems))
+          Some(Ok(array_expression_factory(elems)))
         }
         _ => unimplemented!(),
       }
     })
-    .collect::<Vec<Expr>>();
+    .collect::<Result<Vec<Expr>, LocalFunctionError>>()?;
 
   match func_result.first() {
-    Some(Expr::Array(array)) => Some(Box::new(EvaluateResultValue::Expr(Box::new(Expr::from(
+    Some(Expr::Array(array)) => Ok(Box::new(EvaluateResultValue::Expr(Box::new(Expr::from(
       array.clone(),
     ))))),
-    _ => Some(Box::new(EvaluateResultValue::Expr(Box::new(
+    _ => Ok(Box::new(EvaluateResultValue::Expr(Box::new(
       array_expression_factory(
         func_result
           .into_iter()
@@ -106,10 +112,12 @@ pub(crate) fn evaluate_join(
 pub(crate) fn evaluate_filter(
   funcs: &[Box<EvaluateResultValue>],
   args: &[Option<EvaluateResultValue>],
-) -> Option<Box<EvaluateResultValue>> {
-  let cb = funcs.first()?;
-
-  let cb = cb.as_callback()?;
+  state: &mut StateManager,
+) -> Result<Box<EvaluateResultValue>, LocalFunctionError> {
+  let cb = funcs
+    .first()
+    .and_then(|cb| cb.as_callback())
+    .ok_or(LocalFunctionError::NotStatic)?;
 
   let func_result = args
     .iter()
@@ -117,20 +125,25 @@ pub(crate) fn evaluate_filter(
       let result = arg.as_ref()?;
 
       match result {
-        EvaluateResultValue::Expr(expr) => evaluate_filter_cb(cb, arg, expr.as_ref()),
+        EvaluateResultValue::Expr(expr) => {
+          evaluate_filter_cb(cb, arg, expr.as_ref(), state).transpose()
+        }
         EvaluateResultValue::Vec(vec) => {
-          let func_result = vec
+          let func_result = match vec
             .iter()
             .filter_map(|expr| {
-              let result = evaluate_filter_cb(
-                cb,
-                &expr.clone(),
-                &expr.as_ref()?.as_expr()?.clone(),
-              );
+              let result =
+                evaluate_filter_cb(cb, &expr.clone(), &expr.as_ref()?.as_expr()?.clone(), state);
 
-              result.map(|expr| EvaluateResultValue::Expr(Box::new(expr)))
+              result
+                .map(|expr| expr.map(|expr| EvaluateResultValue::Expr(Box::new(expr))))
+                .transpose()
             })
-            .collect::<Vec<EvaluateResultValue>>();
+            .collect::<Result<Vec<EvaluateResultValue>, LocalFunctionError>>()
+          {
+            Ok(func_result) => func_result,
+            Err(error) => return Some(Err(error)),
+          };
 
           let elems = func_result
             .into_iter()
@@ -142,21 +155,21 @@ pub(crate) fn evaluate_filter(
             })
             .collect::<Vec<Option<ExprOrSpread>>>();
 
-          Some(Expr::Array(ArrayLit {
+          Some(Ok(Expr::Array(ArrayLit {
             span: DUMMY_SP,
             elems,
-          }))
+          })))
         }
         _ => unimplemented!(),
       }
     })
-    .collect::<Vec<Expr>>();
+    .collect::<Result<Vec<Expr>, LocalFunctionError>>()?;
 
   match func_result.first() {
-    Some(Expr::Array(array)) => Some(Box::new(EvaluateResultValue::Expr(Box::new(Expr::from(
+    Some(Expr::Array(array)) => Ok(Box::new(EvaluateResultValue::Expr(Box::new(Expr::from(
       array.clone(),
     ))))),
-    _ => Some(Box::new(EvaluateResultValue::Expr(Box::new(Expr::from(
+    _ => Ok(Box::new(EvaluateResultValue::Expr(Box::new(Expr::from(
       ArrayLit {
         span: DUMMY_SP,
         elems: func_result
@@ -174,26 +187,26 @@ pub(crate) fn evaluate_filter(
 }
 
 pub(crate) fn evaluate_map_cb(
-  cb: &Rc<dyn Fn(Vec<Option<EvaluateResultValue>>) -> Expr>,
+  cb: &EvaluationCallback,
   cb_arg: &Option<EvaluateResultValue>,
-) -> Expr {
-  (cb)(vec![cb_arg.clone()])
+  state: &mut StateManager,
+) -> Result<Expr, LocalFunctionError> {
+  (cb)(vec![cb_arg.clone()], state)
 }
 
 pub(crate) fn evaluate_filter_cb(
-  cb: &Rc<dyn Fn(Vec<Option<EvaluateResultValue>>) -> Expr>,
+  cb: &EvaluationCallback,
   cb_arg: &Option<EvaluateResultValue>,
   item: &Expr,
-) -> Option<Expr> {
-  let result = evaluate_map_cb(cb, cb_arg);
+  state: &mut StateManager,
+) -> Result<Option<Expr>, LocalFunctionError> {
+  let result = evaluate_map_cb(cb, cb_arg, state)?;
 
-  let Some(lit) = result.as_lit() else {
-    panic!("Expr is not a literal");
-  };
+  let lit = result.as_lit().ok_or(LocalFunctionError::NotStatic)?;
 
   if lit_to_num(lit) == 0.0 {
-    None
+    Ok(None)
   } else {
-    Some(item.clone())
+    Ok(Some(item.clone()))
   }
 }
@@ -2,6 +2,7 @@ pub mod common;
 pub mod core;
 pub mod css;
 pub mod ast;
+pub(crate) mod diagnostics;
 pub mod js;
 pub mod object;
 pub(crate) mod validators;
@@ -1,35 +1,118 @@
 use std::collections::HashSet;
 
-use swc_core::ecma::ast::{CallExpr, Expr, Id, KeyValueProp, Lit, Pat, PropName, VarDeclarator};
+use swc_core::{
+  common::{Span, Spanned},
+  ecma::{
+    ast::{CallExpr, Expr, Id, KeyValueProp, Lit, Pat, PropName, SpreadElement, VarDeclarator},
+    visit::{Visit, VisitWith},
+  },
+};
 
 use crate::shared::{
   constants::{
     common::THEME_NAME_KEY,
     messages::{
-      DUPLICATE_CONDITIONAL, ILLEGAL_ARGUMENT_LENGTH, ILLEGAL_PROP_ARRAY_VALUE, ILLEGAL_PROP_VALUE,
-      INVALID_PSEUDO_OR_AT_RULE, NON_EXPORT_NAMED_DECLARATION, NON_OBJECT_FOR_STYLEX_CALL,
+      DUPLICATE_CONDITIONAL, ILLEGAL_ARGUMENT_LENGTH, ILLEGAL_PROP_ARRAY_SPREAD,
+      ILLEGAL_PROP_ARRAY_VALUE, ILLEGAL_PROP_VALUE, INVALID_PSEUDO_OR_AT_RULE, INVALID_SPREAD,
+      INVALID_THEME_VARIABLES, NON_EXPORT_NAMED_DECLARATION, NON_OBJECT_FOR_STYLEX_CALL,
       NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL, NON_OBJECT_KEYFRAME, NON_STATIC_KEYFRAME_VALUE,
-      NON_STATIC_VALUE, ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS, ONLY_TOP_LEVEL_INCLUDES,
-      UNBOUND_STYLEX_CALL_VALUE,
+      NON_STATIC_VALUE, NO_REST_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS, ONLY_TOP_LEVEL_INCLUDES,
+      UNBOUND_STYLEX_CALL_VALUE, UNDEFINED_THEME_VARIABLE,
     },
   },
   enums::data_structures::{
-    evaluate_result_value::EvaluateResultValue,
-    top_level_expression::{TopLevelExpression, TopLevelExpressionKind},
+    evaluate_result_value::EvaluateResultValue, top_level_expression::TopLevelExpressionKind,
   },
   regex::INCLUDED_IDENT_REGEX,
-  structures::state_manager::StateManager,
+  structures::{
+    evaluate_result::EvaluateResult, functions::FunctionMap, state_manager::StateManager,
+  },
   utils::{
     ast::{
       convertors::string_to_expression,
       factories::{ident_factory, key_value_factory},
     },
     common::{get_string_val_from_lit, get_var_decl_by_ident_or_member},
+    diagnostics::{span_assert, span_panic},
+    js::evaluate::evaluate,
   },
 };
 
 use super::common::{get_key_str, get_key_values_from_object};
 
+/// Fails when the argument `arg` of a StyleX call didn't evaluate statically,
+/// pointing at the non-static spread or expression that stopped the evaluation.
+pub(crate) fn validate_static_arg(
+  evaluated_arg: &EvaluateResult,
+  arg: &Expr,
+  state: &mut StateManager,
+  fns: &FunctionMap,
+) {
+  if evaluated_arg.confident {
+    return;
+  }
+
+  let mut spread_finder = SpreadFinder { spreads: vec![] };
+
+  arg.visit_with(&mut spread_finder);
+
+  for spread in spread_finder.spreads {
+    let evaluated_spread = evaluate(&spread, state, fns);
+
+    let is_static_object = evaluated_spread.confident
+      && evaluated_spread
+        .value
+        .as_ref()
+        .and_then(|value| value.as_expr())
+        .is_some_and(|expr| expr.is_object());
+
+    span_assert(is_static_object, spread.span(), INVALID_SPREAD);
+  }
+
+  let deopt_span = evaluated_arg.deopt_span(arg.span());
+
+  let mut key_value_finder = KeyValueFinder {
+    span: deopt_span,
+    key_value_span: None,
+  };
+
+  arg.visit_with(&mut key_value_finder);
+
+  span_panic(
+    key_value_finder.key_value_span.unwrap_or(deopt_span),
+    NON_STATIC_VALUE,
+  )
+}
+
+/// Finds the innermost property whose value contains the non-static expression at `span`.
+struct KeyValueFinder {
+  span: Span,
+  key_value_span: Option<Span>,
+}
+
+impl Visit for KeyValueFinder {
+  fn visit_key_value_prop(&mut self, key_value: &KeyValueProp) {
+    if key_value.value.span().contains(self.span) {
+      self.key_value_span = Some(key_value.span());
+    }
+
+    key_value.visit_children_with(self);
+  }
+}
+
+/// Collects the arguments of the object spreads of an expression.
+struct SpreadFinder {
+  spreads: Vec<Expr>,
+}
+
+impl Visit for SpreadFinder {
+  fn visit_spread_element(&mut self, spread: &SpreadElement) {
+    self.spreads.push(*spread.expr.clone());
+
+    spread.visit_children_with(self);
+  }
+}
+
 pub(crate) fn validate_stylex_create(call: &CallExpr, state: &mut StateManager) {
   if !is_create_call(call, state) {
     return;
@@ -37,33 +120,31 @@ pub(crate) fn validate_stylex_create(call: &CallExpr, state: &mut StateManager)
 
   let ident = ident_factory("create");
 
-  let call_expr = Expr::from(call.clone());
-
-  assert!(
+  span_assert(
     get_var_decl_by_ident_or_member(state, &ident).is_some()
-      || state
-        .top_level_expressions
-        .iter()
-        .any(|TopLevelExpression(_, call_item, _)| { call_item.eq(&call_expr) }),
-    "{}",
-    UNBOUND_STYLEX_CALL_VALUE
+      || state.get_top_level_call(call).is_some(),
+    call.span,
+    UNBOUND_STYLEX_CALL_VALUE,
   );
 
-  assert!(call.args.len() == 1, "{}", ILLEGAL_ARGUMENT_LENGTH);
+  span_assert(call.args.len() == 1, call.span, ILLEGAL_ARGUMENT_LENGTH);
 
   let first_args = &call.args[0];
 
-  assert!(
+  span_assert(
     first_args.expr.is_object(),
-    "{}",
-    NON_OBJECT_FOR_STYLEX_CALL
+    first_args.expr.span(),
+    NON_OBJECT_FOR_STYLEX_CALL,
   )
 }
 
 pub(crate) fn validate_stylex_keyframes_indent(var_decl: &VarDeclarator, state: &mut StateManager) {
   let init = match &var_decl.init {
-    Some(init) => init.clone().call().expect(NON_STATIC_KEYFRAME_VALUE),
-    None => panic!("{}", NON_STATIC_KEYFRAME_VALUE),
+    Some(init) => match init.as_call() {
+      Some(call) => call.clone(),
+      None => span_panic(init.span(), NON_STATIC_KEYFRAME_VALUE),
+    },
+    None => span_panic(var_decl.span, NON_STATIC_KEYFRAME_VALUE),
   };
 
   if !is_keyframes_call(var_decl, state) {
@@ -72,26 +153,21 @@ pub(crate) fn validate_stylex_keyframes_indent(var_decl: &VarDeclarator, state:
 
   let ident = ident_factory("keyframes");
 
-  let expr = Expr::from(init.clone());
-
-  assert!(
+  span_assert(
     get_var_decl_by_ident_or_member(state, &ident).is_some()
-      || state
-        .top_level_expressions
-        .iter()
-        .any(|TopLevelExpression(_, call_item, _)| { call_item.eq(&expr) }),
-    "{}",
-    UNBOUND_STYLEX_CALL_VALUE
+      || state.get_top_level_call(&init).is_some(),
+    init.span,
+    UNBOUND_STYLEX_CALL_VALUE,
   );
 
-  assert!(init.args.len() == 1, "{}", ILLEGAL_ARGUMENT_LENGTH);
+  span_assert(init.args.len() == 1, init.span, ILLEGAL_ARGUMENT_LENGTH);
 
   let first_args = &init.args[0];
 
-  assert!(
+  span_assert(
     first_args.expr.is_object(),
-    "{}",
-    NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL
+    first_args.expr.span(),
+    NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL,
   )
 }
 
@@ -101,12 +177,15 @@ pub(crate) fn validate_stylex_create_theme_indent(
   state: &mut StateManager,
 ) {
   let Some(var_decl) = var_decl else {
-    panic!("{}", UNBOUND_STYLEX_CALL_VALUE)
+    span_panic(call.span, UNBOUND_STYLEX_CALL_VALUE)
   };
 
   let init = match &var_decl.init {
-    Some(init) => init.clone().call().expect(NON_STATIC_KEYFRAME_VALUE),
-    None => panic!("{}", NON_STATIC_KEYFRAME_VALUE),
+    Some(init) => match init.as_call() {
+      Some(call) => call.clone(),
+      None => span_panic(init.span(), NON_STATIC_KEYFRAME_VALUE),
+    },
+    None => span_panic(var_decl.span, NON_STATIC_KEYFRAME_VALUE),
   };
 
   if !is_create_theme_call(call, state) {
@@ -115,19 +194,14 @@ pub(crate) fn validate_stylex_create_theme_indent(
 
   let ident = ident_factory("keyframes");
 
-  let expr = Expr::from(init.clone());
-
-  assert!(
+  span_assert(
     get_var_decl_by_ident_or_member(state, &ident).is_some()
-      || state
-        .top_level_expressions
-        .iter()
-        .any(|TopLevelExpression(_, call_item, _)| { call_item.eq(&expr) }),
-    "{}",
-    UNBOUND_STYLEX_CALL_VALUE
+      || state.get_top_level_call(&init).is_some(),
+    call.span,
+    UNBOUND_STYLEX_CALL_VALUE,
   );
 
-  assert!(init.args.len() == 2, "{}", ILLEGAL_ARGUMENT_LENGTH);
+  span_assert(init.args.len() == 2, call.span, ILLEGAL_ARGUMENT_LENGTH);
 }
 
 pub(crate) fn validate_stylex_define_vars(call: &CallExpr, state: &mut StateManager) {
@@ -137,26 +211,21 @@ pub(crate) fn validate_stylex_define_vars(call: &CallExpr, state: &mut StateMana
 
   let ident = ident_factory("defineVars");
 
-  let expr = Expr::from(call.clone());
-
-  assert!(
+  span_assert(
     get_var_decl_by_ident_or_member(state, &ident).is_some()
-      || state
-        .top_level_expressions
-        .iter()
-        .any(|TopLevelExpression(_, call_item, _)| { call_item.eq(&expr) }),
-    "{}",
-    UNBOUND_STYLEX_CALL_VALUE
+      || state.get_top_level_call(call).is_some(),
+    call.span,
+    UNBOUND_STYLEX_CALL_VALUE,
   );
 
-  assert!(call.args.len() == 1, "{}", ILLEGAL_ARGUMENT_LENGTH);
+  span_assert(call.args.len() == 1, call.span, ILLEGAL_ARGUMENT_LENGTH);
 
-  assert!(
+  span_assert(
     state
       .get_top_level_expr(&TopLevelExpressionKind::NamedExport, call)
       .is_some(),
-    "{}",
-    NON_EXPORT_NAMED_DECLARATION
+    call.span,
+    NON_EXPORT_NAMED_DECLARATION,
   );
 }
 
@@ -233,8 +302,11 @@ pub(crate) fn is_target_call(
 
   is_create_ident || is_create_member
 }
-pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[String]) {
+pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[String], span: Span) {
   for namespace in namespaces {
+    let namespace_span = key_value_span(namespace, span);
+    let namespace_key_span = key_span(namespace, span);
+
     let key = match &namespace.key {
       PropName::Ident(key) => format!("{}", key.sym),
       PropName::Str(key) => {
@@ -243,32 +315,32 @@ pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[Stri
           || key.value == "default"
           || namespace.value.is_lit())
         {
-          panic!("{}", INVALID_PSEUDO_OR_AT_RULE)
+          span_panic(namespace_key_span, INVALID_PSEUDO_OR_AT_RULE)
         }
         key.value.to_string()
       }
-      _ => panic!("{}", NON_STATIC_VALUE),
+      _ => span_panic(namespace_key_span, NON_STATIC_VALUE),
     };
 
     match namespace.value.as_ref() {
       Expr::Lit(lit) => {
         if let Lit::Str(_) | Lit::Null(_) | Lit::Num(_) | Lit::BigInt(_) = lit {
         } else {
-          panic!("{}", ILLEGAL_PROP_VALUE);
+          span_panic(namespace_span, ILLEGAL_PROP_VALUE);
         }
       }
       Expr::Array(array) => {
         for elem in array.elems.iter().flatten() {
-          assert!(
+          span_assert(
             elem.spread.is_none(),
-            "{}",
-            "Spread operator not implemented"
+            namespace_span,
+            ILLEGAL_PROP_ARRAY_SPREAD,
           );
 
           if let Expr::Lit(_) = elem.expr.as_ref() {
             // Do nothing
           } else {
-            panic!("{}", ILLEGAL_PROP_ARRAY_VALUE);
+            span_panic(namespace_span, ILLEGAL_PROP_ARRAY_VALUE);
           }
         }
       }
@@ -277,7 +349,7 @@ pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[Stri
 
         if key.starts_with('@') || key.starts_with(':') {
           if conditions.contains(&key) {
-            panic!("{}", DUPLICATE_CONDITIONAL);
+            span_panic(namespace_key_span, DUPLICATE_CONDITIONAL);
           }
 
           let nested_key_values = get_key_values_from_object(object);
@@ -285,18 +357,22 @@ pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[Stri
           let mut extended_conditions = conditions.to_vec();
           extended_conditions.push(key);
 
-          validate_namespace(&nested_key_values, &extended_conditions);
+          validate_namespace(&nested_key_values, &extended_conditions, namespace_span);
         } else {
           let conditional_styles_key_values = get_key_values_from_object(object);
 
           for conditional_style in &conditional_styles_key_values {
-            validate_conditional_styles(conditional_style, conditions);
+            validate_conditional_styles(conditional_style, conditions, namespace_span);
           }
         }
       }
       _ => {
         if INCLUDED_IDENT_REGEX.is_match(&key) {
-          assert!(conditions.is_empty(), "{}", ONLY_TOP_LEVEL_INCLUDES)
+          span_assert(
+            conditions.is_empty(),
+            namespace_span,
+            ONLY_TOP_LEVEL_INCLUDES,
+          )
         }
       }
     }
@@ -304,23 +380,29 @@ pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[Stri
 }
 
 pub(crate) fn validate_dynamic_style_params(params: &[Pat]) {
-  if params.iter().any(|param| !param.is_ident()) {
-    panic!("{}", ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS);
+  if let Some(param) = params.iter().find(|param| param.is_rest()) {
+    span_panic(param.span(), NO_REST_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS);
   }
 }
 
-pub(crate) fn validate_conditional_styles(inner_key_value: &KeyValueProp, conditions: &[String]) {
+pub(crate) fn validate_conditional_styles(
+  inner_key_value: &KeyValueProp,
+  conditions: &[String],
+  span: Span,
+) {
   let inner_key = get_key_str(inner_key_value);
   let inner_value = inner_key_value.value.clone();
+  let inner_span = key_value_span(inner_key_value, span);
+  let inner_key_span = key_span(inner_key_value, span);
 
-  assert!(
-    (inner_key.starts_with(':') || inner_key.starts_with('@') || inner_key == "default"),
-    "{}",
+  span_assert(
+    inner_key.starts_with(':') || inner_key.starts_with('@') || inner_key == "default",
+    inner_key_span,
     INVALID_PSEUDO_OR_AT_RULE,
   );
 
   if conditions.contains(&inner_key) {
-    panic!("{}", DUPLICATE_CONDITIONAL);
+    span_panic(inner_key_span, DUPLICATE_CONDITIONAL);
   }
 
   match inner_value.as_ref() {
@@ -330,7 +412,7 @@ pub(crate) fn validate_conditional_styles(inner_key_value: &KeyValueProp, condit
         match elem {
           Some(elem) => match elem.expr.as_ref() {
             Expr::Lit(_) => {}
-            _ => panic!("{}", ILLEGAL_PROP_VALUE),
+            _ => span_panic(inner_span, ILLEGAL_PROP_VALUE),
           },
           None => {}
         }
@@ -343,19 +425,19 @@ pub(crate) fn validate_conditional_styles(inner_key_value: &KeyValueProp, condit
       extended_conditions.push(inner_key);
 
       for nested_key_value in nested_key_values.iter() {
-        validate_conditional_styles(nested_key_value, &extended_conditions);
+        validate_conditional_styles(nested_key_value, &extended_conditions, inner_span);
       }
     }
     Expr::Ident(_) => {
       if INCLUDED_IDENT_REGEX.is_match(&inner_key) {
-        panic!("{}", ONLY_TOP_LEVEL_INCLUDES);
+        span_panic(inner_span, ONLY_TOP_LEVEL_INCLUDES);
       }
     }
-    _ => panic!("{}", ILLEGAL_PROP_VALUE),
+    _ => span_panic(inner_span, ILLEGAL_PROP_VALUE),
   }
 }
 
-pub(crate) fn assert_valid_keyframes(obj: &EvaluateResultValue) {
+pub(crate) fn assert_valid_keyframes(obj: &EvaluateResultValue, span: Span) {
   match obj {
     EvaluateResultValue::Expr(expr) => match expr.as_ref() {
       Expr::Object(object) => {
@@ -364,41 +446,38 @@ pub(crate) fn assert_valid_keyframes(obj: &EvaluateResultValue) {
         for key_value in key_values.iter() {
           match key_value.value.as_ref() {
             Expr::Object(_) => {}
-            _ => panic!("{}", NON_OBJECT_KEYFRAME),
+            _ => span_panic(key_value_span(key_value, span), NON_OBJECT_KEYFRAME),
           }
         }
       }
-      _ => panic!("{}", NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL),
+      _ => span_panic(span, NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL),
     },
-    _ => panic!("{}", NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL),
+    _ => span_panic(span, NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL),
   }
 }
 
 pub(crate) fn validate_theme_variables(
   variables: &EvaluateResultValue,
-  state: &mut StateManager,
+  span: Span,
 ) -> KeyValueProp {
   if let Some(theme_ref) = variables.as_theme_ref() {
-    let mut cloned_theme_ref = theme_ref.clone();
-
-    let (value, updated_state) = cloned_theme_ref.get(THEME_NAME_KEY);
-
-    state.combine(updated_state);
+    let value = theme_ref.clone().get(THEME_NAME_KEY);
 
     let key_value = key_value_factory(THEME_NAME_KEY, string_to_expression(value.as_str()));
 
     return key_value;
   }
 
-  assert!(
+  span_assert(
     variables
       .as_expr()
       .map(|expr| expr.is_object())
       .unwrap_or(false),
-    "Can only override variables theme created with stylex.defineVars()."
+    span,
+    INVALID_THEME_VARIABLES,
   );
 
-  variables
+  let theme_name_key_value = variables
     .as_expr()
     .and_then(|expr| expr.as_object())
     .map(get_key_values_from_object)
@@ -423,6 +502,68 @@ pub(crate) fn validate_theme_variables(
       }
 
       None
-    })
-    .expect("Can only override variables theme created with stylex.defineVars().")
+    });
+
+  match theme_name_key_value {
+    Some(key_value) => key_value,
+    None => span_panic(span, INVALID_THEME_VARIABLES),
+  }
+}
+
+/// Every override of `stylex.createTheme()` must be a variable of the imported theme.
+pub(crate) fn validate_theme_overrides(
+  variables: &EvaluateResultValue,
+  overrides: &EvaluateResultValue,
+  overrides_arg: &Expr,
+) {
+  let Some(theme_ref) = variables.as_theme_ref() else {
+    return;
+  };
+
+  let Some(overrides) = overrides.as_expr().and_then(|expr| expr.as_object()) else {
+    return;
+  };
+
+  for key_value in get_key_values_from_object(overrides).iter() {
+    let key = get_key_str(key_value);
+
+    if theme_ref.is_defined(&key) {
+      continue;
+    }
+
+    let span = overrides_arg
+      .as_object()
+      .map(get_key_values_from_object)
+      .and_then(|key_values| {
+        key_values
+          .into_iter()
+          .find(|key_value| get_key_str(key_value) == key)
+      })
+      .map_or(overrides_arg.span(), |key_value| {
+        key_value_span(&key_value, overrides_arg.span())
+      });
+
+    span_panic(span, &format!("{} `{}`", UNDEFINED_THEME_VARIABLE, key));
+  }
+}
+
+/// Span of a style property, or of its key when the value was produced by evaluation and no
+/// longer points to the source.
+fn key_value_span(key_value: &KeyValueProp, fallback: Span) -> Span {
+  if key_value.value.span().is_dummy() {
+    key_span(key_value, fallback)
+  } else {
+    key_value.span()
+  }
+}
+
+/// Span of the key of a style property, or `fallback` when the key doesn't point to the source.
+fn key_span(key_value: &KeyValueProp, fallback: Span) -> Span {
+  let span = key_value.key.span();
+
+  if span.is_dummy() {
+    fallback
+  } else {
+    span
+  }
 }
@@ -1,27 +1,31 @@
 use std::collections::HashSet;
 
 use swc_core::{
-  common::comments::Comments,
-  ecma::ast::{CallExpr, Callee, Expr, Id, MemberProp, VarDeclarator},
+  common::{comments::Comments, errors::SourceMapperDyn, sync::Lrc},
+  ecma::ast::{CallExpr, Callee, Expr, Id, Ident, MemberProp, VarDeclarator},
 };
 
 use crate::{
   shared::{
     enums::core::ModuleCycle,
     structures::{
+      class_name_manifest::ClassNameManifest,
+      meta_data::MetaData,
       named_import_source::{ImportSources, RuntimeInjection},
       plugin_pass::PluginPass,
+      source_location::SourceLocator,
       state_manager::StateManager,
       stylex_options::StyleXOptions,
+      stylex_state_options::StyleXStateOptions,
     },
     utils::common::increase_ident_count,
   },
   StyleXOptionsParams,
 };
 
-mod fold;
 pub(crate) mod styleq;
 pub(crate) mod stylex;
+mod visit_mut;
 
 pub struct ModuleTransformVisitor<C>
 where
@@ -29,7 +33,12 @@ where
 {
   comments: C,
   cycle: ModuleCycle,
+  /// Set while a compiled expression is searched for references to style objects.
+  in_compiled_expr: bool,
   props_declaration: Option<Id>,
+  /// Members of style objects that are still referenced after the transform, as
+  /// `(object, property)`. Whether the styles are kept depends on the final reference counts.
+  style_references: Vec<(Ident, Id)>,
   pub(crate) state: Box<StateManager>,
 }
 
@@ -37,11 +46,7 @@ impl<C> ModuleTransformVisitor<C>
 where
   C: Comments,
 {
-  pub(crate) fn new(
-    comments: C,
-    plugin_pass: Box<PluginPass>,
-    config: &mut StyleXOptionsParams,
-  ) -> Self {
+  pub fn new(comments: C, plugin_pass: Box<PluginPass>, config: &mut StyleXOptionsParams) -> Self {
     let stylex_imports = fill_stylex_imports(&Some(config));
 
     let mut state = Box::new(StateManager::new(config.clone().into()));
@@ -58,7 +63,38 @@ where
     ModuleTransformVisitor {
       comments,
       cycle: ModuleCycle::Initializing,
+      in_compiled_expr: false,
+      props_declaration: None,
+      style_references: vec![],
+      state,
+    }
+  }
+
+  /// Transforms a module imported by the file being compiled with the same options.
+  pub(crate) fn new_nested(
+    comments: C,
+    plugin_pass: Box<PluginPass>,
+    options: Box<StyleXStateOptions>,
+  ) -> Self {
+    let mut state = Box::<StateManager>::default();
+
+    state.stylex_import = options
+      .import_sources
+      .iter()
+      .cloned()
+      .map(Box::new)
+      .collect();
+
+    state.class_name_manifest = ClassNameManifest::from_options(&options);
+    state.options = options;
+    state._state = plugin_pass;
+
+    ModuleTransformVisitor {
+      comments,
+      cycle: ModuleCycle::Initializing,
+      in_compiled_expr: false,
       props_declaration: None,
+      style_references: vec![],
       state,
     }
   }
@@ -100,7 +136,9 @@ where
     ModuleTransformVisitor {
       comments,
       cycle: ModuleCycle::Initializing,
+      in_compiled_expr: false,
       props_declaration: None,
+      style_references: vec![],
       state,
     }
   }
@@ -135,11 +173,40 @@ where
     ModuleTransformVisitor {
       comments,
       cycle: ModuleCycle::Initializing,
+      in_compiled_expr: false,
       props_declaration: None,
+      style_references: vec![],
       state,
     }
   }
 
+  /// Attaches the source map of the transformed file, so every generated rule records
+  /// the location of the style it comes from.
+  pub fn with_source_map(mut self, source_map: Lrc<SourceMapperDyn>) -> Self {
+    self.state.source_locator = Some(SourceLocator::new(source_map));
+
+    self
+  }
+
+  /// Styles extracted from the transformed module, in injection order.
+  pub fn get_metadata(&self) -> Vec<MetaData> {
+    self.state.metadata.values().flatten().cloned().collect()
+  }
+
+  /// Names of the atomic classes generated for this file, in order, without the rules of
+  /// themes, variables and keyframes.
+  pub fn get_atomic_class_names(&self) -> Vec<String> {
+    self
+      .state
+      .metadata
+      .values()
+      .flatten()
+      .map(|rule| rule.get_class_name())
+      .filter(|class_name| self.state.atomic_class_names.contains(*class_name))
+      .map(str::to_string)
+      .collect()
+  }
+
   pub(crate) fn process_declaration(&mut self, call_expr: &mut CallExpr) -> Option<(Id, String)> {
     let stylex_imports = self.state.stylex_import_stringified();
     if let Callee::Expr(callee) = &mut call_expr.callee {
@@ -148,7 +215,7 @@ where
           let ident_id = ident.to_id();
 
           if stylex_imports.contains(&ident.sym.to_string())
-            || (self.cycle == ModuleCycle::TransformEnter
+            || (self.is_transforming()
               && (self.state.stylex_create_import.contains(&ident.to_id()))
               || self.state.stylex_props_import.contains(&ident.to_id())
               || self.state.stylex_keyframes_import.contains(&ident.to_id())
@@ -178,7 +245,7 @@ where
             let ident_id = ident.to_id();
 
             if stylex_imports.contains(&ident.sym.to_string())
-              || (self.cycle == ModuleCycle::TransformEnter
+              || (self.is_transforming()
                 && (self.state.stylex_create_import.contains(&ident.to_id()))
                 || self.state.stylex_props_import.contains(&ident.to_id())
                 || self.state.stylex_keyframes_import.contains(&ident.to_id())
@@ -211,6 +278,10 @@ where
     None
   }
 
+  pub(crate) fn is_transforming(&self) -> bool {
+    self.cycle == ModuleCycle::Transform
+  }
+
   pub(crate) fn transform_call_expression(&mut self, expr: &mut Expr) -> Option<Expr> {
     if let Expr::Call(ex) = expr {
       let declaration = self.process_declaration(ex);
@@ -223,6 +294,19 @@ where
     None
   }
 
+  /// Compiles `expr` if it is a `stylex.create`, `defineVars`, `createTheme` or `keyframes` call.
+  pub(crate) fn transform_definition_call_expression(&mut self, expr: &mut Expr) -> Option<Expr> {
+    if let Expr::Call(ex) = expr {
+      let declaration = self.process_declaration(ex);
+
+      if declaration.is_some() {
+        return self.transform_stylex_definitions(ex);
+      }
+    }
+
+    None
+  }
+
   pub(crate) fn get_call_var_name(
     &mut self,
     call: &CallExpr,
@@ -231,16 +315,8 @@ where
 
     let parent_var_decl = self
       .state
-      .declarations
-      .clone()
-      .into_iter()
-      .find(|decl| {
-        decl
-          .init
-          .as_ref()
-          .unwrap()
-          .eq(&Box::new(Expr::Call(call.clone())))
-      })
+      .get_declaration_by_init(call)
+      .cloned()
       .map(Box::new);
 
     if let Some(parent_var_decl) = &parent_var_decl {
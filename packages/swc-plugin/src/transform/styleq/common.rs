@@ -8,6 +8,8 @@ use crate::shared::{
   constants::common::COMPILED_KEY,
   enums::data_structures::flat_compiled_styles_value::FlatCompiledStylesValue,
 };
+use swc_core::ecma::ast::{Expr, Lit};
+
 use crate::shared::{
   structures::types::FlatCompiledStyles,
   utils::core::parse_nullable_style::{ResolvedArg, StyleObject},
@@ -15,7 +17,15 @@ use crate::shared::{
 
 pub(crate) struct StyleQResult {
   pub(crate) class_name: String,
-  pub(crate) inline_style: Option<FlatCompiledStyles>,
+  pub(crate) inline_style: Option<IndexMap<String, Box<Expr>>>,
+  /// Locations of the applied namespaces, when they were compiled with debug data props.
+  pub(crate) data_style_src: Option<String>,
+}
+
+/// A style object to process, like the entries of the arrays styleq flattens at runtime.
+enum StyleQArg<'a> {
+  Compiled(&'a FlatCompiledStyles),
+  Inline(&'a IndexMap<String, Box<Expr>>),
 }
 
 fn get_hash<T>(obj: T) -> u64
@@ -35,130 +45,163 @@ pub(crate) fn styleq(arguments: &Vec<ResolvedArg>) -> StyleQResult {
     return StyleQResult {
       class_name,
       inline_style: None,
+      data_style_src: None,
     };
   }
 
   let mut defined_properties: Vec<String> = vec![]; // The className and inline style to build up
 
-  let inline_style: Option<FlatCompiledStyles> = None;
+  let mut inline_style: Option<IndexMap<String, Box<Expr>>> = None;
+
+  let mut data_style_src: Option<String> = None;
 
   let mut next_cache: Option<IndexMap<u64, (String, Vec<String>)>> = Some(IndexMap::new()); // This way of creating an array from arguments is fastest
 
   let mut styles = vec![];
 
   for arg in arguments {
-    styles.push(arg);
-  }
-
-  while !styles.is_empty() {
-    let possible_style = match styles.pop() {
-      Some(possible_style) => match possible_style {
-        ResolvedArg::StyleObject(_, _, _) => possible_style,
-        ResolvedArg::ConditionalStyle(_, value, _, _, _) => {
-          if value.is_some() {
-            possible_style
-          } else {
-            continue;
-          }
-        }
-      },
-      None => continue,
+    let style = match arg {
+      ResolvedArg::StyleObject(style, _, _) => style,
+      ResolvedArg::ConditionalStyle(_, Some(style), _, _, _) => style,
+      ResolvedArg::ConditionalStyle(_, None, _, _, _) => continue,
     };
 
+    match style {
+      StyleObject::Style(style) => styles.push(StyleQArg::Compiled(style)),
+      // A dynamic style function returns `[compiledStyle, inlineStyle]`
+      StyleObject::Dynamic(style, inline) => {
+        styles.push(StyleQArg::Compiled(style));
+        styles.push(StyleQArg::Inline(inline));
+      }
+      StyleObject::Nullable => panic!("Nullable style object is not allowed in styleq"),
+      StyleObject::Other => panic!("Other style object is not allowed in styleq"),
+    }
+  }
+
+  while let Some(possible_style) = styles.pop() {
     match possible_style {
-      ResolvedArg::StyleObject(style, _, _) => match style {
-        StyleObject::Style(style) => {
-          let Some(a) = style.get(COMPILED_KEY) else {
-            panic!("Style object does not contain a compiled key")
-          };
+      StyleQArg::Compiled(style) => {
+        if !style.contains_key(COMPILED_KEY) {
+          panic!("Style object does not contain a compiled key")
+        }
 
-          if let FlatCompiledStylesValue::Bool(_) = a.as_ref() {
-            let btree_map: BTreeMap<_, _> = style.iter().collect();
-
-            let style_hash = get_hash(btree_map);
-
-            // Build up the class names defined by this object
-            let mut class_name_chunk = String::default(); // Check the cache to see if we've already done this work
-
-            if next_cache
-              .clone()
-              .and_then(|cache| cache.get(&style_hash).cloned())
-              .is_some()
-            {
-              unimplemented!("Cache entry");
-            } else {
-              // The properties defined by this object
-              let mut defined_properties_chunk: Vec<String> = vec![];
-
-              for (prop, value) in style.iter() {
-                if prop.eq(COMPILED_KEY) {
-                  continue;
-                }
-
-                match value.as_ref() {
-                  FlatCompiledStylesValue::IncludedStyle(_) => {
-                    eprintln!(
-                      "styleq: {} typeof IncludedStyle is not \"string\" or \"null\".",
-                      prop
-                    )
-                  }
-                  FlatCompiledStylesValue::Bool(_) => {
-                    eprintln!(
-                      "styleq: {} typeof {:?} is not \"string\" or \"null\".",
-                      prop, "Bool"
-                    )
-                  }
-                  _ => {}
-                }
-
-                // Only add to chunks if this property hasn't already been seen
-                if !defined_properties.contains(prop) {
-                  defined_properties.push(prop.clone());
-
-                  if next_cache.is_some() {
-                    defined_properties_chunk.push(prop.clone())
-                  }
-
-                  if let FlatCompiledStylesValue::String(value) = *value.clone() {
-                    class_name_chunk = if class_name_chunk.is_empty() {
-                      value.to_string()
-                    } else {
-                      format!("{} {}", class_name_chunk, value)
-                    };
-                  }
-                }
+        let btree_map: BTreeMap<_, _> = style.iter().collect();
+
+        let style_hash = get_hash(btree_map);
+
+        // Build up the class names defined by this object
+        let mut class_name_chunk = String::default(); // Check the cache to see if we've already done this work
+
+        if next_cache
+          .as_ref()
+          .is_some_and(|cache| cache.contains_key(&style_hash))
+        {
+          // The same object was already applied by a later argument, which defined all of its
+          // properties, so it can't add any class names.
+          continue;
+        } else {
+          // The properties defined by this object
+          let mut defined_properties_chunk: Vec<String> = vec![];
+
+          for (prop, value) in style.iter() {
+            if prop.eq(COMPILED_KEY) {
+              if let FlatCompiledStylesValue::String(source) = value.as_ref() {
+                data_style_src = Some(match data_style_src {
+                  Some(data_style_src) => format!("{}; {}", source, data_style_src),
+                  None => source.clone(),
+                });
               }
 
-              if let Some(next_cache) = next_cache.as_mut() {
-                next_cache.insert(
-                  style_hash,
-                  (class_name_chunk.clone(), defined_properties_chunk.clone()),
-                );
+              continue;
+            }
+
+            match value.as_ref() {
+              FlatCompiledStylesValue::IncludedStyle(_) => {
+                eprintln!(
+                  "styleq: {} typeof IncludedStyle is not \"string\" or \"null\".",
+                  prop
+                )
+              }
+              FlatCompiledStylesValue::Bool(_) => {
+                eprintln!(
+                  "styleq: {} typeof {:?} is not \"string\" or \"null\".",
+                  prop, "Bool"
+                )
               }
+              _ => {}
             }
 
-            if !class_name_chunk.is_empty() {
-              class_name = if class_name.is_empty() {
-                class_name_chunk.clone()
-              } else if !class_name.contains(class_name_chunk.as_str()) {
-                format!("{} {}", class_name_chunk, class_name)
-              } else {
-                class_name
-              };
+            // Only add to chunks if this property hasn't already been seen
+            if !defined_properties.contains(prop) {
+              defined_properties.push(prop.clone());
+
+              if next_cache.is_some() {
+                defined_properties_chunk.push(prop.clone())
+              }
+
+              if let FlatCompiledStylesValue::String(value) = *value.clone() {
+                class_name_chunk = if class_name_chunk.is_empty() {
+                  value.to_string()
+                } else {
+                  format!("{} {}", class_name_chunk, value)
+                };
+              }
             }
+          }
+
+          if let Some(next_cache) = next_cache.as_mut() {
+            next_cache.insert(
+              style_hash,
+              (class_name_chunk.clone(), defined_properties_chunk.clone()),
+            );
+          }
+        }
+
+        if !class_name_chunk.is_empty() {
+          class_name = if class_name.is_empty() {
+            class_name_chunk.clone()
+          } else if !class_name.contains(class_name_chunk.as_str()) {
+            format!("{} {}", class_name_chunk, class_name)
           } else {
-            unimplemented!("DYNAMIC: Process inline style object")
+            class_name
+          };
+        }
+      }
+      // DYNAMIC: Process inline style object
+      StyleQArg::Inline(style) => {
+        let mut sub_style: IndexMap<String, Box<Expr>> = IndexMap::new();
+
+        for (prop, value) in style.iter() {
+          if is_undefined(value) || defined_properties.contains(prop) {
+            continue;
           }
+
+          if !matches!(value.as_ref(), Expr::Lit(Lit::Null(_))) {
+            sub_style.insert(prop.clone(), value.clone());
+          }
+
+          defined_properties.push(prop.clone());
+
+          // Cache is unnecessary overhead if results can't be reused.
+          next_cache = None;
         }
-        StyleObject::Nullable => panic!("Nullable style object is not allowed in styleq"),
-        StyleObject::Other => panic!("Other style object is not allowed in styleq"),
-      },
-      _ => unreachable!(),
-    };
+
+        if !sub_style.is_empty() {
+          sub_style.extend(inline_style.take().unwrap_or_default());
+
+          inline_style = Some(sub_style);
+        }
+      }
+    }
   }
 
   StyleQResult {
     class_name,
     inline_style,
+    data_style_src,
   }
 }
+
+fn is_undefined(value: &Expr) -> bool {
+  matches!(value, Expr::Ident(ident) if ident.sym == "undefined")
+}
@@ -4,7 +4,6 @@ use swc_core::{
 };
 use swc_ecma_ast::Id;
 
-use crate::shared::enums::core::ModuleCycle;
 use crate::ModuleTransformVisitor;
 
 impl<C> ModuleTransformVisitor<C>
@@ -28,33 +27,13 @@ where
   }
 
   fn transform_stylex_fns(&mut self, id: &Id, call_expr: &mut CallExpr) -> Option<Expr> {
-    if self.cycle == ModuleCycle::TransformEnter {
-      let (_, parent_var_decl) = &self.get_call_var_name(call_expr);
-
-      if let Some(parent_var_decl) = parent_var_decl {
-        if let Some(value) = self.transform_stylex_keyframes_call(parent_var_decl) {
-          return Some(value);
-        }
-      }
-
-      if let Some(value) = self.transform_stylex_define_vars(call_expr) {
-        return Some(value);
-      }
-
-      if let Some(value) = self.transform_stylex_create_theme_call(call_expr) {
-        return Some(value);
-      }
-
-      if let Some(value) = self.transform_stylex_create(call_expr) {
-        return Some(value);
-      }
-
-      if let Some(value) = self.transform_stylex_create(call_expr) {
-        return Some(value);
-      }
+    // Definitions are compiled at the top level before the rest of the module. The ones found
+    // while rewriting it are only compiled to report why they are not allowed there.
+    if let Some(value) = self.transform_stylex_definitions(call_expr) {
+      return Some(value);
     }
 
-    if self.cycle == ModuleCycle::TransformExit {
+    if self.is_transforming() {
       if self.state.stylex_props_import.contains(id) {
         if let Some(value) = self.transform_stylex_props_call(call_expr) {
           return Some(value);
@@ -82,4 +61,32 @@ where
 
     None
   }
+
+  pub(crate) fn transform_stylex_definitions(&mut self, call_expr: &mut CallExpr) -> Option<Expr> {
+    if !self.is_transforming() {
+      return None;
+    }
+
+    let (_, parent_var_decl) = &self.get_call_var_name(call_expr);
+
+    if let Some(parent_var_decl) = parent_var_decl {
+      if let Some(value) = self.transform_stylex_keyframes_call(parent_var_decl) {
+        return Some(value);
+      }
+    }
+
+    if let Some(value) = self.transform_stylex_define_vars(call_expr) {
+      return Some(value);
+    }
+
+    if let Some(value) = self.transform_stylex_create_theme_call(call_expr) {
+      return Some(value);
+    }
+
+    if let Some(value) = self.transform_stylex_create(call_expr) {
+      return Some(value);
+    }
+
+    None
+  }
 }
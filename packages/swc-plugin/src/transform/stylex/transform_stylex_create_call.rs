@@ -1,14 +1,15 @@
 use std::collections::HashMap;
 
 use indexmap::IndexMap;
-use swc_core::common::DUMMY_SP;
-use swc_core::ecma::ast::{ArrowExpr, BlockStmtOrExpr, ExprOrSpread, Ident, Pat, PropName};
+use swc_core::common::{Span, Spanned, DUMMY_SP};
+use swc_core::ecma::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, ExprOrSpread, Ident, ObjectLit, PropName, ReturnStmt, Stmt,
+};
 use swc_core::{
   common::comments::Comments,
   ecma::ast::{CallExpr, Expr, PropOrSpread},
 };
 
-use crate::shared::utils::validators::{is_create_call, validate_stylex_create};
 use crate::shared::utils::{
   ast::factories::array_expression_factory,
   core::js_to_expr::{convert_object_to_ast, remove_objects_with_spreads, NestedStringObject},
@@ -17,18 +18,18 @@ use crate::shared::utils::{
   ast::factories::object_expression_factory,
   common::{get_key_str, get_key_values_from_object},
 };
-use crate::shared::{
-  constants::messages::NON_STATIC_VALUE,
-  utils::core::dev_class_name::{convert_to_test_styles, inject_dev_class_names},
+use crate::shared::utils::{
+  diagnostics::span_panic,
+  validators::{is_create_call, validate_static_arg, validate_stylex_create},
 };
 use crate::shared::{
-  structures::functions::{FunctionConfig, FunctionMap, FunctionType},
-  transformers::{
-    stylex_create::stylex_create_set, stylex_first_that_works::stylex_first_that_works,
-    stylex_include::stylex_include, stylex_keyframes::get_keyframes_fn,
+  constants::messages::NON_STATIC_VALUE,
+  utils::core::dev_class_name::{
+    convert_to_test_styles, inject_debug_data_props, inject_dev_class_names,
   },
 };
 use crate::shared::{
+  enums::data_structures::flat_compiled_styles_value::FlatCompiledStylesValue,
   structures::types::{FlatCompiledStyles, FunctionMapMemberExpression},
   utils::core::evaluate_stylex_create_arg::evaluate_stylex_create_arg,
 };
@@ -36,6 +37,16 @@ use crate::shared::{
   structures::{functions::FunctionConfigType, types::FunctionMapIdentifiers},
   utils::ast::factories::prop_or_spread_expression_factory,
 };
+use crate::shared::{
+  structures::{
+    functions::{FunctionConfig, FunctionMap, FunctionType},
+    state_manager::StateManager,
+  },
+  transformers::{
+    stylex_create::stylex_create_set, stylex_first_that_works::stylex_first_that_works,
+    stylex_include::stylex_include, stylex_keyframes::get_keyframes_fn,
+  },
+};
 use crate::ModuleTransformVisitor;
 
 impl<C> ModuleTransformVisitor<C>
@@ -123,18 +134,22 @@ where
       let evaluated_arg =
         evaluate_stylex_create_arg(&mut first_arg, &mut self.state, &function_map);
 
+      validate_static_arg(&evaluated_arg, &first_arg, &mut self.state, &function_map);
+
       let value = match evaluated_arg.value {
         Some(value) => value,
-        None => {
-          panic!("{}", NON_STATIC_VALUE)
-        }
+        None => span_panic(first_arg.span(), NON_STATIC_VALUE),
       };
 
-      assert!(evaluated_arg.confident, "{}", NON_STATIC_VALUE);
-
       let (mut compiled_styles, injected_styles_sans_keyframes) =
         stylex_create_set(&value, &mut self.state, &function_map);
 
+      let style_sources = if self.state.source_locator.is_some() {
+        collect_style_sources(&call.args[0].expr, &compiled_styles)
+      } else {
+        HashMap::new()
+      };
+
       for (namespace, properties) in compiled_styles.iter() {
         resolved_namespaces
           .entry(namespace.clone())
@@ -148,12 +163,24 @@ where
 
       let (var_name, parent_var_decl) = &self.get_call_var_name(call);
 
+      let namespace_lines = if self.state.is_test() || self.state.is_dev() {
+        collect_namespace_lines(&call.args[0].expr, &self.state)
+      } else {
+        HashMap::new()
+      };
+
       if self.state.is_test() {
-        compiled_styles = convert_to_test_styles(&compiled_styles, var_name, &self.state);
+        compiled_styles =
+          convert_to_test_styles(&compiled_styles, var_name, &namespace_lines, &self.state);
       }
 
       if self.state.is_dev() {
-        compiled_styles = inject_dev_class_names(&compiled_styles, var_name, &self.state);
+        compiled_styles =
+          inject_dev_class_names(&compiled_styles, var_name, &namespace_lines, &self.state);
+      }
+
+      if self.state.is_debug_data_prop_enabled() && !self.state.is_test() {
+        compiled_styles = inject_debug_data_props(&compiled_styles, &namespace_lines, &self.state);
       }
 
       if let Some(var_name) = var_name.as_ref() {
@@ -168,6 +195,13 @@ where
           .state
           .style_vars
           .insert(var_name.clone(), parent_var_decl.clone().unwrap());
+
+        if let Some(fns) = &evaluated_arg.fns {
+          self
+            .state
+            .style_fns
+            .insert(var_name.clone(), Box::new(fns.clone()));
+        }
       }
 
       let mut result_ast =
@@ -192,29 +226,46 @@ where
               let mut prop: Option<PropOrSpread> = None;
 
               if let Some(key) = key {
-                if let Some((params, inline_styles)) = fns.get(&key) {
+                if let Some(dynamic_style) = fns.get(&key) {
+                  let result = array_expression_factory(vec![
+                    Some(ExprOrSpread {
+                      spread: None,
+                      expr: Box::new(*value.clone()),
+                    }),
+                    Some(ExprOrSpread {
+                      spread: None,
+                      expr: Box::new(object_expression_factory(
+                        dynamic_style
+                          .inline_styles
+                          .iter()
+                          .map(|(key, value)| {
+                            prop_or_spread_expression_factory(key.as_str(), *value.clone())
+                          })
+                          .collect(),
+                      )),
+                    }),
+                  ]);
+
+                  let body = if dynamic_style.stmts.is_empty() {
+                    BlockStmtOrExpr::from(Box::new(result))
+                  } else {
+                    let mut stmts = dynamic_style.stmts.clone();
+
+                    stmts.push(Stmt::Return(ReturnStmt {
+                      span: DUMMY_SP,
+                      arg: Some(Box::new(result)),
+                    }));
+
+                    BlockStmtOrExpr::BlockStmt(BlockStmt {
+                      span: DUMMY_SP,
+                      stmts,
+                    })
+                  };
+
                   let value = Expr::from(ArrowExpr {
                     span: DUMMY_SP,
-                    params: params.iter().map(|arg| Pat::Ident(arg.clone())).collect(),
-                    body: Box::new(BlockStmtOrExpr::from(Box::new(array_expression_factory(
-                      vec![
-                        Some(ExprOrSpread {
-                          spread: None,
-                          expr: Box::new(*value.clone()),
-                        }),
-                        Some(ExprOrSpread {
-                          spread: None,
-                          expr: Box::new(object_expression_factory(
-                            inline_styles
-                              .iter()
-                              .map(|(key, value)| {
-                                prop_or_spread_expression_factory(key.as_str(), *value.clone())
-                              })
-                              .collect(),
-                          )),
-                        }),
-                      ],
-                    )))),
+                    params: dynamic_style.params.clone(),
+                    body: Box::new(body),
                     is_async: false,
                     is_generator: false,
                     type_params: None,
@@ -236,9 +287,13 @@ where
         }
       };
 
-      self
-        .state
-        .register_styles(call, &injected_styles, &result_ast, var_name);
+      self.state.register_styles_with_sources(
+        call,
+        &injected_styles,
+        &style_sources,
+        &result_ast,
+        var_name,
+      );
 
       Some(result_ast)
     } else {
@@ -250,3 +305,87 @@ where
     result
   }
 }
+
+/// Spans of the properties of the `stylex.create` argument that produced each class name.
+///
+/// Class names of a namespace that can't be matched to one of its properties,
+/// like the ones of expanded shorthands, point to the namespace itself.
+fn collect_style_sources(
+  arg: &Expr,
+  compiled_styles: &IndexMap<String, Box<FlatCompiledStyles>>,
+) -> HashMap<String, Span> {
+  let mut sources = HashMap::new();
+
+  let Some(arg) = arg.as_object() else {
+    return sources;
+  };
+
+  for (namespace, properties) in compiled_styles {
+    let Some((namespace_span, namespace_value)) = find_key_value(arg, namespace) else {
+      continue;
+    };
+
+    let namespace_object = match namespace_value {
+      Expr::Object(object) => Some(object),
+      Expr::Arrow(arrow) => arrow
+        .body
+        .as_expr()
+        .and_then(|body| body.as_paren())
+        .and_then(|paren| paren.expr.as_object()),
+      _ => None,
+    };
+
+    for (property, value) in properties.iter() {
+      let FlatCompiledStylesValue::String(class_names) = value.as_ref() else {
+        continue;
+      };
+
+      let span = namespace_object
+        .and_then(|object| find_key_value(object, property))
+        .map_or(namespace_span, |(span, _)| span);
+
+      for class_name in class_names.split_whitespace() {
+        sources.entry(class_name.to_string()).or_insert(span);
+      }
+    }
+  }
+
+  sources
+}
+
+/// Lines of the namespaces of the `stylex.create` argument, when a source map is available.
+fn collect_namespace_lines(arg: &Expr, state: &StateManager) -> HashMap<String, usize> {
+  let Some(arg) = arg.as_object() else {
+    return HashMap::new();
+  };
+
+  arg
+    .props
+    .iter()
+    .filter_map(|prop| prop.as_prop()?.as_key_value())
+    .filter_map(|key_value| {
+      let namespace = match &key_value.key {
+        PropName::Ident(ident) => ident.sym.to_string(),
+        PropName::Str(str) => str.value.to_string(),
+        _ => return None,
+      };
+
+      let location = state.get_source_location(key_value.key.span())?;
+
+      Some((namespace, location.line))
+    })
+    .collect()
+}
+
+fn find_key_value<'a>(object: &'a ObjectLit, key: &str) -> Option<(Span, &'a Expr)> {
+  object
+    .props
+    .iter()
+    .filter_map(|prop| prop.as_prop()?.as_key_value())
+    .find(|key_value| match &key_value.key {
+      PropName::Ident(ident) => ident.sym == *key,
+      PropName::Str(str) => str.value == *key,
+      _ => false,
+    })
+    .map(|key_value| (key_value.key.span(), key_value.value.as_ref()))
+}
@@ -1,16 +1,16 @@
-use core::panic;
 use std::collections::HashMap;
 
 use indexmap::IndexMap;
 use swc_core::{
-  common::{comments::Comments, DUMMY_SP},
+  common::{comments::Comments, Spanned, DUMMY_SP},
   ecma::ast::{CallExpr, Expr, Ident},
 };
 
 use crate::shared::{
-  constants::messages::{NON_OBJECT_FOR_STYLEX_CALL, NON_STATIC_VALUE},
+  constants::messages::{INVALID_SPREAD, INVALID_THEME_VARIABLES, NON_OBJECT_FOR_STYLEX_CALL},
   utils::{
     core::js_to_expr::{convert_object_to_ast, NestedStringObject},
+    diagnostics::{span_assert, span_panic},
     js::evaluate::evaluate,
   },
 };
@@ -23,7 +23,8 @@ use crate::shared::{
   utils::{
     core::dev_class_name::convert_theme_to_dev_styles,
     validators::{
-      is_create_theme_call, validate_stylex_create_theme_indent, validate_theme_variables,
+      is_create_theme_call, validate_static_arg, validate_stylex_create_theme_indent,
+      validate_theme_overrides, validate_theme_variables,
     },
   },
 };
@@ -53,12 +54,12 @@ where
       let second_arg = call.args.get(1);
 
       let first_arg = first_arg.map(|first_arg| match &first_arg.spread {
-        Some(_) => unimplemented!("Spread"),
+        Some(spread) => span_panic(*spread, INVALID_SPREAD),
         None => first_arg.expr.clone(),
       })?;
 
       let second_arg = second_arg.map(|second_arg| match &second_arg.spread {
-        Some(_) => unimplemented!("Spread"),
+        Some(spread) => span_panic(*spread, INVALID_SPREAD),
         None => second_arg.expr.clone(),
       })?;
 
@@ -91,9 +92,7 @@ where
         );
 
         let identifier = identifiers
-          .entry(Box::new(
-            ident_factory(name.get_import_str()).to_id(),
-          ))
+          .entry(Box::new(ident_factory(name.get_import_str()).to_id()))
           .or_insert(Box::new(FunctionConfigType::Map(HashMap::default())));
 
         if let Some(identifier_map) = identifier.as_map_mut() {
@@ -108,44 +107,43 @@ where
 
       let evaluated_arg1 = evaluate(&first_arg, &mut self.state, &function_map);
 
-      assert!(evaluated_arg1.confident, "{}", NON_STATIC_VALUE);
+      validate_static_arg(&evaluated_arg1, &first_arg, &mut self.state, &function_map);
 
       let evaluated_arg2 = evaluate(&second_arg, &mut self.state, &function_map);
 
-      assert!(evaluated_arg2.confident, "{}", NON_STATIC_VALUE);
+      validate_static_arg(&evaluated_arg2, &second_arg, &mut self.state, &function_map);
 
       let mut variables = match evaluated_arg1.value {
         Some(value) => {
-          validate_theme_variables(&value, &mut self.state);
+          validate_theme_variables(&value, first_arg.span());
 
           value
         }
-        None => {
-          panic!("Can only override variables theme created with stylex.defineVars().")
-        }
+        None => span_panic(first_arg.span(), INVALID_THEME_VARIABLES),
       };
       let overrides = match evaluated_arg2.value {
         Some(value) => {
-          assert!(
+          span_assert(
             value
               .as_expr()
               .map(|expr| expr.is_object())
               .unwrap_or(false),
-            "{}",
-            NON_OBJECT_FOR_STYLEX_CALL
+            second_arg.span(),
+            NON_OBJECT_FOR_STYLEX_CALL,
           );
           value
         }
-        None => {
-          panic!("{}", NON_OBJECT_FOR_STYLEX_CALL)
-        }
+        None => span_panic(second_arg.span(), NON_OBJECT_FOR_STYLEX_CALL),
       };
 
+      validate_theme_overrides(&variables, &overrides, &second_arg);
+
       let (mut overrides_obj, inject_styles) = stylex_create_theme(
         &mut variables,
         &overrides,
         &mut self.state,
         &mut IndexMap::default(),
+        call.span,
       );
 
       let (var_name, _) = self.get_call_var_name(call);
@@ -1,21 +1,25 @@
 use std::{collections::HashMap, panic};
 
-use swc_core::common::DUMMY_SP;
+use swc_core::common::{Spanned, DUMMY_SP};
 use swc_core::ecma::ast::Ident;
 use swc_core::{
   common::comments::Comments,
   ecma::ast::{CallExpr, Expr},
 };
 
-use crate::shared::utils::{common::gen_file_based_identifier, js::evaluate::evaluate};
-use crate::shared::{
-  constants::messages::NON_OBJECT_FOR_STYLEX_CALL,
-  utils::validators::{is_define_vars_call, validate_stylex_define_vars},
+use crate::shared::utils::{
+  common::gen_file_based_identifier,
+  diagnostics::{span_assert, span_panic},
+  js::evaluate::evaluate,
 };
 use crate::shared::{
   constants::messages::NON_STATIC_VALUE,
   utils::core::js_to_expr::{convert_object_to_ast, NestedStringObject},
 };
+use crate::shared::{
+  constants::messages::{INVALID_SPREAD, NON_OBJECT_FOR_STYLEX_CALL},
+  utils::validators::{is_define_vars_call, validate_static_arg, validate_stylex_define_vars},
+};
 use crate::shared::{
   enums::data_structures::top_level_expression::TopLevelExpressionKind,
   structures::{
@@ -46,7 +50,7 @@ where
       let first_arg = call.args.first();
 
       let first_arg = first_arg.map(|first_arg| match &first_arg.spread {
-        Some(_) => unimplemented!("Spread"),
+        Some(spread) => span_panic(*spread, INVALID_SPREAD),
         None => first_arg.expr.clone(),
       })?;
 
@@ -79,9 +83,7 @@ where
         );
 
         let identifier = identifiers
-          .entry(Box::new(
-            ident_factory(name.get_import_str()).to_id(),
-          ))
+          .entry(Box::new(ident_factory(name.get_import_str()).to_id()))
           .or_insert(Box::new(FunctionConfigType::Map(HashMap::default())));
 
         if let Some(identifier_map) = identifier.as_map_mut() {
@@ -96,23 +98,21 @@ where
 
       let evaluated_arg = evaluate(&first_arg, &mut self.state, &function_map);
 
-      assert!(evaluated_arg.confident, "{}", NON_STATIC_VALUE);
+      validate_static_arg(&evaluated_arg, &first_arg, &mut self.state, &function_map);
 
       let value = match evaluated_arg.value {
         Some(value) => {
-          assert!(
+          span_assert(
             value
               .as_expr()
               .map(|expr| expr.is_object())
               .unwrap_or(false),
-            "{}",
-            NON_OBJECT_FOR_STYLEX_CALL
+            first_arg.span(),
+            NON_OBJECT_FOR_STYLEX_CALL,
           );
           value
         }
-        None => {
-          panic!("{}", NON_STATIC_VALUE)
-        }
+        None => span_panic(first_arg.span(), NON_STATIC_VALUE),
       };
 
       let Some(file_name) = self.state.get_filename_for_hashing() else {
@@ -124,7 +124,7 @@ where
         .get_top_level_expr(&TopLevelExpressionKind::NamedExport, call);
 
       let export_name = export_expr
-        .and_then(|expr| expr.2)
+        .and_then(|expr| expr.2.clone())
         .map(|decl| decl.0.to_string())
         .expect("Export variable not found");
 
@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 
 use indexmap::IndexMap;
-use swc_core::common::DUMMY_SP;
+use swc_core::common::{Spanned, DUMMY_SP};
 use swc_core::ecma::ast::{Ident, VarDeclarator};
 use swc_core::{common::comments::Comments, ecma::ast::Expr};
 
 use crate::shared::structures::functions::FunctionConfigType;
 use crate::shared::utils::{
   ast::convertors::string_to_expression,
+  diagnostics::{span_assert, span_panic},
   validators::{assert_valid_keyframes, is_keyframes_call, validate_stylex_keyframes_indent},
 };
 use crate::shared::{
@@ -102,28 +103,30 @@ where
 
       let evaluated_arg = evaluate(&first_arg, &mut self.state, &function_map);
 
-      assert!(evaluated_arg.confident, "{}", NON_STATIC_VALUE);
+      span_assert(
+        evaluated_arg.confident,
+        evaluated_arg.deopt_span(first_arg.span()),
+        NON_STATIC_VALUE,
+      );
 
       let value = match evaluated_arg.value {
         Some(value) => {
-          assert!(
+          span_assert(
             value
               .as_expr()
               .map(|expr| expr.is_object())
               .unwrap_or(false),
-            "{}",
-            NON_OBJECT_FOR_STYLEX_CALL
+            first_arg.span(),
+            NON_OBJECT_FOR_STYLEX_CALL,
           );
           value
         }
-        None => {
-          panic!("{}", NON_STATIC_VALUE)
-        }
+        None => span_panic(first_arg.span(), NON_STATIC_VALUE),
       };
 
       let plain_object = value;
 
-      assert_valid_keyframes(&plain_object);
+      assert_valid_keyframes(&plain_object, first_arg.span());
 
       let (animation_name, injectable_style) = stylex_keyframes(&plain_object, &mut self.state);
 
@@ -0,0 +1,80 @@
+mod visit_mut_export_decl;
+mod visit_mut_export_default_expr;
+mod visit_mut_expr;
+mod visit_mut_ident;
+mod visit_mut_import_decl;
+mod visit_mut_member_expression;
+mod visit_mut_module;
+mod visit_mut_module_items;
+mod visit_mut_var_declarator;
+
+use swc_core::{
+  common::comments::Comments,
+  ecma::{
+    ast::{
+      ExportDecl, ExportDefaultExpr, Expr, Ident, ImportDecl, MemberExpr, Module, ModuleItem,
+      VarDeclarator,
+    },
+    visit::{noop_fold_type, noop_visit_mut_type, Fold, VisitMut, VisitMutWith},
+  },
+};
+
+use crate::ModuleTransformVisitor;
+
+impl<C> VisitMut for ModuleTransformVisitor<C>
+where
+  C: Comments,
+{
+  noop_visit_mut_type!();
+
+  fn visit_mut_module(&mut self, module: &mut Module) {
+    self.visit_mut_module_impl(module)
+  }
+
+  fn visit_mut_module_items(&mut self, module_items: &mut Vec<ModuleItem>) {
+    self.visit_mut_module_items_impl(module_items)
+  }
+
+  fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
+    self.visit_mut_import_decl_impl(import_decl)
+  }
+
+  fn visit_mut_var_declarator(&mut self, var_declarator: &mut VarDeclarator) {
+    self.visit_mut_var_declarator_impl(var_declarator)
+  }
+
+  fn visit_mut_export_decl(&mut self, export_decl: &mut ExportDecl) {
+    self.visit_mut_export_decl_impl(export_decl)
+  }
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    self.visit_mut_expr_impl(expr)
+  }
+
+  fn visit_mut_member_expr(&mut self, member_expression: &mut MemberExpr) {
+    self.visit_mut_member_expr_impl(member_expression)
+  }
+
+  fn visit_mut_export_default_expr(&mut self, export_default_expr: &mut ExportDefaultExpr) {
+    self.visit_mut_export_default_expr_impl(export_default_expr)
+  }
+
+  fn visit_mut_ident(&mut self, ident: &mut Ident) {
+    self.visit_mut_ident_impl(ident)
+  }
+}
+
+/// Keeps `fold_with` callers working: the module is rewritten in place by
+/// the `VisitMut` implementation above.
+impl<C> Fold for ModuleTransformVisitor<C>
+where
+  C: Comments,
+{
+  noop_fold_type!();
+
+  fn fold_module(&mut self, mut module: Module) -> Module {
+    module.visit_mut_with(self);
+
+    module
+  }
+}
@@ -2,7 +2,7 @@ use swc_core::{
   common::comments::Comments,
   ecma::{
     ast::{Decl, ExportDecl},
-    visit::FoldWith,
+    visit::VisitMutWith,
   },
 };
 
@@ -15,9 +15,9 @@ impl<C> ModuleTransformVisitor<C>
 where
   C: Comments,
 {
-  pub(crate) fn fold_export_decl_impl(&mut self, export_decl: ExportDecl) -> ExportDecl {
+  pub(crate) fn visit_mut_export_decl_impl(&mut self, export_decl: &mut ExportDecl) {
     if self.cycle == ModuleCycle::Skip {
-      return export_decl;
+      return;
     }
 
     if self.cycle == ModuleCycle::Initializing {
@@ -31,6 +31,6 @@ where
       }
     }
 
-    export_decl.fold_children_with(self)
+    export_decl.visit_mut_children_with(self);
   }
 }
@@ -9,22 +9,20 @@ impl<C> ModuleTransformVisitor<C>
 where
   C: Comments,
 {
-  pub(crate) fn fold_export_default_expr_impl(
+  pub(crate) fn visit_mut_export_default_expr_impl(
     &mut self,
-    mut export_default_expr: ExportDefaultExpr,
-  ) -> ExportDefaultExpr {
+    export_default_expr: &mut ExportDefaultExpr,
+  ) {
     if self.cycle == ModuleCycle::Skip {
-      return export_default_expr;
+      return;
     }
 
-    if self.cycle == ModuleCycle::TransformEnter || self.cycle == ModuleCycle::TransformExit {
+    if self.cycle == ModuleCycle::Transform {
       let normalized_expr = normalize_expr(&mut export_default_expr.expr);
 
       if let Some(value) = self.transform_call_expression(normalized_expr) {
         *export_default_expr.expr = value;
       }
     }
-
-    export_default_expr
   }
 }
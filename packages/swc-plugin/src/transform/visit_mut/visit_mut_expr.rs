@@ -0,0 +1,38 @@
+use swc_core::{
+  common::comments::Comments,
+  ecma::{ast::Expr, visit::VisitMutWith},
+};
+
+use crate::{shared::enums::core::ModuleCycle, ModuleTransformVisitor};
+
+impl<C> ModuleTransformVisitor<C>
+where
+  C: Comments,
+{
+  pub(crate) fn visit_mut_expr_impl(&mut self, expr: &mut Expr) {
+    if self.cycle == ModuleCycle::Skip {
+      return;
+    }
+
+    if self.cycle == ModuleCycle::Initializing {
+      if let Some(call_expr) = expr.as_call() {
+        self.state.add_call_expression(call_expr);
+      }
+    }
+
+    if self.cycle == ModuleCycle::Transform && !self.in_compiled_expr {
+      if let Some(value) = self.transform_call_expression(expr) {
+        *expr = value;
+
+        // The compiled value is not transformed again, but can still reference styles.
+        self.in_compiled_expr = true;
+        expr.visit_mut_children_with(self);
+        self.in_compiled_expr = false;
+
+        return;
+      }
+    }
+
+    expr.visit_mut_children_with(self);
+  }
+}
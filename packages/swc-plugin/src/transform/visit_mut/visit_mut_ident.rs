@@ -9,15 +9,9 @@ impl<C> ModuleTransformVisitor<C>
 where
   C: Comments,
 {
-  pub(crate) fn fold_ident_impl(&mut self, ident: Ident) -> Ident {
-    if self.cycle == ModuleCycle::Skip {
-      return ident;
-    }
-
+  pub(crate) fn visit_mut_ident_impl(&mut self, ident: &mut Ident) {
     if self.cycle == ModuleCycle::Initializing {
-      increase_ident_count(&mut self.state, &ident);
+      increase_ident_count(&mut self.state, ident);
     }
-
-    ident
   }
 }
@@ -2,7 +2,7 @@ use swc_core::{
   common::comments::Comments,
   ecma::{
     ast::{ImportDecl, ImportNamedSpecifier, ImportSpecifier, ModuleExportName},
-    visit::FoldWith,
+    visit::VisitMutWith,
   },
 };
 
@@ -18,14 +18,10 @@ impl<C> ModuleTransformVisitor<C>
 where
   C: Comments,
 {
-  pub(crate) fn fold_import_decl_impl(&mut self, import_decl: ImportDecl) -> ImportDecl {
-    if self.cycle == ModuleCycle::Skip {
-      return import_decl;
-    }
-
+  pub(crate) fn visit_mut_import_decl_impl(&mut self, import_decl: &mut ImportDecl) {
     if self.cycle == ModuleCycle::Initializing {
       if import_decl.type_only {
-        return import_decl;
+        return;
       }
 
       let src = &import_decl.src;
@@ -97,13 +93,9 @@ where
         }
       }
 
-      if self.state.import_paths.is_empty() {
-        import_decl
-      } else {
-        import_decl.fold_children_with(self)
+      if !self.state.import_paths.is_empty() {
+        import_decl.visit_mut_children_with(self);
       }
-    } else {
-      import_decl
     }
   }
 
@@ -185,6 +177,12 @@ where
             .stylex_types_import
             .insert(Box::new(local_name_ident));
         }
+        "css" => {
+          self
+            .state
+            .stylex_css_import
+            .insert(Box::new(local_name_ident));
+        }
         _ => {
           unreachable!("{}", MUST_BE_DEFAULT_IMPORT)
         }
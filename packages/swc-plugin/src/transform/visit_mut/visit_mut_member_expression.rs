@@ -0,0 +1,82 @@
+use std::mem::take;
+
+use swc_core::{
+  common::comments::Comments,
+  ecma::{
+    ast::{Expr, MemberExpr, MemberProp},
+    visit::VisitMutWith,
+  },
+};
+
+use crate::{
+  shared::{
+    enums::{
+      core::ModuleCycle,
+      data_structures::style_vars_to_keep::{NonNullProp, NonNullProps, StyleVarsToKeep},
+    },
+    utils::common::{increase_ident_count, increase_member_ident_count},
+  },
+  ModuleTransformVisitor,
+};
+
+impl<C> ModuleTransformVisitor<C>
+where
+  C: Comments,
+{
+  pub(crate) fn visit_mut_member_expr_impl(&mut self, member_expression: &mut MemberExpr) {
+    if self.cycle == ModuleCycle::Skip {
+      return;
+    }
+
+    if self.cycle == ModuleCycle::Initializing {
+      if let Some(obj_ident) = member_expression.obj.as_ident() {
+        increase_member_ident_count(&mut self.state, &obj_ident.to_id());
+      }
+
+      member_expression.visit_mut_children_with(self);
+
+      return;
+    }
+
+    if self.cycle == ModuleCycle::Transform {
+      self.collect_style_reference(member_expression);
+
+      if self.in_compiled_expr {
+        return;
+      }
+    }
+
+    member_expression.visit_mut_children_with(self);
+  }
+
+  fn collect_style_reference(&mut self, member_expression: &MemberExpr) {
+    if let (Expr::Ident(object), MemberProp::Ident(prop)) =
+      (member_expression.obj.as_ref(), &member_expression.prop)
+    {
+      if self.state.style_map.contains_key(object.sym.as_str()) {
+        self.style_references.push((object.clone(), prop.to_id()));
+      }
+    }
+  }
+
+  /// Keeps the styles that are still referenced once every stylex call is compiled.
+  pub(crate) fn keep_referenced_styles(&mut self) {
+    for (object, prop_name) in take(&mut self.style_references) {
+      let obj_name = object.to_id();
+
+      if let Some(count) = self.state.member_object_ident_count_map.get(&obj_name) {
+        if count > &0 {
+          increase_ident_count(&mut self.state, &object);
+
+          let style_var_to_keep =
+            StyleVarsToKeep(obj_name, NonNullProp::Id(prop_name), NonNullProps::True);
+
+          self
+            .state
+            .style_vars_to_keep
+            .insert(Box::new(style_var_to_keep));
+        }
+      }
+    }
+  }
+}
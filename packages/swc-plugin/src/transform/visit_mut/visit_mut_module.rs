@@ -0,0 +1,76 @@
+use std::{fs, path::Path};
+
+use swc_core::{
+  common::comments::{Comment, CommentKind, Comments},
+  ecma::{ast::Module, visit::VisitMutWith},
+};
+
+use crate::{
+  shared::{enums::core::ModuleCycle, structures::meta_data::MetaDataFile},
+  ModuleTransformVisitor,
+};
+
+impl<C> ModuleTransformVisitor<C>
+where
+  C: Comments,
+{
+  pub(crate) fn visit_mut_module_impl(&mut self, module: &mut Module) {
+    module.visit_mut_children_with(self);
+
+    if self.cycle != ModuleCycle::Skip {
+      self.cycle = ModuleCycle::Transform;
+      module.visit_mut_children_with(self);
+
+      if self.state.options.runtime_injection.is_none() {
+        let metadata = self.get_metadata();
+
+        if let Some(output_path) = self.state.get_metadata_output_path() {
+          write_metadata_file(
+            &output_path,
+            &MetaDataFile::new(self.state.get_filename(), &metadata),
+          );
+        }
+
+        if self.state.options.metadata.comment.unwrap_or(true) {
+          // Preparing stylex metadata for css extraction
+          self.comments.add_leading(
+            module.span.lo,
+            Comment {
+              kind: CommentKind::Line,
+              text: format!(
+                "__stylex_metadata_start__{}__stylex_metadata_end__",
+                serde_json::to_string(&metadata).unwrap()
+              )
+              .into(),
+              span: module.span,
+            },
+          );
+        }
+      }
+    }
+  }
+}
+
+fn write_metadata_file(output_path: &Path, metadata_file: &MetaDataFile) {
+  if let Some(output_dir) = output_path.parent() {
+    fs::create_dir_all(output_dir).unwrap_or_else(|error| {
+      panic!(
+        "Failed to create StyleX metadata directory {}: {}",
+        output_dir.display(),
+        error
+      )
+    });
+  }
+
+  fs::write(
+    output_path,
+    serde_json::to_string_pretty(metadata_file).unwrap(),
+  )
+  .unwrap_or_else(|error| {
+    panic!(
+      "Failed to write StyleX metadata to {}: {}",
+      output_path.display(),
+      error
+    )
+  });
+}
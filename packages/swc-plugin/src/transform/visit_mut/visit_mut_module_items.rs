@@ -0,0 +1,214 @@
+use std::collections::HashSet;
+
+use swc_core::{
+  common::comments::Comments,
+  ecma::{
+    ast::{
+      Decl, Expr, Id, ImportDecl, ImportSpecifier, Lit, ModuleDecl, ModuleItem, Pat, Stmt,
+      VarDeclarator,
+    },
+    visit::VisitMutWith,
+  },
+};
+use swc_ecma_ast::ExportDecl;
+
+use crate::{
+  shared::{
+    enums::core::ModuleCycle,
+    utils::common::{fill_top_level_expressions, normalize_expr},
+  },
+  ModuleTransformVisitor,
+};
+
+impl<C> ModuleTransformVisitor<C>
+where
+  C: Comments,
+{
+  pub(crate) fn visit_mut_module_items_impl(&mut self, module_items: &mut Vec<ModuleItem>) {
+    match self.cycle {
+      ModuleCycle::Skip => {}
+      ModuleCycle::Initializing => {
+        module_items.iter().for_each(|module_item| {
+          if let ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) = module_item {
+            var_decl.decls.iter().for_each(|decl| {
+              if let Pat::Ident(_) = &decl.name {
+                self.state.add_declaration(decl);
+              }
+            });
+          }
+        });
+
+        module_items.visit_mut_children_with(self);
+
+        if self.state.import_paths.is_empty() {
+          self.cycle = ModuleCycle::Skip;
+        } else {
+          fill_top_level_expressions(module_items, &mut self.state);
+        }
+      }
+      ModuleCycle::Transform => {
+        // The top-level definitions are compiled first, so that they are known wherever they
+        // are used, then the rest of the module is rewritten.
+        self.transform_top_level_definitions(module_items);
+
+        module_items.visit_mut_children_with(self);
+
+        self.keep_referenced_styles();
+
+        if self.state.options.runtime_injection.is_some() {
+          self.inject_styles(module_items);
+        }
+
+        self.clean_module_items(module_items);
+      }
+    }
+  }
+
+  fn transform_top_level_definitions(&mut self, module_items: &mut [ModuleItem]) {
+    for module_item in module_items.iter_mut() {
+      match module_item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl)))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+          decl: Decl::Var(var_decl),
+          ..
+        })) => {
+          for decl in var_decl.decls.iter_mut() {
+            if let Some(init) = decl.init.as_deref_mut() {
+              if let Some(value) = self.transform_definition_call_expression(init) {
+                *init = value;
+              }
+            }
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_default_expr)) => {
+          let normalized_expr = normalize_expr(&mut export_default_expr.expr);
+
+          if let Some(value) = self.transform_definition_call_expression(normalized_expr) {
+            *export_default_expr.expr = value;
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  /// Inserts the styles to inject before the declarations they are compiled from. Only the
+  /// top-level items are reordered, the module is not traversed.
+  fn inject_styles(&mut self, module_items: &mut Vec<ModuleItem>) {
+    let mut result_module_items: Vec<ModuleItem> = self.state.prepend_include_module_items.clone();
+
+    result_module_items.extend(self.state.prepend_import_module_items.iter().cloned());
+
+    let mut module_items_iter = std::mem::take(module_items).into_iter().peekable();
+
+    if module_items_iter
+      .peek()
+      .and_then(|first| first.as_stmt())
+      .and_then(|stmp| stmp.as_expr())
+      .is_some_and(|first| matches!(first.expr.as_lit(), Some(Lit::Str(_))))
+    {
+      result_module_items.insert(0, module_items_iter.next().unwrap());
+    }
+
+    for module_item in module_items_iter {
+      let inits: Vec<&Expr> = match &module_item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => export_decl
+          .decl
+          .as_var()
+          .map(|var_decl| var_decl.decls.iter().filter_map(injectable_init).collect())
+          .unwrap_or_default(),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_default_expr)) => {
+          if export_default_expr.expr.is_object() {
+            vec![export_default_expr.expr.as_ref()]
+          } else {
+            vec![]
+          }
+        }
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+          var_decl.decls.iter().filter_map(injectable_init).collect()
+        }
+        _ => vec![],
+      };
+
+      for init in inits {
+        if let Some(metadata_items) = self.state.styles_to_inject.get(init) {
+          result_module_items.extend(metadata_items.iter().cloned());
+        }
+      }
+
+      result_module_items.push(module_item);
+    }
+
+    *module_items = result_module_items;
+  }
+
+  /// Removes the declarations that are no longer used once the stylex calls are compiled, and
+  /// the styles nothing references. Needs the final reference counts, so it runs after the
+  /// traversal, on the top-level items only.
+  fn clean_module_items(&mut self, module_items: &mut Vec<ModuleItem>) {
+    for module_item in module_items.iter_mut() {
+      if let ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl)))
+      | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        decl: Decl::Var(var_decl),
+        ..
+      })) = module_item
+      {
+        var_decl.decls.retain(|decl| {
+          if let Pat::Ident(bind_ident) = &decl.name {
+            if let Some(count) = self.state.var_decl_count_map.get(&bind_ident.to_id()) {
+              // Remove the variable declaration if it is used only once after transformation.
+              return count > &1;
+            }
+          }
+
+          true
+        });
+
+        for decl in var_decl.decls.iter_mut() {
+          self.clean_var_declarator(decl);
+        }
+      }
+    }
+
+    let stylex_css_import = &self.state.stylex_css_import;
+
+    module_items.retain_mut(|module_item| match module_item {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+        remove_css_specifiers(import_decl, stylex_css_import)
+      }
+      _ => true,
+    });
+
+    // We remove `Stmt::Empty` and variable declarations without declarators, which are invalid.
+    module_items.retain(|module_item| {
+      !matches!(module_item, ModuleItem::Stmt(Stmt::Empty(..)))
+        && !matches!(module_item, ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) if var.decls.is_empty())
+        && !matches!(module_item, ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl: Decl::Var(var), .. })) if var.decls.is_empty())
+    });
+  }
+}
+
+fn injectable_init(decl: &VarDeclarator) -> Option<&Expr> {
+  decl
+    .init
+    .as_deref()
+    .filter(|init| init.is_object() || init.is_lit())
+}
+
+/// `css` templates are compiled away, so their specifiers are removed,
+/// and `false` is returned when nothing else was imported.
+fn remove_css_specifiers(
+  import_decl: &mut ImportDecl,
+  stylex_css_import: &HashSet<Box<Id>>,
+) -> bool {
+  if import_decl.specifiers.is_empty() {
+    return true;
+  }
+
+  import_decl.specifiers.retain(|specifier| match specifier {
+    ImportSpecifier::Named(named) => !stylex_css_import.contains(&named.local.to_id()),
+    _ => true,
+  });
+
+  !import_decl.specifiers.is_empty()
+}
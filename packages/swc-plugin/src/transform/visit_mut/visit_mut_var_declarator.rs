@@ -4,7 +4,7 @@ use swc_core::{
   common::{comments::Comments, EqIgnoreSpan},
   ecma::{
     ast::{Expr, Id, KeyValueProp, Lit, ObjectLit, Prop, PropName, PropOrSpread, VarDeclarator},
-    visit::FoldWith,
+    visit::VisitMutWith,
   },
 };
 
@@ -26,82 +26,9 @@ impl<C> ModuleTransformVisitor<C>
 where
   C: Comments,
 {
-  pub(crate) fn fold_var_declarator_impl(
-    &mut self,
-    mut var_declarator: VarDeclarator,
-  ) -> VarDeclarator {
-    if self.cycle != ModuleCycle::Initializing
-      && self.cycle != ModuleCycle::TransformEnter
-      && self.cycle != ModuleCycle::TransformExit
-      && self.cycle != ModuleCycle::PreCleaning
-    {
-      if self.cycle == ModuleCycle::Cleaning {
-        let mut vars_to_keep: HashMap<Id, NonNullProps> = HashMap::new();
-
-        for StyleVarsToKeep(var_name, namespace_name, _) in self
-          .state
-          .style_vars_to_keep
-          .clone()
-          .into_iter()
-          .map(|item| *item)
-        {
-          match vars_to_keep.entry(var_name) {
-            Entry::Occupied(mut entry) => {
-              if let NonNullProps::Vec(vec) = entry.get_mut() {
-                if let NonNullProp::Id(id) = namespace_name {
-                  vec.push(id);
-                }
-              }
-            }
-            Entry::Vacant(entry) => {
-              let value = match namespace_name {
-                NonNullProp::Id(namespace_name) => NonNullProps::Vec(vec![namespace_name]),
-                NonNullProp::True => NonNullProps::True,
-              };
-              entry.insert(value);
-            }
-          }
-        }
-
-        for (_, var_name) in self.state.style_vars.clone().into_iter() {
-          if var_declarator.name != var_name.name {
-            continue;
-          };
-
-          let var_decl = self.state.top_level_expressions.clone().into_iter().find(
-            |TopLevelExpression(_, expr, _)| {
-              var_name.init.clone().unwrap().eq(&Box::new(expr.clone()))
-            },
-          );
-          if let Some(TopLevelExpression(kind, _, _)) = var_decl {
-            if TopLevelExpressionKind::Stmt == kind {
-              if let Some(object) = var_declarator.init.as_mut() {
-                if let Some(mut object) = object.as_object().cloned() {
-                  let namespaces_to_keep =
-                    match vars_to_keep.get(&var_name.name.as_ident().unwrap().to_id()) {
-                      Some(e) => match e {
-                        NonNullProps::Vec(vec) => vec.clone(),
-                        NonNullProps::True => vec![],
-                      },
-                      None => vec![],
-                    };
-
-                  if !namespaces_to_keep.is_empty() {
-                    let props =
-                      self.retain_object_props(&mut object, namespaces_to_keep, var_name.as_ref());
-
-                    object.props = props;
-
-                    var_declarator.init = Some(Box::new(Expr::from(object)));
-                  }
-                }
-              }
-            }
-          }
-        }
-      }
-
-      return var_declarator;
+  pub(crate) fn visit_mut_var_declarator_impl(&mut self, var_declarator: &mut VarDeclarator) {
+    if self.cycle == ModuleCycle::Skip {
+      return;
     }
 
     if let Some(Expr::Call(call)) = var_declarator.init.as_deref_mut() {
@@ -130,12 +57,72 @@ where
       }
     }
 
-    // Call the fold_children_with method on the VarDecl struct
-    var_declarator.fold_children_with(self)
+    var_declarator.visit_mut_children_with(self);
+  }
+
+  /// Removes the namespaces of a top-level style object that are no longer referenced.
+  pub(crate) fn clean_var_declarator(&self, var_declarator: &mut VarDeclarator) {
+    let mut vars_to_keep: HashMap<Id, NonNullProps> = HashMap::new();
+
+    for style_var_to_keep in self.state.style_vars_to_keep.iter() {
+      let StyleVarsToKeep(var_name, namespace_name, _) = style_var_to_keep.as_ref();
+
+      match vars_to_keep.entry(var_name.clone()) {
+        Entry::Occupied(mut entry) => {
+          if let NonNullProps::Vec(vec) = entry.get_mut() {
+            if let NonNullProp::Id(id) = namespace_name {
+              vec.push(id.clone());
+            }
+          }
+        }
+        Entry::Vacant(entry) => {
+          let value = match namespace_name {
+            NonNullProp::Id(namespace_name) => NonNullProps::Vec(vec![namespace_name.clone()]),
+            NonNullProp::True => NonNullProps::True,
+          };
+          entry.insert(value);
+        }
+      }
+    }
+
+    for var_name in self.state.style_vars.values() {
+      if var_declarator.name != var_name.name {
+        continue;
+      };
+
+      let var_id = var_name.name.as_ident().map(|ident| ident.to_id());
+
+      let var_decl = self
+        .state
+        .top_level_expressions
+        .iter()
+        .find(|TopLevelExpression(_, _, id)| id.is_some() && *id == var_id);
+
+      if let Some(TopLevelExpression(kind, _, _)) = var_decl {
+        if TopLevelExpressionKind::Stmt == *kind {
+          if let Some(object) = var_declarator.init.as_deref_mut() {
+            if let Some(object) = object.as_mut_object() {
+              let namespaces_to_keep =
+                match vars_to_keep.get(&var_name.name.as_ident().unwrap().to_id()) {
+                  Some(e) => match e {
+                    NonNullProps::Vec(vec) => vec.clone(),
+                    NonNullProps::True => vec![],
+                  },
+                  None => vec![],
+                };
+
+              if !namespaces_to_keep.is_empty() {
+                object.props = self.retain_object_props(object, namespaces_to_keep, var_name);
+              }
+            }
+          }
+        }
+      }
+    }
   }
 
   fn retain_object_props(
-    &mut self,
+    &self,
     object: &mut ObjectLit,
     namespace_to_keep: Vec<Id>,
     var_name: &VarDeclarator,
@@ -0,0 +1,10 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import "./colors.stylex";
+import stylex from 'stylex';
+import { colors } from './colors.stylex';
+_inject2(".xe4fo6a{--xa513j:green;}", 0.5);
+export const theme = {
+    $$css: true,
+    xfv597z: "xe4fo6a"
+};
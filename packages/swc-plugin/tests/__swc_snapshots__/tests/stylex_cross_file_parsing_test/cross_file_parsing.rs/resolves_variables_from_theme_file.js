@@ -0,0 +1,14 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import "./colors.stylex";
+import stylex from 'stylex';
+import { colors } from './colors.stylex';
+_inject2(".x1qfnmnr{color:var(--xa513j)}", 3000);
+_inject2(".x16ovuyc{background-color:var(--x1giycrp)}", 3000);
+export const styles = {
+    root: {
+        color: "x1qfnmnr",
+        backgroundColor: "x16ovuyc",
+        $$css: true
+    }
+};
@@ -0,0 +1,19 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+import { radius } from 'design-tokens';
+_inject2(".xur7f20{border-radius:8px}", 2000);
+export const styles = {
+    root: {
+        borderRadius: "xur7f20",
+        borderStartStartRadius: null,
+        borderStartEndRadius: null,
+        borderEndStartRadius: null,
+        borderEndEndRadius: null,
+        borderTopLeftRadius: null,
+        borderTopRightRadius: null,
+        borderBottomLeftRadius: null,
+        borderBottomRightRadius: null,
+        $$css: true
+    }
+};
@@ -0,0 +1,21 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+import { colors, accentColor as accent } from './constants';
+_inject2(".xju2f9n{color:blue}", 3000);
+_inject2(".x1sbde0u{border-color:rgb(255,0,0)}", 2000);
+export const styles = {
+    root: {
+        color: "xju2f9n",
+        borderColor: "x1sbde0u",
+        borderInlineColor: null,
+        borderInlineStartColor: null,
+        borderLeftColor: null,
+        borderInlineEndColor: null,
+        borderRightColor: null,
+        borderBlockColor: null,
+        borderTopColor: null,
+        borderBottomColor: null,
+        $$css: true
+    }
+};
@@ -0,0 +1,32 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+import { spacing, breakpoints } from './constants';
+_inject2(".xe8ttls{padding:8px}", 1000);
+_inject2(".x1c5nz5h{margin:-4px}", 1000);
+_inject2(".xh8yej3{width:100%}", 4000);
+_inject2("@media (min-width: 1024px){.xdchjwh.xdchjwh{width:800px}}", 4200);
+export const styles = {
+    root: {
+        padding: "xe8ttls",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        margin: "x1c5nz5h",
+        marginInline: null,
+        marginInlineStart: null,
+        marginLeft: null,
+        marginInlineEnd: null,
+        marginRight: null,
+        marginBlock: null,
+        marginTop: null,
+        marginBottom: null,
+        width: "xh8yej3 xdchjwh",
+        $$css: true
+    }
+};
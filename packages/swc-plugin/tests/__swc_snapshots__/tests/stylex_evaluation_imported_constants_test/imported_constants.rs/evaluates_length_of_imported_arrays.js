@@ -0,0 +1,13 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+import { colors } from './constants';
+_inject2(".xhtitgo{z-index:2}", 3000);
+_inject2(".xgyuaek{flex-grow:2}", 3000);
+export const styles = {
+    root: {
+        zIndex: "xhtitgo",
+        flexGrow: "xgyuaek",
+        $$css: true
+    }
+};
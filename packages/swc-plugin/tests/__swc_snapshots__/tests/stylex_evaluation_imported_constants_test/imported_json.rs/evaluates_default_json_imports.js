@@ -0,0 +1,23 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+import tokens from './tokens.json';
+_inject2(".x1l9anji{color:#1877f2}", 3000);
+_inject2(".x12peec7{background-color:white}", 3000);
+_inject2(".xe8ttls{padding:8px}", 1000);
+export const styles = {
+    root: {
+        color: "x1l9anji",
+        backgroundColor: "x12peec7",
+        padding: "xe8ttls",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        $$css: true
+    }
+};
@@ -0,0 +1,21 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+import { radius, color as colors } from './tokens.json';
+_inject2(".x1kogg8i{border-radius:6px}", 2000);
+_inject2(".x1l9anji{color:#1877f2}", 3000);
+export const styles = {
+    root: {
+        borderRadius: "x1kogg8i",
+        borderStartStartRadius: null,
+        borderStartEndRadius: null,
+        borderEndStartRadius: null,
+        borderEndEndRadius: null,
+        borderTopLeftRadius: null,
+        borderTopRightRadius: null,
+        borderBottomLeftRadius: null,
+        borderBottomRightRadius: null,
+        color: "x1l9anji",
+        $$css: true
+    }
+};
@@ -0,0 +1,15 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+import card from './card.json';
+_inject2(".x1vjfegm{z-index:1}", 3000);
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".x1t391ir{background-color:blue}", 3000);
+export const styles = {
+    root: {
+        zIndex: "x1vjfegm",
+        color: "x1e2nbdu",
+        backgroundColor: "x1t391ir",
+        $$css: true
+    }
+};
@@ -0,0 +1,19 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+import { gutter } from 'SizeConstants';
+_inject2(".xc7ga6q{padding:12px}", 1000);
+export const styles = {
+    root: {
+        padding: "xc7ga6q",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        $$css: true
+    }
+};
@@ -0,0 +1,12 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import "../themes/colors.stylex";
+import stylex from 'stylex';
+import { colors } from '../themes/colors.stylex';
+_inject2(".x1wqvbj2{color:var(--xa7e7h)}", 3000);
+export const styles = {
+    root: {
+        color: "x1wqvbj2",
+        $$css: true
+    }
+};
@@ -0,0 +1,7 @@
+import stylex from 'stylex';
+export const styles = {
+    foo: {
+        color: "x1e2nbdu",
+        $$css: true
+    }
+};
@@ -0,0 +1,28 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+const spacing = 8 as const;
+const isCompact = false;
+_inject2(".x9otpla{margin-top:-8px}", 4000);
+_inject2(".xe8ttls{padding:8px}", 1000);
+_inject2(".x1kky2od{width:16px}", 4000);
+_inject2(".x1hc1fzr{opacity:1}", 3000);
+_inject2(".x1e2nbdu{color:red}", 3000);
+export const styles = {
+    root: {
+        marginTop: "x9otpla",
+        padding: "xe8ttls",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        width: "x1kky2od",
+        opacity: "x1hc1fzr",
+        color: "x1e2nbdu",
+        $$css: true
+    }
+};
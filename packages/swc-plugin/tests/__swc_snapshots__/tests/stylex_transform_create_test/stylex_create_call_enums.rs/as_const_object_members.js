@@ -0,0 +1,18 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+const Colors = {
+    primary: 'blue',
+    surface: {
+        base: 'white'
+    }
+} as const;
+_inject2(".xju2f9n{color:blue}", 3000);
+_inject2(".x12peec7{background-color:white}", 3000);
+export const styles = {
+    root: {
+        color: "xju2f9n",
+        backgroundColor: "x12peec7",
+        $$css: true
+    }
+};
@@ -0,0 +1,43 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+enum Size {
+    Xs,
+    Sm = 4,
+    Md,
+    Lg = Md * 2,
+    Xl
+}
+_inject2(".x1ghz6dp{margin:0}", 1000);
+_inject2(".xfawy5m{padding:4px}", 1000);
+_inject2(".x1nejdyq{gap:5px}", 2000);
+_inject2(".x1fsd2vl{width:10px}", 4000);
+_inject2(".x6w4g8m{height:11px}", 4000);
+export const styles = {
+    root: {
+        margin: "x1ghz6dp",
+        marginInline: null,
+        marginInlineStart: null,
+        marginLeft: null,
+        marginInlineEnd: null,
+        marginRight: null,
+        marginBlock: null,
+        marginTop: null,
+        marginBottom: null,
+        padding: "xfawy5m",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        gap: "x1nejdyq",
+        rowGap: null,
+        columnGap: null,
+        width: "x1fsd2vl",
+        height: "x6w4g8m",
+        $$css: true
+    }
+};
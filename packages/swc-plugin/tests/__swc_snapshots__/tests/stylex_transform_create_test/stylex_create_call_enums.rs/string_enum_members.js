@@ -0,0 +1,16 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+export const enum Color {
+    Primary = 'blue',
+    'On-Primary' = `white`
+}
+_inject2(".x1t391ir{background-color:blue}", 3000);
+_inject2(".x1awj2ng{color:white}", 3000);
+export const styles = {
+    root: {
+        backgroundColor: "x1t391ir",
+        color: "x1awj2ng",
+        $$css: true
+    }
+};
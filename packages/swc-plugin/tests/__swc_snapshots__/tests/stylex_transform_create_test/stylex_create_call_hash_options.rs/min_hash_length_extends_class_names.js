@@ -0,0 +1,12 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu192{color:red}", 3000);
+_inject2(".x1t391iro6j{background-color:blue}", 3000);
+export const styles = {
+    default: {
+        color: "x1e2nbdu192",
+        backgroundColor: "x1t391iro6j",
+        $$css: true
+    }
+};
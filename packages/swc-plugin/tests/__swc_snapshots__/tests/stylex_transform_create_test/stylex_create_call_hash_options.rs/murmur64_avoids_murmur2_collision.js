@@ -0,0 +1,15 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x9p579gq7fb8u{width:3261px}", 4000);
+_inject2(".x36j38x363wn5m{width:74839px}", 4000);
+export const styles = {
+    a: {
+        width: "x9p579gq7fb8u",
+        $$css: true
+    },
+    b: {
+        width: "x36j38x363wn5m",
+        $$css: true
+    }
+};
@@ -0,0 +1,41 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+const spacing = (n, unit = 'px')=>{
+    const base = 4;
+    if (n === 0) {
+        return 0;
+    }
+    const value = n * base;
+    return `${value}${unit}`;
+};
+const size = (name)=>name === 'small' ? spacing(2) : spacing(4, 'em');
+_inject2(".x1ghz6dp{margin:0}", 1000);
+_inject2(".xc7ga6q{padding:12px}", 1000);
+_inject2(".x1xc55vz{width:8px}", 4000);
+_inject2(".xpr1j0t{height:16em}", 4000);
+export const styles = {
+    root: {
+        margin: "x1ghz6dp",
+        marginInline: null,
+        marginInlineStart: null,
+        marginLeft: null,
+        marginInlineEnd: null,
+        marginRight: null,
+        marginBlock: null,
+        marginTop: null,
+        marginBottom: null,
+        padding: "xc7ga6q",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        width: "x1xc55vz",
+        height: "xpr1j0t",
+        $$css: true
+    }
+};
@@ -0,0 +1,18 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xxr9l41{margin:4px 8px}", 1000);
+export const styles = {
+    root: {
+        margin: "xxr9l41",
+        marginInline: null,
+        marginInlineStart: null,
+        marginLeft: null,
+        marginInlineEnd: null,
+        marginRight: null,
+        marginBlock: null,
+        marginTop: null,
+        marginBottom: null,
+        $$css: true
+    }
+};
@@ -0,0 +1,21 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+function rem(px) {
+    return px / 16 + 'rem';
+}
+_inject2(".xis3749{padding:1.5rem}", 1000);
+export const styles = {
+    root: {
+        padding: "xis3749",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        $$css: true
+    }
+};
@@ -0,0 +1,19 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+const base = 8;
+function scale(n) {
+    return n * base;
+}
+function gap(base) {
+    return scale(base) + 'px';
+}
+_inject2(".xou54vl{gap:16px}", 2000);
+export const styles = {
+    root: {
+        gap: "xou54vl",
+        rowGap: null,
+        columnGap: null,
+        $$css: true
+    }
+};
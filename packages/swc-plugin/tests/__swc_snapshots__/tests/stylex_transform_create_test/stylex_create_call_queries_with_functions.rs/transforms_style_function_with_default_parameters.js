@@ -0,0 +1,18 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x19dipnz{color:var(--color,revert)}", 3000);
+_inject2(".x17fnjtu{width:var(--width,revert)}", 4000);
+export const styles = {
+    default: (color = 'red', width = 4)=>[
+            {
+                color: "x19dipnz",
+                width: "x17fnjtu",
+                $$css: true
+            },
+            {
+                "--color": color != null ? color : "initial",
+                "--width": ((val)=>typeof val === "number" ? val + "px" : val != null ? val : "initial")(width)
+            }
+        ]
+};
@@ -0,0 +1,21 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x19dipnz{color:var(--color,revert)}", 3000);
+_inject2(".x1jwls1v{height:var(--height,revert)}", 4000);
+_inject2(".x1v67u4u{margin-top:var(--marginTop,revert)}", 4000);
+export const styles = {
+    default: ({ color, size = 4 }, [top])=>[
+            {
+                color: "x19dipnz",
+                height: "x1jwls1v",
+                marginTop: "x1v67u4u",
+                $$css: true
+            },
+            {
+                "--color": color != null ? color : "initial",
+                "--height": ((val)=>typeof val === "number" ? val + "px" : val != null ? val : "initial")(size),
+                "--marginTop": ((val)=>typeof val === "number" ? val + "px" : val != null ? val : "initial")(top)
+            }
+        ]
+};
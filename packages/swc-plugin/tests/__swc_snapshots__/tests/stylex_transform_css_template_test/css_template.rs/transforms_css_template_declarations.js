@@ -0,0 +1,14 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import { create } from '@stylexjs/stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2(".x1gslohp{margin-top:4px}", 4000);
+_inject2(".x1f3169t{--accent:blue}", 1);
+export const styles = {
+    root: {
+        backgroundColor: "xrkmrrc",
+        marginTop: "x1gslohp",
+        "--accent": "x1f3169t",
+        $$css: true
+    }
+};
@@ -0,0 +1,23 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".x17z2mba:hover{color:blue}", 3130);
+_inject2("@media (max-width: 600px){.xqgww65.xqgww65:hover{color:green}}", 3330);
+_inject2("@media (min-width: 800px){.xj2gzb2.xj2gzb2{color:yellow}}", 3200);
+_inject2("@media (min-width: 800px){.xa10tqj.xa10tqj{padding:8px}}", 1200);
+export const styles = {
+    root: {
+        color: "x1e2nbdu x17z2mba xqgww65 xj2gzb2",
+        padding: "xa10tqj",
+        paddingInline: "",
+        paddingStart: "",
+        paddingLeft: "",
+        paddingEnd: "",
+        paddingRight: "",
+        paddingBlock: "",
+        paddingTop: "",
+        paddingBottom: "",
+        $$css: true
+    }
+};
@@ -0,0 +1,34 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+const spacing = 8;
+_inject2(".x1tamke2{padding:16px}", 1000);
+_inject2(".x1n0khkq{color:rebeccapurple}", 3000);
+_inject2(".xe8ttls{padding:8px}", 1000);
+export const styles = {
+    base: {
+        padding: "x1tamke2",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        color: "x1n0khkq",
+        $$css: true
+    },
+    compact: {
+        padding: "xe8ttls",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        $$css: true
+    }
+};
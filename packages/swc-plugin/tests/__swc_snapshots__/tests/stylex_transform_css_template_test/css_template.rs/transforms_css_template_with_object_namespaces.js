@@ -0,0 +1,18 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".xju2f9n{color:blue}", 3000);
+export const styles = {
+    root: {
+        color: "x1e2nbdu",
+        $$css: true
+    },
+    other: {
+        color: "xju2f9n",
+        $$css: true
+    }
+};
+({
+    className: "xju2f9n"
+});
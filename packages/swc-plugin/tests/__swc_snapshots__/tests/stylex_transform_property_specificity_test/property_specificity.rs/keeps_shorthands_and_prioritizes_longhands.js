@@ -0,0 +1,18 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1oin6zd{margin:10px}", 1000);
+_inject2(".x1ok221b{margin-top:5px}", 4000);
+_inject2(".xdqdrvq{padding:4px 8px}", 1000);
+_inject2(".xyiysdx{padding-inline-start:2px}", 3000);
+_inject2(".x10a8y8t{inset:0}", 1000);
+export const styles = {
+    root: {
+        margin: "x1oin6zd",
+        marginTop: "x1ok221b",
+        padding: "xdqdrvq",
+        paddingInlineStart: "xyiysdx",
+        inset: "x10a8y8t",
+        $$css: true
+    }
+};
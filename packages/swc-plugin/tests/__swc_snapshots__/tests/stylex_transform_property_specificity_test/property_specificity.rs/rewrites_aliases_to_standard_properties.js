@@ -0,0 +1,16 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xuqyx9n{margin-inline:10px}", 2000);
+_inject2(".xifx94n{padding-block:5px}", 2000);
+_inject2(".xocqyrc{border-inline-color:red}", 2000);
+_inject2(".x5yr21d{height:100%}", 4000);
+export const styles = {
+    root: {
+        marginInline: "xuqyx9n",
+        paddingBlock: "xifx94n",
+        borderInlineColor: "xocqyrc",
+        height: "x5yr21d",
+        $$css: true
+    }
+};
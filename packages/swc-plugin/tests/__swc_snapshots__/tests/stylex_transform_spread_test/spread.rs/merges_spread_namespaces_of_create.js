@@ -0,0 +1,15 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".xfifm61{font-size:12px}", 3000);
+export const styles = {
+    root: {
+        color: "x1e2nbdu",
+        $$css: true
+    },
+    label: {
+        fontSize: "xfifm61",
+        $$css: true
+    }
+};
@@ -0,0 +1,14 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(":root{--xjrzwe6:blue;--x8vf0t4:4px;}", 0);
+export const vars = {
+    color: "var(--xjrzwe6)",
+    spacing: "var(--x8vf0t4)",
+    __themeName__: "xm1nzai"
+};
+_inject2(".x1lpipyo{--xjrzwe6:red;--x8vf0t4:8px;}", 0.5);
+export const theme = {
+    $$css: true,
+    xm1nzai: "x1lpipyo"
+};
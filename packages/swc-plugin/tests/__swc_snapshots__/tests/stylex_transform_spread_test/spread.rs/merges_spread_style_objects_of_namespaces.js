@@ -0,0 +1,58 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+const baseButton = {
+    color: 'blue',
+    padding: 4,
+    borderRadius: 2
+};
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".xfawy5m{padding:4px}", 1000);
+_inject2(".x1cum3z5{border-radius:2px}", 2000);
+_inject2(".xju2f9n{color:blue}", 3000);
+export const styles = {
+    primary: {
+        color: "x1e2nbdu",
+        padding: "xfawy5m",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        borderRadius: "x1cum3z5",
+        borderStartStartRadius: null,
+        borderStartEndRadius: null,
+        borderEndStartRadius: null,
+        borderEndEndRadius: null,
+        borderTopLeftRadius: null,
+        borderTopRightRadius: null,
+        borderBottomLeftRadius: null,
+        borderBottomRightRadius: null,
+        $$css: true
+    },
+    secondary: {
+        color: "xju2f9n",
+        padding: "xfawy5m",
+        paddingInline: null,
+        paddingStart: null,
+        paddingLeft: null,
+        paddingEnd: null,
+        paddingRight: null,
+        paddingBlock: null,
+        paddingTop: null,
+        paddingBottom: null,
+        borderRadius: "x1cum3z5",
+        borderStartStartRadius: null,
+        borderStartEndRadius: null,
+        borderEndStartRadius: null,
+        borderEndEndRadius: null,
+        borderTopLeftRadius: null,
+        borderTopRightRadius: null,
+        borderBottomLeftRadius: null,
+        borderBottomRightRadius: null,
+        $$css: true
+    }
+};
@@ -0,0 +1,11 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(":root{--xjrzwe6:red;--x8vf0t4:4px;--xvdvs4v:green;}", 0);
+_inject2("@media (prefers-color-scheme: dark){:root{--xvdvs4v:lime;}}", 0.1);
+export const vars = {
+    color: "var(--xjrzwe6)",
+    spacing: "var(--x8vf0t4)",
+    accent: "var(--xvdvs4v)",
+    __themeName__: "xm1nzai"
+};
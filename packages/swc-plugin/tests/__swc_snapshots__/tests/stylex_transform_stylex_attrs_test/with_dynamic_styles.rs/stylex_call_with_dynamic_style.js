@@ -0,0 +1,10 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2(".x17fnjtu{width:var(--width,revert)}", 4000);
+_inject2(".x19dipnz{color:var(--color,revert)}", 3000);
+({
+    class: "xrkmrrc x17fnjtu x19dipnz",
+    style: "--width:" + ((val)=>typeof val === "number" ? val + "px" : val != null ? val : "initial")(width) + ";--color:" + ('blue' != null ? 'blue' : "initial") + ";"
+});
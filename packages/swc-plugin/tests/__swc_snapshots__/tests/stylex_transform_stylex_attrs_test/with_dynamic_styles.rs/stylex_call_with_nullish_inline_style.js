@@ -0,0 +1,14 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+const styles = {
+    default: {
+        backgroundColor: "xrkmrrc",
+        $$css: true
+    }
+};
+stylex.attrs(styles.default, {
+    color: null,
+    backgroundColor: 'blue'
+});
@@ -0,0 +1,8 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+({
+    class: "FooBar__styles.default x1e2nbdu",
+    "data-style-src": "FooBar.react.js:4"
+});
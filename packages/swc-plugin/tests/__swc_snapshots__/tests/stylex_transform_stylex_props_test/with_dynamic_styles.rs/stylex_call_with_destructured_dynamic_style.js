@@ -0,0 +1,27 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x17fnjtu{width:var(--width,revert)}", 4000);
+_inject2(".x1jwls1v{height:var(--height,revert)}", 4000);
+_inject2(".x13to73x{min-width:var(--minWidth,revert)}", 4000);
+const styles = {
+    size: ({ width, height = 10 })=>{
+        const area = width * height;
+        return [
+            {
+                width: "x17fnjtu",
+                height: "x1jwls1v",
+                minWidth: "x13to73x",
+                $$css: true
+            },
+            {
+                "--width": ((val)=>typeof val === "number" ? val + "px" : val != null ? val : "initial")(width),
+                "--height": ((val)=>typeof val === "number" ? val + "px" : val != null ? val : "initial")(height),
+                "--minWidth": ((val)=>typeof val === "number" ? val + "px" : val != null ? val : "initial")(area)
+            }
+        ];
+    }
+};
+stylex.props(styles.size({
+    width: 20
+}));
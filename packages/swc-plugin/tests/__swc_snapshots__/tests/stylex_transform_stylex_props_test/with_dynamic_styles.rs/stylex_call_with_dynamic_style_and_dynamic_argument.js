@@ -0,0 +1,16 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x19dipnz{color:var(--color,revert)}", 3000);
+const styles = {
+    color: (color)=>[
+            {
+                color: "x19dipnz",
+                $$css: true
+            },
+            {
+                "--color": color != null ? color : "initial"
+            }
+        ]
+};
+stylex.props(styles.color(getColor()));
@@ -0,0 +1,13 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2(".x19dipnz{color:var(--color,revert)}", 3000);
+function Component({ color }) {
+    return <div {...{
+        className: "xrkmrrc x19dipnz",
+        style: {
+            "--color": color != null ? color : "initial"
+        }
+    }}/>;
+}
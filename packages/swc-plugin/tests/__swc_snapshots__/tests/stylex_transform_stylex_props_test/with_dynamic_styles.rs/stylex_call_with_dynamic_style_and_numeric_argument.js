@@ -0,0 +1,12 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x17fnjtu{width:var(--width,revert)}", 4000);
+_inject2(".x1jwls1v{height:var(--height,revert)}", 4000);
+({
+    className: "x17fnjtu x1jwls1v",
+    style: {
+        "--width": ((val)=>typeof val === "number" ? val + "px" : val != null ? val : "initial")(100),
+        "--height": ((val)=>typeof val === "number" ? val + "px" : val != null ? val : "initial")('50%')
+    }
+});
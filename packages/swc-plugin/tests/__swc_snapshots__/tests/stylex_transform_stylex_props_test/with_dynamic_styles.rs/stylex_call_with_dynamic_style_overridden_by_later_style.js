@@ -0,0 +1,11 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1u4uod0{opacity:var(--opacity,revert)}", 3000);
+_inject2(".xg01cxk{opacity:0}", 3000);
+({
+    className: "xg01cxk",
+    style: {
+        "--opacity": value != null ? value : "initial"
+    }
+});
@@ -0,0 +1,8 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".xju2f9n{color:blue}", 3000);
+({
+    className: "x1e2nbdu"
+});
@@ -0,0 +1,15 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+export const styles = {
+    default: {
+        "FooBar__styles.default": "FooBar__styles.default",
+        color: "x1e2nbdu",
+        $$css: "FooBar.react.js:4"
+    }
+};
+({
+    className: "FooBar__styles.default x1e2nbdu",
+    "data-style-src": "FooBar.react.js:4"
+});
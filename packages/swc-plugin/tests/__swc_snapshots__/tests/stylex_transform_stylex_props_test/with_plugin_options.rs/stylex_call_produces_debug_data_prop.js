@@ -0,0 +1,9 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".x1t391ir{background-color:blue}", 3000);
+({
+    className: "FooBar__styles.default x1e2nbdu FooBar__otherStyles.default x1t391ir",
+    "data-style-src": "FooBar.react.js:4; FooBar.react.js:9"
+});
@@ -0,0 +1,7 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+({
+    className: "FooBar-default-L4 x1e2nbdu"
+});
@@ -0,0 +1,21 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".a{color:red}", 3000);
+_inject2(".b{background-color:blue}", 3000);
+_inject2(".c{color:green}", 3000);
+const styles = {
+    default: {
+        color: "a",
+        backgroundColor: "b",
+        $$css: true
+    },
+    active: {
+        color: "c",
+        $$css: true
+    }
+};
+({
+    className: "b c"
+});
+stylex.props(styles.default, isActive && styles.active);
@@ -95,14 +95,18 @@ impl Fold for ArgsModuleTransformVisitor {
             })
             .collect(),
         ),
-        EvaluateResultValue::Callback(func) => func(vec![
-          Some(EvaluateResultValue::Expr(Box::new(number_to_expression(
-            2.0,
-          )))),
-          Some(EvaluateResultValue::Expr(Box::new(number_to_expression(
-            7.0,
-          )))),
-        ]),
+        EvaluateResultValue::Callback(func) => func(
+          vec![
+            Some(EvaluateResultValue::Expr(Box::new(number_to_expression(
+              2.0,
+            )))),
+            Some(EvaluateResultValue::Expr(Box::new(number_to_expression(
+              7.0,
+            )))),
+          ],
+          &mut self.state,
+        )
+        .expect("Failed to evaluate expression"),
         EvaluateResultValue::Map(map) => {
           let mut props = vec![];
 
@@ -87,14 +87,18 @@ impl Fold for EvaluationModuleTransformVisitor {
             })
             .collect(),
         }),
-        EvaluateResultValue::Callback(func) => func(vec![
-          Some(EvaluateResultValue::Expr(Box::new(number_to_expression(
-            2.0,
-          )))),
-          Some(EvaluateResultValue::Expr(Box::new(number_to_expression(
-            7.0,
-          )))),
-        ]),
+        EvaluateResultValue::Callback(func) => func(
+          vec![
+            Some(EvaluateResultValue::Expr(Box::new(number_to_expression(
+              2.0,
+            )))),
+            Some(EvaluateResultValue::Expr(Box::new(number_to_expression(
+              7.0,
+            )))),
+          ],
+          &mut self.state,
+        )
+        .expect("Failed to evaluate expression"),
         _ => panic!("Failed to evaluate expression"),
       },
       None => panic!("Failed to evaluate expression"),
@@ -0,0 +1,147 @@
+use swc_core::ecma::{
+  parser::{Syntax, TsConfig},
+  transforms::testing::test_transform,
+};
+
+use crate::evaluation::evaluation_module_transform::EvaluationModuleTransformVisitor;
+
+#[test]
+fn object_assign() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = Object.assign({}, {a: 1, b: 2}, {b: 3, c: 4});
+        "#,
+    r#"
+            ({
+                a: 1,
+                b: 3,
+                c: 4
+            });
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn array_methods() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = [1, 2, 3].reduce((sum, x) => sum + x, 10);
+            const x = [1, 2, 3].some(x => x > 2);
+            const x = [1, 2, 3].every(x => x > 2);
+            const x = [1, 2, 3].find(x => x > 1);
+            const x = [[1, 2], [3, [4]]].flat();
+            const x = ["a", "b"].includes("b");
+        "#,
+    r#"
+            16;
+            true;
+            false;
+            2;
+            [1, 2, 3, [4]];
+            true;
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn string_methods() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = "button".slice(1, -1);
+            const x = "a-b-c".replace("-", "_");
+            const x = "5".padStart(3, "0");
+            const x = "red".toUpperCase();
+            const x = "a b c".split(" ");
+            const x = "  large ".trim();
+        "#,
+    r#"
+            "utto";
+            "a_b-c";
+            "005";
+            "RED";
+            ["a", "b", "c"];
+            "large";
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn math_functions() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = Math.abs(1 - 3);
+            const x = Math.sqrt(16);
+            const x = Math.min(Math.max(12, 0), 8);
+            const x = Math.max(...[1, 5, 3]);
+        "#,
+    r#"
+            2;
+            4;
+            8;
+            5;
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn number_to_fixed() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = (1 / 3).toFixed(2);
+            const x = (2.5).toFixed(0);
+            const x = (1.005).toFixed(2);
+        "#,
+    r#"
+            "0.33";
+            "3";
+            "1.00";
+        "#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Evaluation built-in functions not supported - Array.prototype.flatMap")]
+fn unsupported_built_in_method() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = [1, 2].flatMap(x => [x, x]);
+        "#,
+    r#""#,
+    false,
+  )
+}
@@ -61,6 +61,26 @@ fn array_map() {
   )
 }
 
+#[test]
+fn array_map_with_repeated_callback() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = [1, 2].map(x => x * 2);
+            const y = [3, 4].map(x => x * 2);
+        "#,
+    r#"
+            [2, 4];
+            [6, 8];
+        "#,
+    false,
+  )
+}
+
 #[test]
 fn array_filter() {
   test_transform(
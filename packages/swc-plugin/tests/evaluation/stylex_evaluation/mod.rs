@@ -1,3 +1,4 @@
+mod evaluating_built_in_functions;
 mod evaluating_function_expressions;
 mod stylex_evaluation_common;
 mod stylex_fn_obj_evaluation;
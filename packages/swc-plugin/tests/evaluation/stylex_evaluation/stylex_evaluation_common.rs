@@ -118,7 +118,7 @@ fn evaluates_objects_with_spreads() {
     r#"
             ({ name: "Name", hero: true, age: 43 });
             ({ name: "StyleX", age: 43 });
-            ({ age: 43 , name: "StyleX", });
+            ({ name: "StyleX", age: 43 });
         "#,
     false,
   )
@@ -358,3 +358,99 @@ fn evaluates_custom_functions_that_take_paths() {
     false,
   )
 }
+
+#[test]
+fn evaluates_typescript_casts() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = 4 as number;
+            const x = "red" satisfies string;
+            const x = {color: "red"} as const;
+            const x = [1, 2] as const;
+        "#,
+    r#"
+            4;
+            "red";
+            ({color: "red"});
+            [1, 2];
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_sequences_and_conditionals() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = (1, 2, 3);
+            const x = true ? 4 : 8;
+            const x = 0 ? 4 : 8;
+            const x = "" ? "a" : "b";
+            const x = null ? "a" : "b";
+            const x = 1 + 1 === 2 ? "a" : "b";
+            const x = {} ? "a" : "b";
+        "#,
+    r#"
+            3;
+            4;
+            8;
+            "b";
+            "b";
+            "a";
+            "a";
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_unary_expressions() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            const x = -4;
+            const x = -(2 * 3);
+            const x = +"12";
+            const x = +true;
+            const x = !0;
+            const x = !"red";
+            const x = ~5;
+            const x = typeof 4;
+            const x = typeof "red";
+            const x = typeof null;
+            const x = typeof [];
+            const x = typeof (() => 1);
+            const x = void 0;
+        "#,
+    r#"
+            -4;
+            -6;
+            12;
+            1;
+            true;
+            false;
+            -6;
+            "number";
+            "string";
+            "object";
+            "object";
+            "function";
+            undefined;
+        "#,
+    false,
+  )
+}
@@ -1,7 +1,11 @@
 mod evaluation;
+mod stylex_cross_file_parsing_test;
+mod stylex_evaluation_imported_constants_test;
+mod stylex_haste_module_resolution_test;
 mod stylex_metadata_test;
 mod stylex_transform_call_test;
 mod stylex_transform_create_test;
+mod stylex_transform_css_template_test;
 mod stylex_transform_define_vars_test;
 mod stylex_transform_import_test;
 mod stylex_transform_legacy_shorthands;
@@ -9,6 +13,8 @@ mod stylex_transform_logical_properties_test;
 mod stylex_transform_logical_values_test;
 mod stylex_transform_override_vars_test;
 mod stylex_transform_polyfills_test;
+mod stylex_transform_property_specificity_test;
+mod stylex_transform_spread_test;
 mod stylex_transform_stylex_attrs_test;
 mod stylex_transform_stylex_create_theme_test;
 mod stylex_transform_stylex_keyframes_test;
@@ -20,6 +26,7 @@ mod stylex_validation_create_theme_test;
 mod stylex_validation_custom_properties_test;
 mod stylex_validation_declarations_test;
 mod stylex_validation_define_vars_test;
+mod stylex_validation_diagnostics_test;
 mod stylex_validation_import_test;
 mod stylex_validation_keyframes_test;
 mod stylex_validation_regular_css;
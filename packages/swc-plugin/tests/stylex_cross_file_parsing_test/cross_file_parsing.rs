@@ -0,0 +1,272 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Parser, StringInput, Syntax, TsConfig},
+    transforms::testing::{test, test_transform},
+    visit::FoldWith,
+  },
+  plugin::proxies::PluginCommentsProxy,
+};
+
+use crate::utils::transform::stringify_js;
+
+fn fixtures_dir() -> String {
+  format!(
+    "{}/tests/stylex_cross_file_parsing_test/fixtures",
+    env!("CARGO_MANIFEST_DIR")
+  )
+}
+
+fn plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real(format!("{}/Component.js", fixtures_dir()).into()),
+  }
+}
+
+fn cross_file_parsing_options() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    unstable_module_resolution: Some(StyleXOptions::get_cross_file_parsing_module_resolution(
+      Some(fixtures_dir()),
+    )),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &plugin_pass(),
+    Some(&mut cross_file_parsing_options())
+  ),
+  resolves_variables_from_theme_file,
+  r#"
+    import stylex from 'stylex';
+    import { colors } from './colors.stylex';
+
+    export const styles = stylex.create({
+      root: {
+        color: colors.primary,
+        backgroundColor: colors.secondary,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &plugin_pass(),
+    Some(&mut cross_file_parsing_options())
+  ),
+  overrides_variables_from_theme_file,
+  r#"
+    import stylex from 'stylex';
+    import { colors } from './colors.stylex';
+
+    export const theme = stylex.createTheme(colors, {
+      primary: 'green',
+    });
+  "#
+);
+
+fn transform_with_prefix(class_name_prefix: &str) -> String {
+  stringify_js(
+    r#"
+      import stylex from 'stylex';
+      import { colors } from './colors.stylex';
+
+      export const styles = stylex.create({
+        root: {
+          color: colors.primary,
+        },
+      });
+    "#,
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &plugin_pass(),
+        Some(&mut StyleXOptionsParams {
+          class_name_prefix: Some(class_name_prefix.to_string()),
+          ..cross_file_parsing_options()
+        }),
+      )
+    },
+  )
+}
+
+#[test]
+fn compiles_theme_file_with_options_of_importing_file() {
+  let default_prefix = transform_with_prefix("x");
+  let custom_prefix = transform_with_prefix("y");
+
+  assert!(
+    default_prefix.contains("color:var(--x"),
+    "{}",
+    default_prefix
+  );
+  assert!(custom_prefix.contains("color:var(--y"), "{}", custom_prefix);
+}
+
+#[test]
+#[should_panic(
+  expected = "Variable is not defined by stylex.defineVars() in the imported theme file: `tertiary`"
+)]
+fn undefined_variable_of_theme_file() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &plugin_pass(),
+        Some(&mut cross_file_parsing_options()),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      import { colors } from './colors.stylex';
+
+      export const styles = stylex.create({
+        root: {
+          color: colors.tertiary,
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Variable is not defined by stylex.defineVars() in the imported theme file: `tertiary`"
+)]
+fn undefined_variable_override_of_theme_file() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &plugin_pass(),
+        Some(&mut cross_file_parsing_options()),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      import { colors } from './colors.stylex';
+
+      export const theme = stylex.createTheme(colors, {
+        tertiary: 'green',
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Imported theme variables are not exported with stylex.defineVars(): `spacing`"
+)]
+fn import_of_non_theme_export() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &plugin_pass(),
+        Some(&mut cross_file_parsing_options()),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      import { spacing } from './colors.stylex';
+
+      export const styles = stylex.create({
+        root: {
+          padding: spacing.small,
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+fn reports_undefined_variable_override_at_key() {
+  let diagnostics = testing::run_test(false, |cm, _| {
+    let fm = cm.new_source_file(
+      FileName::Real(format!("{}/Component.js", fixtures_dir()).into()),
+      r#"import stylex from 'stylex';
+import { colors } from './colors.stylex';
+export const theme = stylex.createTheme(colors, {
+  primary: 'blue',
+  tertiary: 'green',
+});
+"#
+      .into(),
+    );
+
+    let module = Parser::new(
+      Syntax::Typescript(TsConfig {
+        tsx: true,
+        ..Default::default()
+      }),
+      StringInput::from(&*fm),
+      None,
+    )
+    .parse_module()
+    .expect("Failed to parse input");
+
+    let transformed = catch_unwind(AssertUnwindSafe(|| {
+      module.fold_with(&mut ModuleTransformVisitor::new_test_styles(
+        PluginCommentsProxy,
+        &plugin_pass(),
+        Some(&mut cross_file_parsing_options()),
+      ))
+    }));
+
+    assert!(transformed.is_err(), "Transform should fail");
+
+    Err::<(), ()>(())
+  })
+  .expect_err("Diagnostics should be emitted")
+  .to_string();
+
+  assert!(
+    diagnostics.contains(" 5 |   tertiary: 'green',\n   :   ^^^^^^^^^^^^^^^^^"),
+    "{}",
+    diagnostics
+  );
+}
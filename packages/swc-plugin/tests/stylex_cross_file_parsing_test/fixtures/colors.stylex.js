@@ -0,0 +1,10 @@
+import * as stylex from '@stylexjs/stylex';
+
+const primary = 'red';
+
+export const colors = stylex.defineVars({
+  primary,
+  secondary: 'blue',
+});
+
+export const spacing = 4;
@@ -0,0 +1,22 @@
+const base = 4;
+
+export const spacing = {
+  small: base,
+  medium: base * 2,
+  large: -base,
+} as const;
+
+export const breakpoints = {
+  mobile: '@media (max-width: 640px)',
+  desktop: '@media (min-width: 1024px)',
+};
+
+export const colors = ['red', 'blue'];
+
+const accent = `rgb(${255}, 0, 0)`;
+
+export { accent as accentColor };
+
+export let mutable = 'red';
+
+export const dynamic = getColor();
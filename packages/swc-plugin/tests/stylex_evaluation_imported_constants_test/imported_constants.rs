@@ -0,0 +1,224 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsConfig},
+    transforms::testing::{test, test_transform},
+  },
+};
+
+fn plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real(
+      format!(
+        "{}/tests/stylex_evaluation_imported_constants_test/fixtures/Component.js",
+        env!("CARGO_MANIFEST_DIR")
+      )
+      .into(),
+    ),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+  evaluates_imported_objects,
+  r#"
+    import stylex from 'stylex';
+    import { spacing, breakpoints } from './constants';
+
+    export const styles = stylex.create({
+      root: {
+        padding: spacing.medium,
+        margin: spacing.large,
+        width: {
+          default: '100%',
+          [breakpoints.desktop]: 800,
+        },
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+  evaluates_imported_arrays_and_renamed_exports,
+  r#"
+    import stylex from 'stylex';
+    import { colors, accentColor as accent } from './constants';
+
+    export const styles = stylex.create({
+      root: {
+        color: colors[1],
+        borderColor: accent,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+  evaluates_length_of_imported_arrays,
+  r#"
+    import stylex from 'stylex';
+    import { colors } from './constants';
+
+    export const styles = stylex.create({
+      root: {
+        zIndex: colors.length,
+        flexGrow: colors['length'],
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+  evaluates_constants_from_packages,
+  r#"
+    import stylex from 'stylex';
+    import { radius } from 'design-tokens';
+
+    export const styles = stylex.create({
+      root: {
+        borderRadius: radius,
+      },
+    });
+  "#
+);
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn rejects_non_const_exports() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+    r#"
+      import stylex from 'stylex';
+      import { mutable } from './constants';
+
+      export const styles = stylex.create({
+        root: {
+          color: mutable,
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn rejects_dynamic_exports() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+    r#"
+      import stylex from 'stylex';
+      import { dynamic } from './constants';
+
+      export const styles = stylex.create({
+        root: {
+          color: dynamic,
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn rejects_negative_array_index() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+    r#"
+      import stylex from 'stylex';
+      import { colors } from './constants';
+
+      export const styles = stylex.create({
+        root: {
+          color: colors[-1],
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn rejects_fractional_array_index() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+    r#"
+      import stylex from 'stylex';
+      import { colors } from './constants';
+
+      export const styles = stylex.create({
+        root: {
+          color: colors[0.5],
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn rejects_out_of_range_array_index() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+    r#"
+      import stylex from 'stylex';
+      import { colors } from './constants';
+
+      export const styles = stylex.create({
+        root: {
+          color: colors[2],
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
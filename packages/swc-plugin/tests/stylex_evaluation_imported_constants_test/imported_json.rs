@@ -0,0 +1,103 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsConfig},
+    transforms::testing::{test, test_transform},
+  },
+};
+
+fn plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real(
+      format!(
+        "{}/tests/stylex_evaluation_imported_constants_test/fixtures/Component.js",
+        env!("CARGO_MANIFEST_DIR")
+      )
+      .into(),
+    ),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+  evaluates_default_json_imports,
+  r#"
+    import stylex from 'stylex';
+    import tokens from './tokens.json';
+
+    export const styles = stylex.create({
+      root: {
+        color: tokens.color.primary,
+        backgroundColor: tokens.color['on-primary'],
+        padding: tokens.space[2],
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+  evaluates_named_json_imports,
+  r#"
+    import stylex from 'stylex';
+    import { radius, color as colors } from './tokens.json';
+
+    export const styles = stylex.create({
+      root: {
+        borderRadius: radius,
+        color: colors.primary,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+  keeps_key_order_of_json_objects,
+  r#"
+    import stylex from 'stylex';
+    import card from './card.json';
+
+    export const styles = stylex.create({
+      root: card,
+    });
+  "#
+);
+
+#[test]
+#[should_panic(expected = "Imported JSON module could not be parsed:")]
+fn reports_invalid_json_modules() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+    r#"
+      import stylex from 'stylex';
+      import { color } from './invalid.json';
+
+      export const styles = stylex.create({
+        root: {
+          color,
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
@@ -0,0 +1,2 @@
+mod imported_constants;
+mod imported_json;
@@ -0,0 +1,5 @@
+/**
+ * @providesModule SizeConstants
+ */
+
+export const gutter = 12;
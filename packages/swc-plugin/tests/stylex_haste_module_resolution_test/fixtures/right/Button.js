@@ -0,0 +1 @@
+export const padding = 8;
@@ -0,0 +1,221 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Parser, StringInput, Syntax, TsConfig},
+    transforms::testing::{test, test_transform},
+    visit::FoldWith,
+  },
+  plugin::proxies::PluginCommentsProxy,
+};
+
+fn fixtures_dir() -> String {
+  format!(
+    "{}/tests/stylex_haste_module_resolution_test/fixtures",
+    env!("CARGO_MANIFEST_DIR")
+  )
+}
+
+fn plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real(format!("{}/components/Component.js", fixtures_dir()).into()),
+  }
+}
+
+fn haste_options() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(Some(
+      fixtures_dir(),
+    ))),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &plugin_pass(),
+    Some(&mut haste_options())
+  ),
+  resolves_theme_file_by_haste_name,
+  r#"
+    import stylex from 'stylex';
+    import { colors } from 'colors.stylex';
+
+    export const styles = stylex.create({
+      root: {
+        color: colors.primary,
+        backgroundColor: colors.secondary,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &plugin_pass(),
+    Some(&mut haste_options())
+  ),
+  resolves_provided_module_constants,
+  r#"
+    import stylex from 'stylex';
+    import { gutter } from 'SizeConstants';
+
+    export const styles = stylex.create({
+      root: {
+        padding: gutter,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &plugin_pass(),
+    Some(&mut haste_options())
+  ),
+  resolves_relative_theme_file,
+  r#"
+    import stylex from 'stylex';
+    import { colors } from '../themes/colors.stylex';
+
+    export const styles = stylex.create({
+      root: {
+        color: colors.primary,
+      },
+    });
+  "#
+);
+
+#[test]
+#[should_panic(
+  expected = "Imported haste module is not provided by any file in the root directory: \"spacing.stylex\""
+)]
+fn unknown_haste_theme_module() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &plugin_pass(),
+        Some(&mut haste_options()),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      import { spacing } from 'spacing.stylex';
+
+      export const styles = stylex.create({
+        root: {
+          padding: spacing.small,
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Imported haste module is provided by more than one file in the root directory: \"Button\""
+)]
+fn ambiguous_haste_module() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &plugin_pass(),
+        Some(&mut haste_options()),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      import { padding } from 'Button';
+
+      export const styles = stylex.create({
+        root: {
+          padding,
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+fn reports_ambiguous_haste_module_at_import() {
+  let diagnostics = testing::run_test(false, |cm, _| {
+    let fm = cm.new_source_file(
+      FileName::Real(format!("{}/components/Component.js", fixtures_dir()).into()),
+      r#"import stylex from 'stylex';
+import { padding } from 'Button';
+export const styles = stylex.create({ root: { padding } });
+"#
+      .into(),
+    );
+
+    let module = Parser::new(
+      Syntax::Typescript(TsConfig {
+        tsx: true,
+        ..Default::default()
+      }),
+      StringInput::from(&*fm),
+      None,
+    )
+    .parse_module()
+    .expect("Failed to parse input");
+
+    let transformed = catch_unwind(AssertUnwindSafe(|| {
+      module.fold_with(&mut ModuleTransformVisitor::new_test_styles(
+        PluginCommentsProxy,
+        &plugin_pass(),
+        Some(&mut haste_options()),
+      ))
+    }));
+
+    assert!(transformed.is_err(), "Transform should fail");
+
+    Err::<(), ()>(())
+  })
+  .expect_err("Diagnostics should be emitted")
+  .to_string();
+
+  assert!(
+    diagnostics
+      .contains(" 2 | import { padding } from 'Button';\n   :                         ^^^^^^^^"),
+    "{}",
+    diagnostics
+  );
+}
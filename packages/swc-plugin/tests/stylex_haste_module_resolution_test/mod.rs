@@ -0,0 +1 @@
+mod haste_module_resolution;
@@ -1 +1,4 @@
 mod stylex_metadata_common_test;
+mod stylex_metadata_minified_test;
+mod stylex_metadata_output_test;
+mod stylex_metadata_source_test;
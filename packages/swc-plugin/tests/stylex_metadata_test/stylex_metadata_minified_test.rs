@@ -0,0 +1,140 @@
+use std::{env, fs, path::Path};
+
+use stylex_swc_plugin::{
+  assign_minified_class_names,
+  shared::structures::{
+    meta_data::MetaData, plugin_pass::PluginPass, stylex_options::StyleXOptionsParams,
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{sync::Lrc, FileName, SourceMap},
+  ecma::{
+    parser::{Parser, StringInput, Syntax, TsConfig},
+    visit::VisitMutWith,
+  },
+  plugin::proxies::PluginCommentsProxy,
+};
+
+fn options(manifest_path: &Path) -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    minify_class_names: Some(true),
+    class_name_manifest: Some(manifest_path.to_str().unwrap().to_string()),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+fn transform(input: &str, file_name: &str, manifest_path: &Path) -> Vec<MetaData> {
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Real(file_name.into()), input.into());
+
+  let mut module = Parser::new(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    StringInput::from(&*fm),
+    None,
+  )
+  .parse_module()
+  .expect("Failed to parse input");
+
+  let mut visitor = ModuleTransformVisitor::new_test(
+    PluginCommentsProxy,
+    &PluginPass {
+      cwd: Some("/app".into()),
+      filename: FileName::Real(file_name.into()),
+    },
+    Some(&mut options(manifest_path)),
+  );
+
+  module.visit_mut_with(&mut visitor);
+
+  visitor.get_metadata()
+}
+
+fn class_names(metadata: &[MetaData]) -> Vec<&str> {
+  metadata.iter().map(|rule| rule.get_class_name()).collect()
+}
+
+#[test]
+fn shares_minified_class_names_between_files() {
+  let manifest_path = env::temp_dir()
+    .join("stylex_metadata_minified_test")
+    .join("class-names.json");
+
+  let _ = fs::remove_file(&manifest_path);
+
+  assign_minified_class_names(
+    &options(&manifest_path),
+    ["x1e2nbdu", "x1ycjhwn", "xju2f9n"],
+  );
+
+  let button = transform(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: { color: 'red', height: 5 },
+      });
+    "#,
+    "/app/src/Button.tsx",
+    &manifest_path,
+  );
+
+  let card = transform(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: { color: 'blue', height: 5 },
+      });
+    "#,
+    "/app/src/Card.tsx",
+    &manifest_path,
+  );
+
+  assert_eq!(class_names(&button), vec!["a", "b"]);
+  assert_eq!(class_names(&card), vec!["c", "b"]);
+  assert_eq!(button[0].get_css(), ".a{color:red}");
+
+  let manifest: serde_json::Value =
+    serde_json::from_str(&fs::read_to_string(&manifest_path).unwrap()).unwrap();
+
+  assert_eq!(
+    manifest,
+    serde_json::json!({
+      "nextIndex": 3,
+      "classNames": {
+        "x1e2nbdu": "a",
+        "x1ycjhwn": "b",
+        "xju2f9n": "c",
+      },
+    })
+  );
+
+  fs::remove_file(&manifest_path).unwrap();
+}
+
+#[test]
+#[should_panic(
+  expected = "Class name has no minified name, assign the class names of every file with `assign_minified_class_names` before transforming them: \"xju2f9n\""
+)]
+fn rejects_class_names_without_minified_name() {
+  let manifest_path = env::temp_dir()
+    .join("stylex_metadata_minified_missing_test")
+    .join("class-names.json");
+
+  let _ = fs::remove_file(&manifest_path);
+
+  assign_minified_class_names(&options(&manifest_path), ["x1e2nbdu"]);
+
+  transform(
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: { color: 'blue' },
+      });
+    "#,
+    "/app/src/Card.tsx",
+    &manifest_path,
+  );
+}
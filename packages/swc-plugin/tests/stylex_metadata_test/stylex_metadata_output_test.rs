@@ -0,0 +1,150 @@
+use std::{env, fs, path::PathBuf};
+
+use stylex_swc_plugin::{
+  shared::structures::{
+    meta_data::{MetaDataFile, METADATA_VERSION},
+    plugin_pass::PluginPass,
+    stylex_options::{MetadataOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{sync::Lrc, FileName, SourceMap},
+  ecma::{
+    parser::{Parser, StringInput, Syntax, TsConfig},
+    transforms::testing::test,
+    visit::FoldWith,
+  },
+  plugin::proxies::PluginCommentsProxy,
+};
+
+const INPUT: &str = r#"
+  import stylex from 'stylex';
+  export const styles = stylex.create({
+    foo: {
+      color: 'red',
+      height: 5,
+    },
+  });
+"#;
+
+fn transform_with_metadata_options(cwd: &PathBuf, filename: PathBuf, metadata: MetadataOptions) {
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Real(filename.clone()), INPUT.into());
+
+  let module = Parser::new(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    StringInput::from(&*fm),
+    None,
+  )
+  .parse_module()
+  .expect("Failed to parse input");
+
+  module.fold_with(&mut ModuleTransformVisitor::new_test(
+    PluginCommentsProxy,
+    &PluginPass {
+      cwd: Some(cwd.clone()),
+      filename: FileName::Real(filename),
+    },
+    Some(&mut StyleXOptionsParams {
+      metadata: Some(metadata),
+      ..StyleXOptionsParams::default()
+    }),
+  ));
+}
+
+fn temp_project_dir(name: &str) -> PathBuf {
+  let dir = env::temp_dir().join(format!("stylex_metadata_{}_{}", name, std::process::id()));
+
+  let _ = fs::remove_dir_all(&dir);
+  fs::create_dir_all(dir.join("src")).unwrap();
+
+  dir
+}
+
+fn read_metadata_file(path: PathBuf) -> MetaDataFile {
+  serde_json::from_str(&fs::read_to_string(path).expect("Metadata file should be written"))
+    .expect("Metadata file should be valid")
+}
+
+#[test]
+fn writes_sidecar_metadata_file() {
+  let cwd = temp_project_dir("sidecar");
+  let filename = cwd.join("src/Button.tsx");
+
+  transform_with_metadata_options(
+    &cwd,
+    filename.clone(),
+    MetadataOptions {
+      sidecar: Some(true),
+      ..Default::default()
+    },
+  );
+
+  let metadata = read_metadata_file(cwd.join("src/Button.tsx.stylex.json"));
+
+  assert_eq!(metadata.version, METADATA_VERSION);
+  assert_eq!(metadata.file_path, filename.to_str().unwrap());
+  assert_eq!(metadata.rules.len(), 2);
+  assert_eq!(metadata.rules[0].class_name, "x1e2nbdu");
+  assert_eq!(metadata.rules[0].ltr, ".x1e2nbdu{color:red}");
+  assert_eq!(metadata.rules[0].rtl, None);
+  assert_eq!(metadata.rules[0].priority, 3000.0);
+  assert_eq!(metadata.rules[1].class_name, "x1ycjhwn");
+
+  fs::remove_dir_all(cwd).unwrap();
+}
+
+#[test]
+fn writes_metadata_file_into_output_dir() {
+  let cwd = temp_project_dir("output_dir");
+
+  transform_with_metadata_options(
+    &cwd,
+    cwd.join("src/Button.tsx"),
+    MetadataOptions {
+      output_dir: Some(".stylex".to_string()),
+      ..Default::default()
+    },
+  );
+
+  assert!(!cwd.join("src/Button.tsx.stylex.json").exists());
+
+  let metadata = read_metadata_file(cwd.join(".stylex/src/Button.tsx.stylex.json"));
+
+  assert_eq!(metadata.rules.len(), 2);
+
+  fs::remove_dir_all(cwd).unwrap();
+}
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    ModuleTransformVisitor::new_test(
+      tr.comments.clone(),
+      &PluginPass::default(),
+      Some(&mut StyleXOptionsParams {
+        metadata: Some(MetadataOptions {
+          comment: Some(false),
+          ..Default::default()
+        }),
+        ..StyleXOptionsParams::default()
+      }),
+    )
+  },
+  stylex_metadata_comment_is_omitted,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      foo: {
+        color: 'red',
+      },
+    });
+  "#
+);
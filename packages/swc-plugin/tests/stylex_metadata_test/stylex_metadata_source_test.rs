@@ -0,0 +1,115 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    meta_data::MetaData, plugin_pass::PluginPass, source_location::SourceLocation,
+    stylex_options::StyleXOptionsParams,
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::{sync::Lrc, FileName, SourceMap},
+  ecma::{
+    parser::{Parser, StringInput, Syntax, TsConfig},
+    visit::VisitMutWith,
+  },
+  plugin::proxies::PluginCommentsProxy,
+};
+
+const INPUT: &str = r#"
+  import stylex from 'stylex';
+  export const styles = stylex.create({
+    foo: {
+      color: 'red',
+      height: 5,
+    },
+  });
+  const fade = stylex.keyframes({ from: { opacity: 0 } });
+"#;
+
+fn transform(with_source_map: bool) -> Vec<MetaData> {
+  let cm: Lrc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Real("/app/src/Button.tsx".into()), INPUT.into());
+
+  let mut module = Parser::new(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    StringInput::from(&*fm),
+    None,
+  )
+  .parse_module()
+  .expect("Failed to parse input");
+
+  let mut visitor = ModuleTransformVisitor::new_test(
+    PluginCommentsProxy,
+    &PluginPass {
+      cwd: Some("/app".into()),
+      filename: FileName::Real("/app/src/Button.tsx".into()),
+    },
+    Some(&mut StyleXOptionsParams::default()),
+  );
+
+  if with_source_map {
+    visitor = visitor.with_source_map(cm);
+  }
+
+  module.visit_mut_with(&mut visitor);
+
+  visitor.get_metadata()
+}
+
+fn source_of<'a>(metadata: &'a [MetaData], class_name: &str) -> Option<&'a SourceLocation> {
+  metadata
+    .iter()
+    .find(|rule| rule.get_class_name() == class_name)
+    .and_then(|rule| rule.get_source())
+}
+
+#[test]
+fn records_location_of_each_style_property() {
+  let metadata = transform(true);
+
+  assert_eq!(
+    source_of(&metadata, "x1e2nbdu"),
+    Some(&SourceLocation {
+      file: "src/Button.tsx".to_string(),
+      line: 5,
+      column: 6,
+    })
+  );
+  assert_eq!(
+    source_of(&metadata, "x1ycjhwn"),
+    Some(&SourceLocation {
+      file: "src/Button.tsx".to_string(),
+      line: 6,
+      column: 6,
+    })
+  );
+}
+
+#[test]
+fn records_location_of_the_call_for_keyframes() {
+  let metadata = transform(true);
+
+  let keyframes = metadata
+    .iter()
+    .find(|rule| rule.get_css().starts_with("@keyframes"))
+    .expect("Keyframes should be registered");
+
+  assert_eq!(
+    keyframes.get_source(),
+    Some(&SourceLocation {
+      file: "src/Button.tsx".to_string(),
+      line: 9,
+      column: 15,
+    })
+  );
+}
+
+#[test]
+fn skips_locations_without_source_map() {
+  let metadata = transform(false);
+
+  assert!(!metadata.is_empty());
+  assert!(metadata.iter().all(|rule| rule.get_source().is_none()));
+}
@@ -1,4 +1,7 @@
 mod stylex_create_call;
+mod stylex_create_call_enums;
+mod stylex_create_call_hash_options;
+mod stylex_create_call_local_functions;
 mod stylex_create_call_pseudo_classes;
 mod stylex_create_call_pseudo_classes_within_properties;
 mod stylex_create_call_pseudo_elements;
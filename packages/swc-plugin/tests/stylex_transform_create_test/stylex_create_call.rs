@@ -693,3 +693,31 @@ test!(
     });
     "#
 );
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_typescript_casts_conditionals_and_unary_values,
+  r#"
+    import stylex from 'stylex';
+    const spacing = 8 as const;
+    const isCompact = false;
+    const sizes = { small: 4, large: 16 } satisfies Record<string, number>;
+    export const styles = stylex.create({
+      root: {
+        marginTop: -spacing,
+        padding: isCompact ? 4 : spacing,
+        width: -spacing < 0 ? sizes.large : 0,
+        opacity: !isCompact ? 1 : 0.5,
+        color: 'red' as const,
+      },
+    });
+    "#
+);
@@ -0,0 +1,77 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsConfig},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  numeric_enum_members,
+  r#"
+    import stylex from 'stylex';
+    enum Size {
+      Xs,
+      Sm = 4,
+      Md,
+      Lg = Md * 2,
+      Xl,
+    }
+    export const styles = stylex.create({
+      root: {
+        margin: Size.Xs,
+        padding: Size.Sm,
+        gap: Size.Md,
+        width: Size.Lg,
+        height: Size.Xl,
+      }
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  string_enum_members,
+  r#"
+    import stylex from 'stylex';
+    export const enum Color {
+      Primary = 'blue',
+      'On-Primary' = `white`,
+    }
+    export const styles = stylex.create({
+      root: {
+        backgroundColor: Color.Primary,
+        color: Color['On-Primary'],
+      }
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  as_const_object_members,
+  r#"
+    import stylex from 'stylex';
+    const Colors = {
+      primary: 'blue',
+      surface: { base: 'white' },
+    } as const;
+    export const styles = stylex.create({
+      root: {
+        color: Colors.primary,
+        backgroundColor: Colors.surface.base,
+      }
+    });
+  "#
+);
@@ -0,0 +1,82 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{HashAlgorithm, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsConfig},
+  transforms::testing::{test, test_transform},
+};
+
+#[test]
+#[should_panic(
+  expected = "Class name hash collision, use `hashAlgorithm: \"murmur64\"` or a larger `minHashLength`: \"x1xzhxgu\" is generated by both `a.width` (UnknownFile) and `b.width` (UnknownFile)."
+)]
+fn colliding_class_names_are_reported() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        a: { width: '3261px' },
+        b: { width: '74839px' }
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      hash_algorithm: Some(HashAlgorithm::Murmur64),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  murmur64_avoids_murmur2_collision,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      a: { width: '3261px' },
+      b: { width: '74839px' }
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      min_hash_length: Some(10),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  min_hash_length_extends_class_names,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      default: {
+        color: 'red',
+        backgroundColor: 'blue'
+      }
+    });
+  "#
+);
@@ -0,0 +1,143 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsConfig},
+  transforms::testing::{test, test_transform},
+};
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  function_declaration,
+  r#"
+    import stylex from 'stylex';
+    function rem(px) {
+      return px / 16 + 'rem';
+    }
+    export const styles = stylex.create({
+      root: { padding: rem(24) }
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  arrow_function_with_block_body,
+  r#"
+    import stylex from 'stylex';
+    const spacing = (n, unit = 'px') => {
+      const base = 4;
+      if (n === 0) {
+        return 0;
+      }
+      const value = n * base;
+      return `${value}${unit}`;
+    };
+    const size = (name) => name === 'small' ? spacing(2) : spacing(4, 'em');
+    export const styles = stylex.create({
+      root: {
+        margin: spacing(0),
+        padding: spacing(3),
+        width: size('small'),
+        height: size('large'),
+      }
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  callback_with_block_body,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      root: {
+        margin: [1, 2].map((n) => {
+          const value = n * 4;
+          return value + 'px';
+        }).join(' '),
+      }
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  module_functions_do_not_see_caller_locals,
+  r#"
+    import stylex from 'stylex';
+    const base = 8;
+    function scale(n) {
+      return n * base;
+    }
+    function gap(base) {
+      return scale(base) + 'px';
+    }
+    export const styles = stylex.create({
+      root: { gap: gap(2) }
+    });
+  "#
+);
+
+#[test]
+#[should_panic(
+  expected = "Function call could not be statically evaluated, it exceeds the recursion or step limit."
+)]
+fn runaway_recursion_is_reported() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+    r#"
+      import stylex from 'stylex';
+      function grow(n) {
+        return grow(n + 1);
+      }
+      export const styles = stylex.create({
+        root: { width: grow(0) }
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn non_static_callback_is_reported() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: {
+          margin: [1, 2].map((n) => {
+            return n * getScale();
+          }).join(' '),
+        }
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
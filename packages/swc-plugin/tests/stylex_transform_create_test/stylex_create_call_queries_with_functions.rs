@@ -117,3 +117,73 @@ test!(
     });
   "#
 );
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_style_function_with_block_body,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      default: (size) => {
+        const half = size / 2;
+        return {
+          backgroundColor: 'red',
+          width: half,
+        };
+      }
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_style_function_with_default_parameters,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      default: (color = 'red', width = 4) => ({
+        color,
+        width,
+      })
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_style_function_with_destructured_parameters,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      default: ({ color, size = 4 }, [top]) => ({
+        color,
+        height: size,
+        marginTop: top,
+      })
+    });
+  "#
+);
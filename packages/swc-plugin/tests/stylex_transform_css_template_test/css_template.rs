@@ -0,0 +1,207 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsConfig},
+  transforms::testing::{test, test_transform},
+};
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  transforms_css_template_declarations,
+  r#"
+    import { create, css } from '@stylexjs/stylex';
+    export const styles = create({
+      root: css`
+        background-color: red;
+        margin-top: 4px;
+        --accent: blue;
+      `,
+    });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  transforms_css_template_nested_pseudo_classes_and_at_rules,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      root: stylex.css`
+        color: red;
+        &:hover {
+          color: blue;
+          @media (max-width: 600px) {
+            color: green;
+          }
+        }
+        @media (min-width: 800px) {
+          color: yellow;
+          padding: 8px;
+        }
+      `,
+    });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  transforms_css_template_with_interpolated_constants,
+  r#"
+    import stylex from 'stylex';
+    const spacing = 8;
+    const accent = 'rebeccapurple';
+    const base = stylex.css`
+      padding: ${spacing * 2}px;
+      color: ${accent};
+    `;
+    export const styles = stylex.create({
+      base,
+      compact: stylex.css`padding: ${spacing}px;`,
+    });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  transforms_css_template_with_object_namespaces,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      root: stylex.css`color: red;`,
+      other: {
+        color: 'blue',
+      },
+    });
+    stylex.props(styles.root, styles.other);
+    "#
+);
+
+#[test]
+#[should_panic(
+  expected = "Nested selectors of a css`` template must be a pseudo selector of `&`, e.g. `&:hover`."
+)]
+fn css_template_with_descendant_selector() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: stylex.css`
+          color: red;
+          .child {
+            color: blue;
+          }
+        `,
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "A css`` template can only contain CSS declarations, nested pseudo selectors and at-rules."
+)]
+fn css_template_with_invalid_css() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: stylex.css`
+          color: red;
+        }
+        `,
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  removes_css_only_import,
+  r#"
+    import stylex from 'stylex';
+    import { css } from 'stylex';
+    export const styles = stylex.create({
+      root: css`
+        color: red;
+      `,
+    });
+    "#
+);
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn css_template_with_dynamic_value() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: stylex.css`
+          color: ${props.color};
+        `,
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn css_template_with_object_value() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+    r#"
+      import stylex from 'stylex';
+      const colors = { primary: 'red' };
+      export const styles = stylex.create({
+        root: stylex.css`
+          color: ${colors};
+        `,
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
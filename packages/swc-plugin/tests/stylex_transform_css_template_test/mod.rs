@@ -0,0 +1 @@
+mod css_template;
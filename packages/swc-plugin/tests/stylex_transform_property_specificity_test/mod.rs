@@ -0,0 +1 @@
+mod property_specificity;
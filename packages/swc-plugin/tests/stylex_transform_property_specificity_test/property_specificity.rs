@@ -0,0 +1,130 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleResolution, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsConfig},
+  transforms::testing::{test, test_transform},
+};
+
+fn property_specificity_options() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    runtime_injection: Some(true),
+    style_resolution: Some(StyleResolution::PropertySpecificity),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    ModuleTransformVisitor::new_test_styles(
+      tr.comments.clone(),
+      &PluginPass::default(),
+      Some(&mut property_specificity_options()),
+    )
+  },
+  keeps_shorthands_and_prioritizes_longhands,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      root: {
+        margin: 10,
+        marginTop: 5,
+        padding: '4px 8px',
+        paddingInlineStart: 2,
+        inset: 0,
+      },
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    ModuleTransformVisitor::new_test_styles(
+      tr.comments.clone(),
+      &PluginPass::default(),
+      Some(&mut property_specificity_options()),
+    )
+  },
+  rewrites_aliases_to_standard_properties,
+  r#"
+    import stylex from 'stylex';
+    export const styles = stylex.create({
+      root: {
+        marginHorizontal: 10,
+        paddingVertical: 5,
+        borderHorizontalColor: 'red',
+        blockSize: '100%',
+      },
+    });
+  "#
+);
+
+#[test]
+#[should_panic(
+  expected = "border is not supported. Use border-width, border-style and border-color instead"
+)]
+fn rejects_shorthands_of_shorthands() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut property_specificity_options()),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: {
+          border: '1px solid red',
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "borderInline is not supported.")]
+fn rejects_deprecated_border_shorthand_aliases() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_styles(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut property_specificity_options()),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      export const styles = stylex.create({
+        root: {
+          borderHorizontal: '1px solid red',
+        },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
@@ -0,0 +1,183 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsConfig},
+    transforms::testing::{test, test_transform},
+  },
+};
+
+fn plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real("/stylex/packages/TestTheme.stylex.js".into()),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  merges_spread_namespaces_of_create,
+  r#"
+    import stylex from 'stylex';
+    const baseStyles = {
+      root: { color: 'blue' },
+      label: { fontSize: 12 },
+    };
+    export const styles = stylex.create({
+      ...baseStyles,
+      root: { color: 'red' },
+    });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  merges_spread_style_objects_of_namespaces,
+  r#"
+    import stylex from 'stylex';
+    const baseButton = { color: 'blue', padding: 4, borderRadius: 2 };
+    export const styles = stylex.create({
+      primary: { ...baseButton, color: 'red' },
+      secondary: { color: 'red', ...baseButton },
+    });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+  merges_spread_variables_of_define_vars,
+  r#"
+    import stylex from 'stylex';
+    const baseTokens = { color: 'blue', spacing: '4px' };
+    export const vars = stylex.defineVars({
+      ...baseTokens,
+      color: 'red',
+      accent: { default: 'green', '@media (prefers-color-scheme: dark)': 'lime' },
+    });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+  merges_spread_overrides_of_create_theme,
+  r#"
+    import stylex from 'stylex';
+    export const vars = stylex.defineVars({
+      color: 'blue',
+      spacing: '4px',
+    });
+    const baseOverrides = { color: 'green', spacing: '8px' };
+    export const theme = stylex.createTheme(vars, {
+      ...baseOverrides,
+      color: 'red',
+    });
+    "#
+);
+
+#[test]
+#[should_panic(
+  expected = "Imported styles spread with a stylex.create call must be type cast as `XStyle` to verify their type."
+)]
+fn non_static_spread_of_create() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+    r#"
+      import stylex from 'stylex';
+      import { baseStyles } from './base';
+      export const styles = stylex.create({
+        ...baseStyles,
+        root: { color: 'red' },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Imported styles spread with a stylex.create call must be type cast as `XStyle` to verify their type."
+)]
+fn non_static_spread_of_define_vars() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+    r#"
+      import stylex from 'stylex';
+      import { baseTokens } from './tokens';
+      export const vars = stylex.defineVars({
+        ...baseTokens,
+        color: 'red',
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Imported styles spread with a stylex.create call must be type cast as `XStyle` to verify their type."
+)]
+fn spread_argument_of_define_vars() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &plugin_pass(), None),
+    r#"
+      import stylex from 'stylex';
+      const args = [{ color: 'red' }];
+      export const vars = stylex.defineVars(...args);
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Imported styles spread with a stylex.create call must be type cast as `XStyle` to verify their type."
+)]
+fn non_static_spread_of_namespace() {
+  test_transform(
+    Syntax::Typescript(TsConfig {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+    r#"
+      import stylex from 'stylex';
+      import { baseButton } from './base';
+      export const styles = stylex.create({
+        root: { ...baseButton, color: 'red' },
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
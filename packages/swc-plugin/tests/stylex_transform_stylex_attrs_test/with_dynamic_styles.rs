@@ -0,0 +1,63 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsConfig},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_call_with_dynamic_style,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+            size: (width, color) => ({
+                width,
+                color,
+            }),
+        });
+        stylex.attrs(styles.default, styles.size(width, 'blue'));
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_call_with_repeated_namespace,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                color: 'red',
+            },
+        });
+        stylex.attrs(styles.default, styles.default);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_call_with_nullish_inline_style,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+        });
+        stylex.attrs(styles.default, { color: null, backgroundColor: 'blue' });
+    "#
+);
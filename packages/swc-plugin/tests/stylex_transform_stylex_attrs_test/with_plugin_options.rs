@@ -136,3 +136,33 @@ test!(
         stylex.attrs([styles.default, isActive && styles.active]);
     "#
 );
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/html/js/FooBar.react.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      dev: Some(true),
+      enable_debug_data_prop: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  )
+  .with_source_map(tr.cm.clone()),
+  stylex_call_produces_debug_data_prop,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                color: 'red',
+            },
+        });
+        stylex.attrs(styles.default);
+    "#
+);
@@ -5,3 +5,4 @@ mod specific_edge_case_bugs;
 mod stylex_props_call;
 mod with_conditional_styles_and_collisions;
 mod with_plugin_options;
+mod with_dynamic_styles;
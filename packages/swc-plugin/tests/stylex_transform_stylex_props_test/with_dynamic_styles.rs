@@ -0,0 +1,130 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsConfig},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_call_with_dynamic_style_and_identifier_argument,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                backgroundColor: 'red',
+            },
+            color: (color) => ({
+                color,
+            }),
+        });
+        function Component({ color }) {
+            return <div {...stylex.props(styles.default, styles.color(color))} />;
+        }
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_call_with_dynamic_style_and_numeric_argument,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            size: (width, height) => ({
+                width,
+                height,
+            }),
+        });
+        stylex.props(styles.size(100, '50%'));
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_call_with_dynamic_style_overridden_by_later_style,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            opacity: (opacity) => ({
+                opacity,
+            }),
+            hidden: {
+                opacity: 0,
+            },
+        });
+        stylex.props(styles.opacity(value), styles.hidden);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_call_with_dynamic_style_and_dynamic_argument,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            color: (color) => ({
+                color,
+            }),
+        });
+        stylex.props(styles.color(getColor()));
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_call_with_repeated_namespace,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                color: 'red',
+            },
+            active: {
+                color: 'blue',
+            },
+        });
+        stylex.props(styles.default, styles.active, styles.default);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(tr.comments.clone(), &PluginPass::default(), None),
+  stylex_call_with_destructured_dynamic_style,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            size: ({ width, height = 10 }) => {
+                const area = width * height;
+                return {
+                    width,
+                    height,
+                    minWidth: area,
+                };
+            },
+        });
+        stylex.props(styles.size({ width: 20 }));
+    "#
+);
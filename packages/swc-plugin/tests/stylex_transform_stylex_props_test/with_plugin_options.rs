@@ -1,4 +1,5 @@
 use stylex_swc_plugin::{
+  assign_minified_class_names,
   shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
   ModuleTransformVisitor,
 };
@@ -136,3 +137,137 @@ test!(
         stylex.props([styles.default, isActive && styles.active]);
     "#
 );
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/html/js/FooBar.react.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      dev: Some(true),
+      enable_debug_data_prop: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  )
+  .with_source_map(tr.cm.clone()),
+  stylex_call_produces_debug_data_prop,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                color: 'red',
+            },
+        });
+        const otherStyles = stylex.create({
+            default: {
+                backgroundColor: 'blue',
+            }
+        });
+        stylex.props(styles.default, otherStyles.default);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/html/js/FooBar.react.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      dev: Some(true),
+      dev_class_name_format: Some("[file]-[namespace]-L[line]".to_string()),
+      ..StyleXOptionsParams::default()
+    })
+  )
+  .with_source_map(tr.cm.clone()),
+  stylex_call_produces_dev_class_names_with_custom_format,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                color: 'red',
+            },
+        });
+        stylex.props(styles.default);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_styles(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/html/js/FooBar.react.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      dev: Some(true),
+      enable_debug_data_prop: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  )
+  .with_source_map(tr.cm.clone()),
+  stylex_call_keeps_debug_data_in_exported_styles,
+  r#"
+        import stylex from 'stylex';
+        export const styles = stylex.create({
+            default: {
+                color: 'red',
+            },
+        });
+        stylex.props(styles.default);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsConfig {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    let mut options = StyleXOptionsParams {
+      minify_class_names: Some(true),
+      ..StyleXOptionsParams::default()
+    };
+
+    assign_minified_class_names(&options, ["x1e2nbdu", "x1t391ir", "x1prwzq3"]);
+
+    ModuleTransformVisitor::new_test_styles(
+      tr.comments.clone(),
+      &PluginPass {
+        cwd: None,
+        filename: FileName::Real("/html/js/FooBar.react.js".into()),
+      },
+      Some(&mut options),
+    )
+  },
+  stylex_call_produces_minified_class_names,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            default: {
+                color: 'red',
+                backgroundColor: 'blue',
+            },
+            active: {
+                color: 'green',
+            },
+        });
+        stylex.props(styles.default, styles.active);
+        stylex.props(styles.default, isActive && styles.active);
+    "#
+);
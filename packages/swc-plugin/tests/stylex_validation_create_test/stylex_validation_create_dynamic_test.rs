@@ -5,40 +5,8 @@ use swc_core::ecma::{
 };
 
 #[test]
-#[should_panic(
-  expected = "Only named parameters are allowed in Dynamic Style functions. Destructuring, spreading or default values are not allowed."
-)]
-fn dynamic_style_function_only_accepts_named_parameters_default_value() {
-  test_transform(
-    Syntax::Typescript(TsConfig {
-      tsx: true,
-      ..Default::default()
-    }),
-    |tr| {
-      ModuleTransformVisitor::new_test_styles(
-        tr.comments.clone(),
-        &PluginPass::default(),
-        None,
-      )
-    },
-    r#"
-            import stylex from 'stylex';
-            const styles = stylex.create({
-                dynamic: (props = {}) => ({
-                    color: props.color,
-                }),
-            });
-        "#,
-    r#""#,
-    false,
-  )
-}
-
-#[test]
-#[should_panic(
-  expected = "Only named parameters are allowed in Dynamic Style functions. Destructuring, spreading or default values are not allowed."
-)]
-fn dynamic_style_function_only_accepts_named_parameters_default_string_value() {
+#[should_panic(expected = "Rest parameters are not allowed in Dynamic Style functions.")]
+fn dynamic_style_function_only_accepts_named_parameters_rest_arg() {
   test_transform(
     Syntax::Typescript(TsConfig {
       tsx: true,
@@ -54,7 +22,7 @@ fn dynamic_style_function_only_accepts_named_parameters_default_string_value() {
     r#"
             import stylex from 'stylex';
             const styles = stylex.create({
-                dynamic: (color = 'red') => ({
+                dynamic: (...rest) => ({
                     color,
                 }),
             });
@@ -66,9 +34,9 @@ fn dynamic_style_function_only_accepts_named_parameters_default_string_value() {
 
 #[test]
 #[should_panic(
-  expected = "Only named parameters are allowed in Dynamic Style functions. Destructuring, spreading or default values are not allowed."
+  expected = "Dynamic Style functions can only declare variables before returning a style object."
 )]
-fn dynamic_style_function_only_accepts_named_parameters_object_arg() {
+fn dynamic_style_function_only_accepts_variable_declarations_in_body() {
   test_transform(
     Syntax::Typescript(TsConfig {
       tsx: true,
@@ -84,9 +52,12 @@ fn dynamic_style_function_only_accepts_named_parameters_object_arg() {
     r#"
             import stylex from 'stylex';
             const styles = stylex.create({
-                dynamic: ({ color }) => ({
-                    color,
-                }),
+                dynamic: (color) => {
+                    if (!color) {
+                        color = 'red';
+                    }
+                    return { color };
+                },
             });
         "#,
     r#""#,
@@ -96,9 +67,9 @@ fn dynamic_style_function_only_accepts_named_parameters_object_arg() {
 
 #[test]
 #[should_panic(
-  expected = "Only named parameters are allowed in Dynamic Style functions. Destructuring, spreading or default values are not allowed."
+  expected = "Dynamic Style functions can only declare variables before returning a style object."
 )]
-fn dynamic_style_function_only_accepts_named_parameters_rest_arg() {
+fn dynamic_style_function_must_return_style_object_from_body() {
   test_transform(
     Syntax::Typescript(TsConfig {
       tsx: true,
@@ -114,9 +85,9 @@ fn dynamic_style_function_only_accepts_named_parameters_rest_arg() {
     r#"
             import stylex from 'stylex';
             const styles = stylex.create({
-                dynamic: (...rest) => ({
-                    color,
-                }),
+                dynamic: (color) => {
+                    const styles = { color };
+                },
             });
         "#,
     r#""#,
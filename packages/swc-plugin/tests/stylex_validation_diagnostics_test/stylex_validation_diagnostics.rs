@@ -0,0 +1,193 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Parser, StringInput, Syntax, TsConfig},
+    visit::FoldWith,
+  },
+  plugin::proxies::PluginCommentsProxy,
+};
+
+fn transform_diagnostics(input: &str) -> String {
+  let result = testing::run_test(false, |cm, _| {
+    let fm = cm.new_source_file(FileName::Real("input.js".into()), input.into());
+
+    let module = Parser::new(
+      Syntax::Typescript(TsConfig {
+        tsx: true,
+        ..Default::default()
+      }),
+      StringInput::from(&*fm),
+      None,
+    )
+    .parse_module()
+    .expect("Failed to parse input");
+
+    let transformed = catch_unwind(AssertUnwindSafe(|| {
+      module.fold_with(&mut ModuleTransformVisitor::new_test_styles(
+        PluginCommentsProxy,
+        &PluginPass::default(),
+        None,
+      ))
+    }));
+
+    assert!(transformed.is_err(), "Transform should fail");
+
+    Err::<(), ()>(())
+  });
+
+  result
+    .expect_err("Diagnostics should be emitted")
+    .to_string()
+}
+
+#[test]
+fn reports_non_object_argument_at_argument() {
+  let diagnostics = transform_diagnostics(
+    r#"import stylex from "@stylexjs/stylex";
+const styles = stylex.create(genStyles());
+"#,
+  );
+
+  assert!(diagnostics.contains("stylex.create() can only accept a style object."));
+  assert!(
+    diagnostics.contains(
+      " 2 | const styles = stylex.create(genStyles());\n   :                              ^^^^^^^^^^^"
+    ),
+    "{}",
+    diagnostics
+  );
+}
+
+#[test]
+fn reports_unbound_call_at_call() {
+  let diagnostics = transform_diagnostics(
+    r#"import stylex from "@stylexjs/stylex";
+stylex.create({});
+"#,
+  );
+
+  assert!(diagnostics.contains("stylex.create calls must be bound to a bare variable."));
+  assert!(
+    diagnostics.contains(" 2 | stylex.create({});\n   : ^^^^^^^^^^^^^^^^^"),
+    "{}",
+    diagnostics
+  );
+}
+
+#[test]
+fn reports_invalid_pseudo_at_conditional_key() {
+  let diagnostics = transform_diagnostics(
+    r#"import stylex from "@stylexjs/stylex";
+const styles = stylex.create({
+  default: {
+    color: 'red',
+  },
+  root: {
+    color: { 'hover': 'blue' },
+  },
+});
+"#,
+  );
+
+  assert!(diagnostics.contains("Invalid pseudo or at-rule."));
+  assert!(
+    diagnostics.contains(" 7 |     color: { 'hover': 'blue' },\n   :              ^^^^^^^"),
+    "{}",
+    diagnostics
+  );
+}
+
+#[test]
+fn reports_duplicate_conditional_at_nested_key() {
+  let diagnostics = transform_diagnostics(
+    r#"import stylex from "@stylexjs/stylex";
+const styles = stylex.create({
+  root: {
+    color: {
+      default: 'red',
+      ':hover': {
+        default: 'blue',
+        ':hover': 'green',
+      },
+    },
+  },
+});
+"#,
+  );
+
+  assert!(
+    diagnostics.contains("The same pseudo selector or at-rule cannot be used more than once.")
+  );
+  assert!(
+    diagnostics.contains(" 8 |         ':hover': 'green',\n   :         ^^^^^^^^"),
+    "{}",
+    diagnostics
+  );
+}
+
+#[test]
+fn reports_non_static_value_at_property() {
+  let diagnostics = transform_diagnostics(
+    r#"import stylex from "@stylexjs/stylex";
+const styles = stylex.create({
+  root: {
+    color: props.color,
+  },
+});
+"#,
+  );
+
+  assert!(diagnostics.contains("Only static values are allowed inside of a stylex.create() call."));
+  assert!(
+    diagnostics.contains(" 4 |     color: props.color,\n   :     ^^^^^^^^^^^^^^^^^^"),
+    "{}",
+    diagnostics
+  );
+}
+
+#[test]
+fn reports_non_static_value_at_nested_property() {
+  let diagnostics = transform_diagnostics(
+    r#"import stylex from "@stylexjs/stylex";
+const styles = stylex.create({
+  root: {
+    color: {
+      default: 'red',
+      ':hover': tokens.hover,
+    },
+  },
+});
+"#,
+  );
+
+  assert!(diagnostics.contains("Only static values are allowed inside of a stylex.create() call."));
+  assert!(
+    diagnostics.contains(" 6 |       ':hover': tokens.hover,\n   :       ^^^^^^^^^^^^^^^^^^^^^^"),
+    "{}",
+    diagnostics
+  );
+}
+
+#[test]
+fn reports_callback_evaluation_limit_at_exceeding_call() {
+  let diagnostics = transform_diagnostics(
+    r#"import stylex from "@stylexjs/stylex";
+function grow(n) { return grow(n + 1); }
+const styles = stylex.create({ root: { width: [1].reduce((acc, n) => grow(n), 0) } });
+"#,
+  );
+
+  assert!(diagnostics.contains(
+    "Function call could not be statically evaluated, it exceeds the recursion or step limit."
+  ));
+  assert!(
+    diagnostics.contains(
+      " 2 | function grow(n) { return grow(n + 1); }\n   :                           ^^^^^^^^^^^"
+    ),
+    "{}",
+    diagnostics
+  );
+}